@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use qd_html::dom::{Doctype, Span};
+use qd_html::parser::{parse, parse_to_dom, Control, ParseHandler, StreamingParser, TagAttributes};
+
+/// Handler ignoring all events to measure the parser alone
+struct NoopHandler;
+
+impl ParseHandler for NoopHandler {
+    fn text(&mut self, _text: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn comment(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn cdata(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+    fn processing_instruction(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn element_start(&mut self, _name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, _span: Span) -> Control { Control::Continue }
+    fn element_end(&mut self, _name: &str, _span: Span) -> Control { Control::Continue }
+}
+
+/// A document with a lot of text, links and tables
+fn text_document() -> String {
+    let mut html = String::from("<!DOCTYPE html><html><head><title>Benchmark &amp; more</title></head><body>\n");
+
+    for i in 0..2000 {
+        html.push_str(&format!(
+            "<div class=\"item\" id=\"item-{}\"><h2>Item {}</h2>\n\
+             <p>Lorem ipsum dolor sit amet, consectetur adipiscing elit &ndash; sed do eiusmod tempor \
+             incididunt ut labore et dolore magna aliqua. <a href=\"/item/{}?a=1&amp;b=2\">More</a></p>\n\
+             <table><tr><td>{}</td><td>&euro; 12,50</td></tr></table><!-- item {} --></div>\n",
+            i, i, i, i, i
+        ));
+    }
+
+    html.push_str("</body></html>");
+    html
+}
+
+/// A document dominated by large script blocks
+fn script_document() -> String {
+    let mut html = String::from("<!DOCTYPE html><html><head>\n");
+
+    for i in 0..200 {
+        html.push_str("<script>\n");
+        for j in 0..100 {
+            html.push_str(&format!("var value_{}_{} = a < b && c > d ? \"<p>\" : '</div>';\n", i, j));
+        }
+        html.push_str("</script>\n");
+    }
+
+    html.push_str("</head><body></body></html>");
+    html
+}
+
+/// A document with a single script that spans many chunks when streamed
+fn long_script_document() -> String {
+    let mut html = String::from("<!DOCTYPE html><html><head><script>\n");
+
+    for i in 0..100_000 {
+        html.push_str(&format!("var value_{} = a < b && c > d ? \"<p>\" : '</div>';\n", i));
+    }
+
+    html.push_str("</script></head><body></body></html>");
+    html
+}
+
+/// Feeds the document to a streaming parser in chunks like `parse_reader` does
+fn feed_chunks(html: &str) {
+    let mut parser = StreamingParser::new(NoopHandler);
+    let mut start = 0;
+
+    while start < html.len() {
+        let mut end = (start + 8192).min(html.len());
+        while !html.is_char_boundary(end) {
+            end -= 1;
+        }
+        parser.feed(&html[start..end]);
+        start = end;
+    }
+
+    parser.finish();
+}
+
+/// A document with deeply nested and misnested formatting elements
+fn formatting_document() -> String {
+    let mut html = String::from("<!DOCTYPE html><html><body>\n");
+
+    for i in 0..5000 {
+        html.push_str(&format!("<b><i class=\"x{}\">one</b>two</i><p><em>three</p>", i % 10));
+    }
+
+    html.push_str("</body></html>");
+    html
+}
+
+fn bench_documents(c: &mut Criterion) {
+    let documents = [
+        ("text", text_document()),
+        ("script", script_document()),
+        ("long_script", long_script_document()),
+        ("formatting", formatting_document()),
+    ];
+
+    for (name, html) in documents.iter() {
+        let mut group = c.benchmark_group(*name);
+        group.throughput(Throughput::Bytes(html.len() as u64));
+
+        group.bench_function("parse", |b| b.iter(|| parse(&mut NoopHandler, html)));
+        group.bench_function("parse_to_dom", |b| b.iter(|| parse_to_dom(html)));
+        group.bench_function("feed_chunks", |b| b.iter(|| feed_chunks(html)));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_documents);
+criterion_main!(benches);
@@ -1,5 +1,43 @@
 //! Minimalistic Document Object Model
 
+/// A position in the parsed input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// Byte offset from the start of the input
+    pub offset: usize,
+    /// Line number starting with 1
+    pub line: usize,
+    /// Column number starting with 1, counted in chars
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(offset: usize, line: usize, column: usize) -> Position {
+        Position { offset, line, column }
+    }
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position::new(0, 1, 1)
+    }
+}
+
+/// The part of the input a parser event or node was created from
+///
+/// `end` points behind the last char of the span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Span {
+        Span { start, end }
+    }
+}
+
 /// A node
 #[derive(Debug)]
 pub enum Node {
@@ -15,11 +53,15 @@ pub enum Node {
 #[derive(Debug)]
 pub struct Text {
     pub content: String,
+    pub span: Option<Span>,
 }
 
 impl Text {
     pub fn new<T: Into<String>>(content: T) -> Text {
-        Text { content: content.into() }
+        Text {
+            content: content.into(),
+            span: None,
+        }
     }
 }
 
@@ -28,11 +70,15 @@ impl Text {
 #[derive(Debug)]
 pub struct Comment {
     pub content: String,
+    pub span: Option<Span>,
 }
 
 impl Comment {
     pub fn new<T: Into<String>>(content: T) -> Comment {
-        Comment { content: content.into() }
+        Comment {
+            content: content.into(),
+            span: None,
+        }
     }
 }
 
@@ -73,7 +119,7 @@ impl ProcessingInstruction {
 }
 
 /// Attribute of an element
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Attribute {
     pub name: String,
     pub value: Option<String>,
@@ -104,7 +150,7 @@ impl Attribute {
 }
 
 /// List of attributes of an element
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Attributes {
     list: Vec<Attribute>,
 }
@@ -114,6 +160,11 @@ impl Attributes {
         Attributes { list: Vec::new() }
     }
 
+    /// Creates the attributes from a list without duplicate names
+    pub(crate) fn from_unique(list: Vec<Attribute>) -> Attributes {
+        Attributes { list }
+    }
+
     pub fn set<T: Into<String>, T2: Into<String>>(&mut self, name: T, value: T2) {
         let value = value.into();
         let name = name.into();
@@ -202,6 +253,7 @@ pub struct Element {
     pub attributes: Attributes,
     pub nodes: Vec<Node>,
     pub autoclosed: bool,
+    pub span: Option<Span>,
 }
 
 impl Element {
@@ -211,6 +263,7 @@ impl Element {
             attributes: Attributes::new(),
             nodes: Vec::new(),
             autoclosed: false,
+            span: None,
         }
     }
 
@@ -220,6 +273,7 @@ impl Element {
             attributes,
             nodes: Vec::new(),
             autoclosed: false,
+            span: None,
         }
     }
 
@@ -260,11 +314,210 @@ impl Element {
     }
 }
 
+impl Drop for Element {
+    /// Drops the child elements in a loop, so deeply nested trees don't overflow the stack
+    fn drop(&mut self) {
+        let mut nodes = std::mem::take(&mut self.nodes);
+        while let Some(node) = nodes.pop() {
+            if let Node::Element(mut e) = node {
+                nodes.append(&mut e.nodes);
+            }
+        }
+    }
+}
+
+/// Public identifiers that put a document in quirks mode if they start one
+static QUIRKS_PUBLIC_ID_PREFIXES: &[&str] = &[
+    "+//silmaril//dtd html pro v0r11 19970101//",
+    "-//as//dtd html 3.0 aswedit + extensions//",
+    "-//advasoft ltd//dtd html 3.0 aswedit + extensions//",
+    "-//ietf//dtd html 2.0 level 1//",
+    "-//ietf//dtd html 2.0 level 2//",
+    "-//ietf//dtd html 2.0 strict level 1//",
+    "-//ietf//dtd html 2.0 strict level 2//",
+    "-//ietf//dtd html 2.0 strict//",
+    "-//ietf//dtd html 2.0//",
+    "-//ietf//dtd html 2.1e//",
+    "-//ietf//dtd html 3.0//",
+    "-//ietf//dtd html 3.2 final//",
+    "-//ietf//dtd html 3.2//",
+    "-//ietf//dtd html 3//",
+    "-//ietf//dtd html level 0//",
+    "-//ietf//dtd html level 1//",
+    "-//ietf//dtd html level 2//",
+    "-//ietf//dtd html level 3//",
+    "-//ietf//dtd html strict level 0//",
+    "-//ietf//dtd html strict level 1//",
+    "-//ietf//dtd html strict level 2//",
+    "-//ietf//dtd html strict level 3//",
+    "-//ietf//dtd html strict//",
+    "-//ietf//dtd html//",
+    "-//metrius//dtd metrius presentational//",
+    "-//microsoft//dtd internet explorer 2.0 html strict//",
+    "-//microsoft//dtd internet explorer 2.0 html//",
+    "-//microsoft//dtd internet explorer 2.0 tables//",
+    "-//microsoft//dtd internet explorer 3.0 html strict//",
+    "-//microsoft//dtd internet explorer 3.0 html//",
+    "-//microsoft//dtd internet explorer 3.0 tables//",
+    "-//netscape comm. corp.//dtd html//",
+    "-//netscape comm. corp.//dtd strict html//",
+    "-//o'reilly and associates//dtd html 2.0//",
+    "-//o'reilly and associates//dtd html extended 1.0//",
+    "-//o'reilly and associates//dtd html extended relaxed 1.0//",
+    "-//sq//dtd html 2.0 hotmetal + extensions//",
+    "-//softquad software//dtd hotmetal pro 6.0::19990601::extensions to html 4.0//",
+    "-//softquad//dtd hotmetal pro 4.0::19971010::extensions to html 4.0//",
+    "-//spyglass//dtd html 2.0 extended//",
+    "-//sun microsystems corp.//dtd hotjava html//",
+    "-//sun microsystems corp.//dtd hotjava strict html//",
+    "-//w3c//dtd html 3 1995-03-24//",
+    "-//w3c//dtd html 3.2 draft//",
+    "-//w3c//dtd html 3.2 final//",
+    "-//w3c//dtd html 3.2//",
+    "-//w3c//dtd html 3.2s draft//",
+    "-//w3c//dtd html 4.0 frameset//",
+    "-//w3c//dtd html 4.0 transitional//",
+    "-//w3c//dtd html experimental 19960712//",
+    "-//w3c//dtd html experimental 970421//",
+    "-//w3c//dtd w3 html//",
+    "-//w3o//dtd w3 html 3.0//",
+    "-//webtechs//dtd mozilla html 2.0//",
+    "-//webtechs//dtd mozilla html//",
+];
+
+/// Public identifiers that put a document in quirks mode
+static QUIRKS_PUBLIC_IDS: &[&str] = &["-//w3o//dtd w3 html strict 3.0//en//", "-/w3c/dtd html 4.0 transitional/en", "html"];
+
+/// Public identifiers of HTML 4.01 that put a document in quirks mode without a system identifier
+/// and in limited quirks mode with one
+static HTML4_PUBLIC_ID_PREFIXES: &[&str] = &["-//w3c//dtd html 4.01 frameset//", "-//w3c//dtd html 4.01 transitional//"];
+
+/// Public identifiers that put a document in limited quirks mode
+static LIMITED_QUIRKS_PUBLIC_ID_PREFIXES: &[&str] = &["-//w3c//dtd xhtml 1.0 frameset//", "-//w3c//dtd xhtml 1.0 transitional//"];
+
+/// Rendering mode of a document as selected by its doctype
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuirksMode {
+    NoQuirks,
+    LimitedQuirks,
+    Quirks,
+}
+
+/// Document type declaration
+///
+/// # Example
+///
+/// ```
+/// use qd_html::dom::{Doctype, QuirksMode};
+/// use qd_html::parser::parse_to_dom;
+///
+/// let document = parse_to_dom(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">"#);
+/// let doctype = document.doctype.unwrap();
+///
+/// assert_eq!(doctype.public_id.as_deref(), Some("-//W3C//DTD XHTML 1.0 Strict//EN"));
+/// assert_eq!(doctype.quirks_mode(), QuirksMode::NoQuirks);
+/// assert_eq!(Doctype::new("html").quirks_mode(), QuirksMode::NoQuirks);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Doctype {
+    /// Lowercase name of the root element, empty if the doctype has no name
+    pub name: String,
+    pub public_id: Option<String>,
+    pub system_id: Option<String>,
+    /// Set for malformed doctypes, which always select the quirks mode
+    pub force_quirks: bool,
+}
+
+impl Doctype {
+    pub fn new<T: Into<String>>(name: T) -> Doctype {
+        Doctype {
+            name: name.into(),
+            public_id: None,
+            system_id: None,
+            force_quirks: false,
+        }
+    }
+
+    /// Returns the mode browsers render a document with this doctype in
+    pub fn quirks_mode(&self) -> QuirksMode {
+        let public_id = self.public_id.as_deref().unwrap_or("").to_ascii_lowercase();
+        let starts_with = |prefixes: &[&str]| prefixes.iter().any(|prefix| public_id.starts_with(prefix));
+
+        if self.force_quirks
+            || self.name != "html"
+            || QUIRKS_PUBLIC_IDS.contains(&public_id.as_str())
+            || starts_with(QUIRKS_PUBLIC_ID_PREFIXES)
+            || self.system_id.as_ref().is_some_and(|id| id.eq_ignore_ascii_case("http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd"))
+            || (self.system_id.is_none() && starts_with(HTML4_PUBLIC_ID_PREFIXES))
+        {
+            QuirksMode::Quirks
+        } else if starts_with(LIMITED_QUIRKS_PUBLIC_ID_PREFIXES) || starts_with(HTML4_PUBLIC_ID_PREFIXES) {
+            QuirksMode::LimitedQuirks
+        } else {
+            QuirksMode::NoQuirks
+        }
+    }
+}
+
+/// XML declaration at the start of a document
+///
+/// Only a declaration at the very start of the input is read, others are kept as processing instructions.
+///
+/// # Example
+///
+/// ```
+/// use qd_html::parser::parse_to_dom;
+///
+/// let document = parse_to_dom("<?xml version='1.0' encoding='ISO-8859-1' standalone='yes'?>\n<feed/>");
+/// let declaration = document.xml_declaration.unwrap();
+///
+/// assert_eq!(declaration.version, "1.0");
+/// assert_eq!(declaration.encoding.as_deref(), Some("ISO-8859-1"));
+/// assert_eq!(declaration.standalone, Some(true));
+/// assert_eq!(declaration.quote, '\'');
+/// assert_eq!(declaration.trailing_whitespace, "\n");
+/// assert_eq!(declaration.source.as_deref(), Some("xml version='1.0' encoding='ISO-8859-1' standalone='yes'"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlDeclaration {
+    pub version: String,
+    pub encoding: Option<String>,
+    pub standalone: Option<bool>,
+    /// Quote around the values when written
+    pub quote: char,
+    /// Whitespace directly following the declaration
+    pub trailing_whitespace: String,
+    /// Source text between `<?` and `?>`, written as it is while it declares the same values
+    pub source: Option<String>,
+}
+
+impl XmlDeclaration {
+    pub fn new<T: Into<String>>(version: T) -> XmlDeclaration {
+        XmlDeclaration {
+            version: version.into(),
+            encoding: None,
+            standalone: None,
+            quote: '"',
+            trailing_whitespace: String::new(),
+            source: None,
+        }
+    }
+}
+
 /// Document
 #[derive(Debug)]
 pub struct Document {
-    pub doctype: Option<String>,
+    pub xml_declaration: Option<XmlDeclaration>,
+    pub doctype: Option<Doctype>,
+    /// Quirks mode selected by the doctype
+    ///
+    /// Parsed documents without a doctype are in quirks mode.
+    pub quirks_mode: QuirksMode,
     pub is_xml: bool,
+    /// Name of the encoding the document was decoded from
+    ///
+    /// This is only set for documents parsed from bytes.
+    pub encoding: Option<&'static str>,
     pub element: Element,
 }
 
@@ -274,8 +527,11 @@ impl Document {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Document {
         Document {
-            doctype: Some("html".to_owned()),
+            xml_declaration: None,
+            doctype: Some(Doctype::new("html")),
+            quirks_mode: QuirksMode::NoQuirks,
             is_xml: false,
+            encoding: None,
             element: Element::new("#document")
         }
     }
@@ -0,0 +1,1655 @@
+//! Build a simplified DOM tree from parser events
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+use crate::dom::*;
+use crate::error::ParseError;
+use crate::parser::{Control, ParseHandler, ParseMode, ParseOptions, ParserLimits, TagAttributes};
+use crate::utils::*;
+
+/// Elements limiting the search for an open element
+#[derive(Clone, Copy)]
+enum Scope {
+    Default,
+    ListItem,
+    Button,
+    Table,
+}
+
+impl Scope {
+    fn is_boundary(self, name: &str) -> bool {
+        let default = || matches!(name, "#document" | "applet" | "caption" | "html" | "table" | "td" | "th"
+            | "marquee" | "object" | "template");
+
+        match self {
+            Scope::Default => default(),
+            Scope::ListItem => default() || name == "ol" || name == "ul",
+            Scope::Button => default() || name == "button",
+            Scope::Table => matches!(name, "#document" | "html" | "table" | "template"),
+        }
+    }
+}
+
+/// Tests if a start tag with the given name closes an open `p` element
+pub(crate) fn closes_paragraph(name: &str) -> bool {
+    matches!(name, "address" | "article" | "aside" | "blockquote" | "center" | "details" | "dialog"
+        | "dir" | "div" | "dl" | "fieldset" | "figcaption" | "figure" | "footer" | "header" | "hgroup"
+        | "main" | "menu" | "nav" | "ol" | "p" | "search" | "section" | "summary" | "ul" | "pre"
+        | "listing" | "form" | "plaintext" | "xmp" | "table" | "hr" | "li" | "dd" | "dt")
+        || is_heading(name)
+}
+
+fn is_heading(name: &str) -> bool {
+    matches!(name, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// Returns the scope an end tag is searched in for elements only closed by their own end tag
+fn end_tag_scope(name: &str) -> Option<Scope> {
+    match name {
+        "address" | "article" | "aside" | "blockquote" | "button" | "center" | "details" | "dialog"
+        | "dir" | "div" | "dl" | "fieldset" | "figcaption" | "figure" | "footer" | "header" | "hgroup"
+        | "listing" | "main" | "menu" | "nav" | "ol" | "pre" | "search" | "section" | "summary" | "ul"
+        | "form" | "applet" | "marquee" | "object" | "dd" | "dt" | "body" | "html" | "select" => Some(Scope::Default),
+        "li" => Some(Scope::ListItem),
+        "table" | "caption" | "colgroup" | "tbody" | "thead" | "tfoot" | "tr" | "td" | "th" => Some(Scope::Table),
+        _ => None,
+    }
+}
+
+/// Tests if the element is a formatting element that is reopened when it is closed implicitly
+fn is_formatting_element(name: &str) -> bool {
+    matches!(name, "a" | "b" | "big" | "code" | "em" | "font" | "i" | "nobr" | "s" | "small" | "strike"
+        | "strong" | "tt" | "u")
+}
+
+/// Tests if the element limits the reopening of formatting elements to its content
+fn inserts_marker(name: &str) -> bool {
+    matches!(name, "applet" | "caption" | "marquee" | "object" | "td" | "th")
+}
+
+/// Tests if closed formatting elements are reopened before an element with the given name
+fn reopens_formatting(name: &str) -> bool {
+    if is_special_element(name) {
+        matches!(name, "applet" | "area" | "br" | "embed" | "img" | "input" | "keygen" | "marquee"
+            | "object" | "select" | "wbr" | "xmp")
+    } else {
+        !matches!(name, "rb" | "rp" | "rt" | "rtc")
+    }
+}
+
+fn same_attributes(a: &Attributes, b: &Attributes) -> bool {
+    a.len() == b.len() && a.into_iter().all(|attr| b.contains(&attr.name) && b.get_value(&attr.name) == attr.value.as_deref())
+}
+
+/// Tests if text and elements not allowed in a table are moved before the table when the element is the current one
+fn is_table_element(name: &str) -> bool {
+    matches!(name, "table" | "tbody" | "tfoot" | "thead" | "tr")
+}
+
+/// Table elements that close an open cell or caption
+fn is_table_structure(name: &str) -> bool {
+    matches!(name, "caption" | "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr")
+}
+
+/// Part of a table the current element is in
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TableMode {
+    Table,
+    TableBody,
+    Row,
+    Cell,
+    Caption,
+    ColumnGroup,
+}
+
+/// Returns the part of a table an element starts
+fn table_mode_of(name: &str) -> Option<TableMode> {
+    match name {
+        "table" => Some(TableMode::Table),
+        "tbody" | "thead" | "tfoot" => Some(TableMode::TableBody),
+        "tr" => Some(TableMode::Row),
+        "td" | "th" => Some(TableMode::Cell),
+        "caption" => Some(TableMode::Caption),
+        "colgroup" => Some(TableMode::ColumnGroup),
+        _ => None,
+    }
+}
+
+/// Reads an XML declaration from the content of a processing instruction
+///
+/// Returns `None` for other processing instructions and declarations that couldn't be read, which
+/// are kept as nodes then.
+pub(crate) fn read_xml_declaration(content: &str) -> Option<XmlDeclaration> {
+    let mut rest = content.strip_prefix("xml")?;
+    if !rest.starts_with(is_html_whitespace) {
+        return None;
+    }
+
+    let mut declaration = XmlDeclaration::new("");
+    loop {
+        rest = rest.trim_start_matches(is_html_whitespace);
+        if rest.is_empty() {
+            break;
+        }
+
+        let (name, value) = rest.split_once('=')?;
+        let value = value.trim_start_matches(is_html_whitespace);
+        let quote = value.chars().next().filter(|&ch| ch == '"' || ch == '\'')?;
+        let (value, after) = value[1..].split_once(quote)?;
+        rest = after;
+
+        match name.trim_end_matches(is_html_whitespace) {
+            "version" if declaration.version.is_empty() => {
+                declaration.version = value.to_owned();
+                declaration.quote = quote;
+            }
+            "encoding" if declaration.encoding.is_none() => declaration.encoding = Some(value.to_owned()),
+            "standalone" if declaration.standalone.is_none() => declaration.standalone = match value {
+                "yes" => Some(true),
+                "no" => Some(false),
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+
+    if declaration.version.is_empty() {
+        None
+    } else {
+        declaration.source = Some(content.to_owned());
+        Some(declaration)
+    }
+}
+
+/// An element on the stack of open elements
+struct OpenElement {
+    /// Identifies the element in the list of active formatting elements
+    id: usize,
+    /// Added before the nearest table when closed because it isn't allowed in the table
+    fostered: bool,
+    element: Element,
+}
+
+impl Deref for OpenElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Element {
+        &self.element
+    }
+}
+
+impl DerefMut for OpenElement {
+    fn deref_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+/// Entry of the list of active formatting elements
+enum Formatting {
+    /// Limits the reopening of formatting elements to the content of an element like `td`
+    Marker,
+    Element { id: usize, name: String, attributes: Attributes },
+}
+
+/// Tests if the element belongs into the `head` element
+pub(crate) fn is_head_element(name: &str) -> bool {
+    matches!(name, "base" | "basefont" | "bgsound" | "link" | "meta" | "noframes" | "noscript" | "script"
+        | "style" | "template" | "title")
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' ')
+}
+
+/// Returns the position after the text
+fn advance(mut position: Position, text: &str) -> Position {
+    for c in text.chars() {
+        position.offset += c.len_utf8();
+        if c == '\n' {
+            position.line += 1;
+            position.column = 1;
+        } else {
+            position.column += 1;
+        }
+    }
+    position
+}
+
+/// Where content is inserted if the implied elements are inserted
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum InsertionMode {
+    BeforeHtml,
+    BeforeHead,
+    InHead,
+    AfterHead,
+    InBody,
+    AfterBody,
+    AfterAfterBody,
+}
+
+/// Tests if the end tag of an element is generated implicitly by the end tag of a parent element
+fn has_implied_end_tag(name: &str) -> bool {
+    matches!(name, "dd" | "dt" | "li" | "optgroup" | "option" | "p" | "rb" | "rp" | "rt" | "rtc")
+}
+
+/// A ParseHandler implementation that construct simplified DOM tree
+pub(crate) struct DomParseHandler {
+    /// Open elements starting with the document element
+    stack: Vec<OpenElement>,
+    /// Number of elements at the bottom of the stack that are never closed
+    root: usize,
+    /// Table part of the context element when parsing a fragment
+    context: Option<TableMode>,
+    /// Formatting elements reopened when they are closed implicitly
+    formatting: Vec<Formatting>,
+    next_id: usize,
+    /// Always `InBody` if the implied elements are not inserted
+    mode: InsertionMode,
+    /// Set while content misplaced in a table is processed
+    foster_parenting: bool,
+    implied_elements: bool,
+    /// Comments after the end of the `body` element
+    after_body: Vec<Node>,
+    /// Comments after the end of the `html` element
+    after_html: Vec<Node>,
+    xml_declaration: Option<XmlDeclaration>,
+    /// Offset behind the XML declaration to find whitespace directly following it
+    declaration_end: Option<usize>,
+    doctype: Option<Doctype>,
+    /// Build the tree from XML, where elements are only closed by their end tags
+    is_xml: bool,
+    spans: bool,
+    /// End position of the last processed event
+    position: Position,
+    errors: Vec<ParseError>,
+    limits: ParserLimits,
+    /// Number of nodes created from the input
+    nodes: usize,
+    /// Number of open elements that are a part of a table
+    table_elements: usize,
+    /// Number of open elements by name to skip searching the stack for elements that aren't open
+    open_elements: HashMap<String, usize>,
+}
+
+impl DomParseHandler {
+    pub fn new(options: &ParseOptions) -> DomParseHandler {
+        let is_xml = options.mode == ParseMode::Xml;
+        let implied_elements = options.implied_elements && !is_xml;
+
+        DomParseHandler {
+            stack: vec![OpenElement {
+                id: 0,
+                fostered: false,
+                element: Element::new("#document"),
+            }],
+            root: 1,
+            context: None,
+            formatting: Vec::new(),
+            next_id: 1,
+            mode: if implied_elements {
+                InsertionMode::BeforeHtml
+            } else {
+                InsertionMode::InBody
+            },
+            implied_elements,
+            foster_parenting: false,
+            after_body: Vec::new(),
+            after_html: Vec::new(),
+            xml_declaration: None,
+            declaration_end: None,
+            doctype: None,
+            is_xml,
+            spans: options.spans,
+            position: Position::default(),
+            errors: Vec::new(),
+            limits: options.limits.clone(),
+            nodes: 0,
+            table_elements: 0,
+            open_elements: HashMap::new(),
+        }
+    }
+
+    /// Creates a handler for a fragment parsed as the content of the `context` element
+    ///
+    /// Like in the HTML specification the content is added to an `html` element that can't be closed.
+    pub fn new_fragment(context: &str) -> DomParseHandler {
+        let mut handler = DomParseHandler::new(&ParseOptions::default());
+        handler.open_implied("html", Span::default());
+        handler.root = 2;
+        handler.context = table_mode_of(context);
+        handler
+    }
+
+    /// Returns the nodes of a fragment
+    pub fn into_fragment(mut self) -> Vec<Node> {
+        self.finish();
+        std::mem::take(&mut self.stack.pop().expect("Fragment element").nodes)
+    }
+
+    /// Returns the document and the errors sorted by their position
+    pub fn into_document(mut self) -> (Document, Vec<ParseError>) {
+        self.finish();
+
+        let mut errors = self.errors;
+        errors.sort_by_key(|e| e.span().start.offset);
+
+        let quirks_mode = match self.doctype {
+            // XML documents are never rendered in quirks mode
+            _ if self.is_xml => QuirksMode::NoQuirks,
+            Some(ref doctype) => doctype.quirks_mode(),
+            None => QuirksMode::Quirks,
+        };
+        let document = Document {
+            xml_declaration: self.xml_declaration,
+            doctype: self.doctype,
+            quirks_mode,
+            is_xml: self.is_xml,
+            encoding: None,
+            element: self.stack.pop().expect("Document element").element,
+        };
+
+        (document, errors)
+    }
+
+    fn span(&self, span: Span) -> Option<Span> {
+        if self.spans {
+            Some(span)
+        } else {
+            None
+        }
+    }
+
+    /// Counts a new node and returns false if the node limit is reached
+    fn count_node(&mut self, span: Span) -> bool {
+        if self.nodes >= self.limits.max_nodes {
+            self.errors.push(ParseError::TooManyNodes { span });
+            return false;
+        }
+
+        self.nodes += 1;
+        true
+    }
+
+    /// Closes elements until a new element doesn't exceed the depth limit
+    fn limit_depth(&mut self, name: &str, span: Span) {
+        if self.stack.len() - self.root < self.limits.max_depth {
+            return;
+        }
+
+        self.errors.push(ParseError::NestingTooDeep {
+            name: name.to_owned(),
+            span,
+        });
+
+        while self.stack.len() - self.root >= self.limits.max_depth {
+            let id = self.stack[self.stack.len() - 1].id;
+            if self.close_current(span.start, true).is_none() {
+                break;
+            }
+
+            // Formatting elements closed because of the limit aren't reopened
+            if let Some(position) = self.formatting_position(id) {
+                self.formatting.remove(position);
+            }
+        }
+    }
+
+    fn current(&mut self) -> &mut Element {
+        self.stack.last_mut().expect("Document element")
+    }
+
+    fn current_name(&self) -> &str {
+        &self.stack[self.stack.len() - 1].name
+    }
+
+    /// Adds an element to the stack of open elements and returns its id
+    ///
+    /// Elements are closed first if the new element would exceed the depth limit.
+    fn open(&mut self, element: Element, span: Span) -> usize {
+        self.limit_depth(&element.name, span);
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let fostered = self.foster_parenting && is_table_element(self.current_name());
+        if table_mode_of(&element.name).is_some() {
+            self.table_elements += 1;
+        }
+        match self.open_elements.get_mut(&element.name) {
+            Some(count) => *count += 1,
+            None => {
+                self.open_elements.insert(element.name.clone(), 1);
+            }
+        }
+        self.stack.push(OpenElement { id, fostered, element });
+        id
+    }
+
+    /// Returns the element new nodes are added to
+    fn insertion_target(&mut self) -> &mut Element {
+        if self.foster_parenting && is_table_element(self.current_name()) {
+            self.foster_parent()
+        } else {
+            self.current()
+        }
+    }
+
+    /// Returns the parent of the nearest open table
+    ///
+    /// The table is added to the parent when it is closed, so nodes added now end up before the table.
+    fn foster_parent(&mut self) -> &mut Element {
+        let parent = match self.stack.iter().rposition(|e| e.name == "table") {
+            Some(table) if table >= self.root => table - 1,
+            _ => self.root - 1,
+        };
+        &mut self.stack[parent]
+    }
+
+    fn is_open(&self, id: usize) -> bool {
+        self.stack.iter().rev().any(|e| e.id == id)
+    }
+
+    /// Tests if an element with the given name is open
+    fn has_open(&self, name: &str) -> bool {
+        matches!(self.open_elements.get(name), Some(&count) if count > 0)
+    }
+
+    fn in_scope(&self, name: &str, scope: Scope) -> bool {
+        if !self.has_open(name) {
+            return false;
+        }
+
+        for e in self.stack[self.root..].iter().rev() {
+            if e.name == name {
+                return true;
+            }
+            if scope.is_boundary(&e.name) {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Closes the current element and adds it to its parent
+    ///
+    /// If the element isn't closed by its own end tag an error is reported unless the end tag is optional.
+    fn close_current(&mut self, end: Position, end_tag: bool) -> Option<&Element> {
+        if self.stack.len() <= self.root {
+            return None;
+        }
+
+        let OpenElement { fostered, element: mut e, .. } = self.stack.pop()?;
+
+        if table_mode_of(&e.name).is_some() {
+            self.table_elements -= 1;
+        }
+        if let Some(count) = self.open_elements.get_mut(&e.name) {
+            *count -= 1;
+        }
+
+        if inserts_marker(&e.name) {
+            self.clear_formatting_to_marker();
+        }
+
+        // Open elements always store the span of their start tag to be able to report errors
+        if let Some(mut span) = e.span {
+            if !end_tag && (self.is_xml || !has_optional_end_tag(&e.name)) {
+                self.errors.push(ParseError::UnclosedElement {
+                    name: e.name.clone(),
+                    span,
+                });
+            }
+
+            span.end = end;
+            e.span = self.span(span);
+        }
+
+        let parent = if fostered {
+            self.foster_parent()
+        } else {
+            self.current()
+        };
+        parent.add_element(e);
+
+        match parent.nodes.last() {
+            Some(Node::Element(e)) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Closes all open elements up to the element with the given name
+    ///
+    /// The element is expected to be open. If `end_tag` is set the element was closed by its own end tag.
+    fn close_until(&mut self, name: &str, span: Span, end_tag: bool) {
+        loop {
+            let found = self.current_name() == name;
+            let (end, end_tag) = if found && end_tag {
+                (span.end, true)
+            } else {
+                (span.start, false)
+            };
+
+            if self.close_current(end, end_tag).is_none() || found {
+                return;
+            }
+        }
+    }
+
+    /// Adds an element of an XML document
+    fn xml_element_start(&mut self, name: String, attributes: Attributes, autoclose: bool, span: Span) {
+        self.limit_depth(&name, span);
+
+        let mut e = Element::new_with_attributes(name, attributes);
+        e.autoclosed = autoclose;
+
+        if autoclose {
+            e.span = self.span(span);
+            self.current().add_element(e);
+        } else {
+            e.span = Some(span);
+            self.open(e, span);
+        }
+    }
+
+    /// Closes the nearest open element of an XML document with the given name
+    ///
+    /// Elements that are still open inside of it are reported as unclosed. End tags without an open
+    /// element are ignored.
+    fn xml_element_end(&mut self, name: &str, span: Span) {
+        if self.has_open(name) && self.stack[self.root..].iter().any(|e| e.name == name) {
+            self.close_until(name, span, true);
+        } else {
+            self.unexpected_end_tag(name, span);
+        }
+    }
+
+    fn unexpected_end_tag(&mut self, name: &str, span: Span) {
+        self.errors.push(ParseError::UnexpectedEndTag {
+            name: name.to_owned(),
+            span,
+        });
+    }
+
+    /// Closes the element with the given name if it is open in the scope
+    ///
+    /// The end tag is ignored otherwise.
+    fn close_in_scope(&mut self, name: &str, scope: Scope, span: Span) {
+        if self.in_scope(name, scope) {
+            self.generate_implied_end_tags(&[name], span.start);
+            self.close_until(name, span, true);
+        } else {
+            self.unexpected_end_tag(name, span);
+        }
+    }
+
+    /// Closes the nearest open element with the given name
+    ///
+    /// The end tag is ignored if a special element is found before a matching element.
+    fn close_nearest(&mut self, name: &str, span: Span) {
+        if self.has_open(name) {
+            for e in self.stack.iter().skip(self.root).rev() {
+                if e.name == name {
+                    self.generate_implied_end_tags(&[name], span.start);
+                    self.close_until(name, span, true);
+                    return;
+                }
+
+                if is_special_element(&e.name) {
+                    break;
+                }
+            }
+        }
+
+        self.unexpected_end_tag(name, span);
+    }
+
+    /// Adds a formatting element to the list of active formatting elements
+    ///
+    /// Only three elements with the same name and attributes are kept after the last marker. The entries
+    /// after the last marker are limited to the maximum depth like the open elements.
+    fn push_formatting(&mut self, id: usize, name: &str, attributes: &Attributes) {
+        let mut same = 0;
+        let mut earliest = 0;
+        let mut entries = 0;
+        let mut first = 0;
+
+        for (i, f) in self.formatting.iter().enumerate().rev() {
+            match f {
+                Formatting::Marker => break,
+                Formatting::Element { name: n, attributes: a, .. } if n == name && same_attributes(a, attributes) => {
+                    same += 1;
+                    earliest = i;
+                }
+                Formatting::Element { .. } => {}
+            }
+            entries += 1;
+            first = i;
+        }
+
+        if same >= 3 {
+            self.formatting.remove(earliest);
+        } else if entries > 0 && entries >= self.limits.max_depth {
+            self.formatting.remove(first);
+        }
+
+        self.formatting.push(Formatting::Element {
+            id,
+            name: name.to_owned(),
+            attributes: attributes.clone(),
+        });
+    }
+
+    fn clear_formatting_to_marker(&mut self) {
+        while let Some(f) = self.formatting.pop() {
+            if let Formatting::Marker = f {
+                break;
+            }
+        }
+    }
+
+    /// Returns the position in the list of active formatting elements
+    fn formatting_position(&self, id: usize) -> Option<usize> {
+        self.formatting.iter()
+            .rposition(|f| matches!(f, Formatting::Element { id: i, .. } if *i == id))
+    }
+
+    /// Returns the position and id of the last active formatting element with the given name after the last marker
+    fn find_formatting(&self, name: &str) -> Option<(usize, usize)> {
+        for (i, f) in self.formatting.iter().enumerate().rev() {
+            match f {
+                Formatting::Marker => return None,
+                Formatting::Element { id, name: n, .. } if n == name => return Some((i, *id)),
+                Formatting::Element { .. } => {}
+            }
+        }
+        None
+    }
+
+    /// Reopens formatting elements that were closed implicitly
+    fn reconstruct_formatting(&mut self, span: Span) {
+        let mut start = self.formatting.len();
+
+        while start > 0 {
+            match &self.formatting[start - 1] {
+                Formatting::Marker => break,
+                Formatting::Element { id, .. } if self.is_open(*id) => break,
+                Formatting::Element { .. } => start -= 1,
+            }
+        }
+
+        let mut position = start;
+        while position < self.formatting.len() {
+            let id = self.reopen_formatting(position, span);
+            position = self.formatting_position(id).expect("Reopened formatting element") + 1;
+        }
+    }
+
+    /// Opens a new element for an entry of the list of active formatting elements and returns its id
+    fn reopen_formatting(&mut self, position: usize, span: Span) -> usize {
+        let (old_id, element) = match &self.formatting[position] {
+            Formatting::Element { id, name, attributes } => (*id, Element::new_with_attributes(name.as_str(), attributes.clone())),
+            Formatting::Marker => unreachable!("Markers are never reopened"),
+        };
+
+        // Opening the element can remove other entries when elements are closed because of the depth limit
+        let new_id = self.open(element, span);
+        let position = self.formatting_position(old_id).expect("Reopened formatting element");
+        if let Formatting::Element { id, .. } = &mut self.formatting[position] {
+            *id = new_id;
+        }
+
+        new_id
+    }
+
+    /// Closes a formatting element with the adoption agency algorithm of the HTML specification
+    ///
+    /// Elements overlapping the formatting element are split and the formatting element is reopened
+    /// inside of them. Reopened elements have no span because they don't exist in the source.
+    /// If `end_tag` is set the formatting element is closed by its own end tag.
+    fn adopt(&mut self, name: &str, span: Span, end_tag: bool) {
+        let end = if end_tag { span.end } else { span.start };
+
+        let current = &self.stack[self.stack.len() - 1];
+        if current.name == name && self.formatting_position(current.id).is_none() {
+            self.close_current(end, true);
+            return;
+        }
+
+        for iteration in 0..8 {
+            let (position, id) = match self.find_formatting(name) {
+                Some(found) => found,
+                None => {
+                    self.close_nearest(name, span);
+                    return;
+                }
+            };
+
+            let index = match self.stack.iter().rposition(|e| e.id == id) {
+                Some(index) => index,
+                None => {
+                    self.formatting.remove(position);
+                    self.unexpected_end_tag(name, span);
+                    return;
+                }
+            };
+
+            if self.stack[index + 1..].iter().any(|e| Scope::Default.is_boundary(&e.name)) {
+                self.unexpected_end_tag(name, span);
+                return;
+            }
+
+            if iteration == 0 && index != self.stack.len() - 1 {
+                self.errors.push(ParseError::MisnestedElement {
+                    name: name.to_owned(),
+                    span,
+                });
+            }
+
+            let furthest_block = match self.stack[index + 1..].iter().position(|e| is_special_element(&e.name)) {
+                Some(i) => index + 1 + i,
+                None => {
+                    while self.stack.len() > index + 1 {
+                        self.close_current(span.start, true);
+                    }
+                    self.close_current(end, true);
+                    self.formatting.remove(position);
+                    return;
+                }
+            };
+
+            let mut tail = self.stack.split_off(furthest_block);
+
+            // Formatting elements between the formatting element and the furthest block are reopened
+            // around the furthest block. Only the three nearest ones are kept.
+            let mut reopened = Vec::new();
+            for (counter, i) in (index + 1..furthest_block).rev().enumerate() {
+                let id = self.stack[i].id;
+                if let Some(position) = self.formatting_position(id) {
+                    if counter >= 3 {
+                        self.formatting.remove(position);
+                    } else {
+                        reopened.push(id);
+                    }
+                }
+            }
+
+            while self.stack.len() > index {
+                self.close_current(span.start, true);
+            }
+
+            // The formatting element is reopened after the innermost reopened element
+            let mut bookmark = None;
+            for old_id in reopened.into_iter().rev() {
+                let position = self.formatting_position(old_id).expect("Reopened formatting element");
+                bookmark = Some(self.reopen_formatting(position, span));
+            }
+
+            let position = self.formatting_position(id).expect("Formatting element");
+            let entry = self.formatting.remove(position);
+
+            if let Formatting::Element { name, attributes, .. } = entry {
+                let mut block = tail.remove(0);
+                let mut element = Element::new_with_attributes(name.as_str(), attributes.clone());
+                element.nodes = std::mem::take(&mut block.nodes);
+                self.stack.push(block);
+                let new_id = self.open(element, span);
+                self.stack.append(&mut tail);
+
+                // Elements added to a table are moved before the table
+                self.stack[index].fostered = is_table_element(&self.stack[index - 1].name);
+
+                let insert_at = match bookmark {
+                    Some(bookmark) => self.formatting_position(bookmark).expect("Reopened formatting element") + 1,
+                    None => position,
+                };
+
+                self.formatting.insert(insert_at, Formatting::Element { id: new_id, name, attributes });
+            }
+        }
+    }
+
+    /// Returns the part of the table the current element is in
+    ///
+    /// Returns `None` outside of tables.
+    fn table_mode(&self) -> Option<TableMode> {
+        // Table elements outside of a table are handled like other elements
+        if self.table_elements == 0 || (self.context.is_none() && !self.has_open("table")) {
+            return self.context;
+        }
+
+        self.stack.iter().rev().find_map(|e| table_mode_of(&e.name)).or(self.context)
+    }
+
+    /// Closes elements until one of the given table elements is the current element
+    fn clear_to_table_context(&mut self, names: &[&str], span: Span) {
+        while !names.contains(&self.current_name()) && !matches!(self.current_name(), "html" | "template" | "#document") {
+            self.close_current(span.start, false);
+        }
+    }
+
+    fn unexpected_start_tag(&mut self, name: &str, span: Span) {
+        self.errors.push(ParseError::UnexpectedStartTag {
+            name: name.to_owned(),
+            span,
+        });
+    }
+
+    /// Closes the open `td` or `th` element
+    fn close_cell(&mut self, span: Span) {
+        self.generate_implied_end_tags(&[], span.start);
+        let cell = if self.in_scope("td", Scope::Table) { "td" } else { "th" };
+        self.close_until(cell, span, false);
+    }
+
+    /// Inserts or closes the table elements implied by a start tag in a table
+    ///
+    /// Returns false if the start tag is ignored.
+    fn table_start_tag(&mut self, name: &str, attributes: &Attributes, span: Span) -> bool {
+        const SECTIONS: &[&str] = &["tbody", "thead", "tfoot"];
+
+        loop {
+            match self.table_mode() {
+                None => return true,
+                Some(TableMode::Caption) if is_table_structure(name) => {
+                    if !self.in_scope("caption", Scope::Table) {
+                        self.unexpected_start_tag(name, span);
+                        return false;
+                    }
+                    self.generate_implied_end_tags(&[], span.start);
+                    self.close_until("caption", span, false);
+                }
+                Some(TableMode::Cell) if is_table_structure(name) => {
+                    if !self.in_scope("td", Scope::Table) && !self.in_scope("th", Scope::Table) {
+                        self.unexpected_start_tag(name, span);
+                        return false;
+                    }
+                    self.close_cell(span);
+                }
+                Some(TableMode::Caption) | Some(TableMode::Cell) => return true,
+                Some(TableMode::Row) if name == "td" || name == "th" => {
+                    self.clear_to_table_context(&["tr"], span);
+                    return true;
+                }
+                Some(TableMode::Row) if is_table_structure(name) => {
+                    // Only possible when parsing a fragment in the context of a row
+                    if !self.in_scope("tr", Scope::Table) {
+                        self.unexpected_start_tag(name, span);
+                        return false;
+                    }
+                    self.clear_to_table_context(&["tr"], span);
+                    self.close_current(span.start, false);
+                }
+                Some(TableMode::TableBody) if name == "tr" => {
+                    self.clear_to_table_context(SECTIONS, span);
+                    return true;
+                }
+                Some(TableMode::TableBody) if name == "td" || name == "th" => {
+                    self.clear_to_table_context(SECTIONS, span);
+                    self.open_implied("tr", span);
+                }
+                Some(TableMode::TableBody) if is_table_structure(name) => {
+                    // Only possible when parsing a fragment in the context of a table section
+                    if !SECTIONS.iter().any(|section| self.in_scope(section, Scope::Table)) {
+                        self.unexpected_start_tag(name, span);
+                        return false;
+                    }
+                    self.clear_to_table_context(SECTIONS, span);
+                    self.close_current(span.start, false);
+                }
+                Some(TableMode::ColumnGroup) if name == "col" || name == "template" => return true,
+                Some(TableMode::ColumnGroup) => {
+                    if self.current_name() != "colgroup" {
+                        self.unexpected_start_tag(name, span);
+                        return false;
+                    }
+                    self.close_current(span.start, false);
+                }
+                Some(_) => match name {
+                    "caption" | "colgroup" | "tbody" | "tfoot" | "thead" => {
+                        self.clear_to_table_context(&["table"], span);
+                        return true;
+                    }
+                    "col" => {
+                        self.clear_to_table_context(&["table"], span);
+                        self.open_implied("colgroup", span);
+                    }
+                    "td" | "th" | "tr" => {
+                        self.clear_to_table_context(&["table"], span);
+                        self.open_implied("tbody", span);
+                    }
+                    "table" => {
+                        if !self.in_scope("table", Scope::Table) {
+                            self.unexpected_start_tag(name, span);
+                            return false;
+                        }
+                        self.close_until("table", span, false);
+                    }
+                    "style" | "script" | "template" => return true,
+                    "input" if attributes.get_value("type").is_some_and(|t| t.eq_ignore_ascii_case("hidden")) => {
+                        return true;
+                    }
+                    _ => {
+                        if is_table_element(self.current_name()) {
+                            self.errors.push(ParseError::MisplacedTableContent { span });
+                            self.foster_parenting = true;
+                        }
+                        return true;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Closes the table elements implied by an end tag in a table
+    ///
+    /// Returns false if the end tag is handled or ignored.
+    fn table_end_tag(&mut self, name: &str, span: Span) -> bool {
+        const SECTIONS: &[&str] = &["tbody", "thead", "tfoot"];
+
+        let ignored = matches!(name, "body" | "caption" | "col" | "colgroup" | "html" | "tbody" | "td" | "tfoot"
+            | "th" | "thead" | "tr");
+
+        loop {
+            match self.table_mode() {
+                None => return true,
+                Some(TableMode::Caption) if name == "caption" || name == "table" => {
+                    if !self.in_scope("caption", Scope::Table) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.generate_implied_end_tags(&[], span.start);
+                    self.close_until("caption", span, name == "caption");
+                    if name == "caption" {
+                        return false;
+                    }
+                }
+                Some(TableMode::Cell) if name == "td" || name == "th" => {
+                    if !self.in_scope(name, Scope::Table) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.generate_implied_end_tags(&[], span.start);
+                    self.close_until(name, span, true);
+                    return false;
+                }
+                Some(TableMode::Cell) if is_table_element(name) => {
+                    if !self.in_scope(name, Scope::Table) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.close_cell(span);
+                }
+                Some(TableMode::Caption) | Some(TableMode::Cell) if ignored => {
+                    self.unexpected_end_tag(name, span);
+                    return false;
+                }
+                Some(TableMode::Caption) | Some(TableMode::Cell) => return true,
+                Some(TableMode::Row) if is_table_element(name) => {
+                    if !self.in_scope(name, Scope::Table) || !self.in_scope("tr", Scope::Table) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.clear_to_table_context(&["tr"], span);
+                    if name == "tr" {
+                        self.close_current(span.end, true);
+                        return false;
+                    }
+                    self.close_current(span.start, false);
+                }
+                Some(TableMode::TableBody) if name == "table" || SECTIONS.contains(&name) => {
+                    if !SECTIONS.iter().any(|section| self.in_scope(section, Scope::Table))
+                        || (name != "table" && !self.in_scope(name, Scope::Table)) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.clear_to_table_context(SECTIONS, span);
+                    if name != "table" {
+                        self.close_current(span.end, true);
+                        return false;
+                    }
+                    self.close_current(span.start, false);
+                }
+                Some(TableMode::ColumnGroup) if name != "col" && self.current_name() == "colgroup" => {
+                    if name == "colgroup" {
+                        self.close_current(span.end, true);
+                        return false;
+                    }
+                    self.close_current(span.start, false);
+                }
+                Some(_) if name == "table" => {
+                    if !self.in_scope("table", Scope::Table) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+                    self.close_until("table", span, true);
+                    return false;
+                }
+                Some(_) if ignored || name == "col" => {
+                    self.unexpected_end_tag(name, span);
+                    return false;
+                }
+                Some(_) => {
+                    self.foster_parenting = is_table_element(self.current_name());
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Moves misplaced text in a table before the table
+    fn table_text(&mut self, content: &str, span: Span) {
+        if content.chars().all(is_whitespace) {
+            return;
+        }
+
+        if self.table_mode() == Some(TableMode::ColumnGroup) && self.current_name() == "colgroup" {
+            self.close_current(span.start, false);
+        }
+
+        if self.table_mode().is_some() && is_table_element(self.current_name()) {
+            self.errors.push(ParseError::MisplacedTableContent { span });
+            self.foster_parenting = true;
+        }
+    }
+
+    /// Moves whitespace directly following the XML declaration to the declaration
+    ///
+    /// Returns the remaining text or `None` if all of it was whitespace.
+    fn declaration_whitespace<'c>(&mut self, content: Cow<'c, str>, span: Span) -> Option<(Cow<'c, str>, Span)> {
+        let declaration = match self.xml_declaration {
+            Some(ref mut declaration) if self.declaration_end == Some(span.start.offset) => declaration,
+            _ => return Some((content, span)),
+        };
+
+        let length = content.len() - content.trim_start_matches(is_whitespace).len();
+        declaration.trailing_whitespace.push_str(&content[..length]);
+
+        if length == content.len() {
+            return None;
+        }
+
+        let start = advance(span.start, &content[..length]);
+        let content = match content {
+            Cow::Borrowed(content) => Cow::Borrowed(&content[length..]),
+            Cow::Owned(content) => Cow::Owned(content[length..].to_owned()),
+        };
+        Some((content, Span::new(start, span.end)))
+    }
+
+    /// Opens an element that is implied by the content
+    fn open_implied(&mut self, name: &str, span: Span) {
+        self.open(Element::new(name), span);
+    }
+
+    /// Adds the attributes of a misplaced `html` or `body` start tag to the open element
+    fn merge_attributes(&mut self, name: &str, attributes: &Attributes, span: Span) {
+        self.errors.push(ParseError::UnexpectedStartTag {
+            name: name.to_owned(),
+            span,
+        });
+
+        let element = match self.stack.get_mut(if name == "html" { 1 } else { 2 }) {
+            Some(element) if element.name == name => element,
+            _ => return,
+        };
+
+        for attribute in attributes {
+            if !element.attributes.contains(&attribute.name) {
+                match &attribute.value {
+                    Some(value) => element.attributes.set(attribute.name.as_str(), value.as_str()),
+                    None => element.attributes.set_bool(attribute.name.as_str()),
+                }
+            }
+        }
+    }
+
+    /// Moves the closed `head` element back onto the stack of open elements
+    fn reopen_head(&mut self, span: Span) {
+        let html = self.current();
+        let head = html.nodes.iter()
+            .rposition(|node| matches!(node, Node::Element(e) if e.name == "head"));
+
+        if let Some(Node::Element(head)) = head.map(|index| html.nodes.remove(index)) {
+            self.open(head, span);
+        } else {
+            self.open_implied("head", span);
+        }
+
+        self.mode = InsertionMode::InHead;
+    }
+
+    /// Inserts the implied elements before a start tag
+    ///
+    /// Returns false if the start tag is ignored.
+    fn before_start_tag(&mut self, name: &str, attributes: &Attributes, span: Span) -> bool {
+        loop {
+            match self.mode {
+                InsertionMode::BeforeHtml if name == "html" => {
+                    self.mode = InsertionMode::BeforeHead;
+                    return true;
+                }
+                InsertionMode::BeforeHtml => {
+                    self.open_implied("html", span);
+                    self.mode = InsertionMode::BeforeHead;
+                }
+                InsertionMode::BeforeHead if name == "head" => {
+                    self.mode = InsertionMode::InHead;
+                    return true;
+                }
+                InsertionMode::BeforeHead if name != "html" => {
+                    self.open_implied("head", span);
+                    self.mode = InsertionMode::InHead;
+                }
+                InsertionMode::InHead if is_head_element(name) => return true,
+                InsertionMode::InHead if name != "html" && name != "head" => {
+                    self.close_until("head", span, false);
+                    self.mode = InsertionMode::AfterHead;
+                }
+                InsertionMode::AfterHead if name == "body" => {
+                    self.mode = InsertionMode::InBody;
+                    return true;
+                }
+                InsertionMode::AfterHead if is_head_element(name) => {
+                    self.errors.push(ParseError::UnexpectedStartTag {
+                        name: name.to_owned(),
+                        span,
+                    });
+                    self.reopen_head(span);
+                    return true;
+                }
+                InsertionMode::AfterHead if name != "html" && name != "head" => {
+                    self.open_implied("body", span);
+                    self.mode = InsertionMode::InBody;
+                }
+                InsertionMode::AfterBody | InsertionMode::AfterAfterBody => self.mode = InsertionMode::InBody,
+                _ if !self.implied_elements => return true,
+                _ if name == "html" || name == "body" => {
+                    self.merge_attributes(name, attributes, span);
+                    return false;
+                }
+                _ if name == "head" => {
+                    self.errors.push(ParseError::UnexpectedStartTag {
+                        name: name.to_owned(),
+                        span,
+                    });
+                    return false;
+                }
+                _ => return true,
+            }
+        }
+    }
+
+    /// Inserts the implied elements before an end tag
+    ///
+    /// Returns false if the end tag is handled or ignored.
+    fn before_end_tag(&mut self, name: &str, span: Span) -> bool {
+        let implies = matches!(name, "head" | "body" | "html" | "br");
+
+        loop {
+            match self.mode {
+                InsertionMode::BeforeHtml if implies => {
+                    self.open_implied("html", span);
+                    self.mode = InsertionMode::BeforeHead;
+                }
+                InsertionMode::BeforeHead if implies => {
+                    self.open_implied("head", span);
+                    self.mode = InsertionMode::InHead;
+                }
+                InsertionMode::InHead if name == "head" => {
+                    self.close_until("head", span, true);
+                    self.mode = InsertionMode::AfterHead;
+                    return false;
+                }
+                InsertionMode::InHead if implies => {
+                    self.close_until("head", span, false);
+                    self.mode = InsertionMode::AfterHead;
+                }
+                InsertionMode::InHead if self.stack.iter().skip(3).any(|e| e.name == name) => return true,
+                InsertionMode::AfterHead if implies && name != "head" => {
+                    self.open_implied("body", span);
+                    self.mode = InsertionMode::InBody;
+                }
+                InsertionMode::InBody if self.implied_elements && (name == "body" || name == "html") => {
+                    if !self.in_scope("body", Scope::Default) {
+                        self.unexpected_end_tag(name, span);
+                        return false;
+                    }
+
+                    self.mode = InsertionMode::AfterBody;
+                    if name == "body" {
+                        return false;
+                    }
+                }
+                InsertionMode::InBody => return true,
+                InsertionMode::AfterBody if name == "html" => {
+                    self.mode = InsertionMode::AfterAfterBody;
+                    return false;
+                }
+                InsertionMode::AfterBody | InsertionMode::AfterAfterBody => {
+                    self.unexpected_end_tag(name, span);
+                    self.mode = InsertionMode::InBody;
+                }
+                _ => {
+                    self.unexpected_end_tag(name, span);
+                    return false;
+                }
+            }
+        }
+    }
+
+    /// Inserts the implied elements before text
+    ///
+    /// Leading whitespace is dropped or kept in the element it belongs to. Returns the
+    /// remaining text and its span.
+    fn before_text(&mut self, mut content: String, mut span: Span) -> Option<(String, Span)> {
+        loop {
+            // Content of elements in the head like `noscript` stays in the element
+            if self.mode == InsertionMode::InBody || (self.mode == InsertionMode::InHead && self.current_name() != "head") {
+                return Some((content, span));
+            }
+
+            let whitespace = content.len() - content.trim_start_matches(is_whitespace).len();
+            if whitespace > 0 && self.mode != InsertionMode::AfterBody && self.mode != InsertionMode::AfterAfterBody {
+                let rest = content.split_off(whitespace);
+                let start = span.start;
+                span.start = advance(start, &content);
+
+                if matches!(self.mode, InsertionMode::InHead | InsertionMode::AfterHead) {
+                    let mut text = Text::new(content);
+                    text.span = self.span(Span::new(start, span.start));
+                    self.current().nodes.push(Node::Text(text));
+                }
+
+                content = rest;
+            }
+
+            if content.is_empty() {
+                return None;
+            }
+
+            match self.mode {
+                InsertionMode::BeforeHtml => {
+                    self.open_implied("html", span);
+                    self.mode = InsertionMode::BeforeHead;
+                }
+                InsertionMode::BeforeHead => {
+                    self.open_implied("head", span);
+                    self.mode = InsertionMode::InHead;
+                }
+                InsertionMode::InHead => {
+                    self.close_until("head", span, false);
+                    self.mode = InsertionMode::AfterHead;
+                }
+                InsertionMode::AfterHead => {
+                    self.open_implied("body", span);
+                    self.mode = InsertionMode::InBody;
+                }
+                _ => {
+                    if content.contains(|c| !is_whitespace(c)) {
+                        self.mode = InsertionMode::InBody;
+                    }
+                    return Some((content, span));
+                }
+            }
+        }
+    }
+
+    /// Closes elements with optional end tags that are implicitly closed by the end of their parent
+    fn generate_implied_end_tags(&mut self, except: &[&str], end: Position) {
+        while has_implied_end_tag(self.current_name()) && !except.contains(&self.current_name()) {
+            self.close_current(end, false);
+        }
+    }
+
+    fn close_paragraph(&mut self, span: Span) {
+        if self.in_scope("p", Scope::Button) {
+            self.generate_implied_end_tags(&["p"], span.start);
+            self.close_until("p", span, false);
+        }
+    }
+
+    /// Closes an open list item before a new `li`, `dd` or `dt` element is started
+    fn close_list_item(&mut self, names: &[&str], span: Span) {
+        let mut found = None;
+
+        for e in self.stack[self.root..].iter().rev() {
+            if names.contains(&e.name.as_str()) {
+                found = Some(e.name.clone());
+                break;
+            }
+            if is_special_element(&e.name) && !matches!(e.name.as_str(), "address" | "div" | "p") {
+                break;
+            }
+        }
+
+        if let Some(name) = found {
+            self.generate_implied_end_tags(&[&name], span.start);
+            self.close_until(&name, span, false);
+        }
+    }
+
+    /// Closes the elements implicitly ended by a start tag
+    fn close_implied_by_start_tag(&mut self, name: &str, span: Span) {
+        match name {
+            "li" => self.close_list_item(&["li"], span),
+            "dd" | "dt" => self.close_list_item(&["dd", "dt"], span),
+            "option" if self.current_name() == "option" => {
+                self.close_current(span.start, false);
+            }
+            "optgroup" => {
+                if self.current_name() == "option" {
+                    self.close_current(span.start, false);
+                }
+                if self.current_name() == "optgroup" {
+                    self.close_current(span.start, false);
+                }
+            }
+            "tr" if self.in_scope("tr", Scope::Table) => {
+                self.close_until("tr", span, false);
+            }
+            "td" | "th" => {
+                for cell in &["td", "th"] {
+                    if self.in_scope(cell, Scope::Table) {
+                        self.generate_implied_end_tags(&[], span.start);
+                        self.close_until(cell, span, false);
+                    }
+                }
+            }
+            "rb" | "rtc" if self.in_scope("ruby", Scope::Default) => {
+                self.generate_implied_end_tags(&[], span.start);
+            }
+            "rp" | "rt" if self.in_scope("ruby", Scope::Default) => {
+                self.generate_implied_end_tags(&["rtc"], span.start);
+            }
+            "button" if self.in_scope("button", Scope::Default) => {
+                self.generate_implied_end_tags(&[], span.start);
+                self.close_until("button", span, false);
+            }
+            _ => {}
+        }
+
+        if closes_paragraph(name) {
+            self.close_paragraph(span);
+        }
+
+        if is_heading(name) && is_heading(self.current_name()) {
+            self.close_current(span.start, false);
+        }
+    }
+}
+
+impl ParseHandler for DomParseHandler {
+    fn finish(&mut self) {
+        let end = self.position;
+
+        if self.implied_elements {
+            if matches!(self.mode, InsertionMode::BeforeHtml | InsertionMode::BeforeHead
+                | InsertionMode::InHead | InsertionMode::AfterHead) {
+                self.before_end_tag("br", Span::new(end, end));
+            }
+
+            while self.stack.len() > 2 {
+                self.close_current(end, false);
+            }
+
+            let after_body = std::mem::take(&mut self.after_body);
+            self.current().nodes.extend(after_body);
+        }
+
+        while self.close_current(end, false).is_some() {}
+
+        let after_html = std::mem::take(&mut self.after_html);
+        self.current().nodes.extend(after_html);
+    }
+
+    fn text(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.position = span.end;
+        if !self.count_node(span) {
+            return Control::Stop;
+        }
+        self.foster_parenting = false;
+
+        let (content, span) = match self.declaration_whitespace(content, span) {
+            Some(text) => text,
+            None => return Control::Continue,
+        };
+
+        if self.is_xml {
+            let mut text = Text::new(content);
+            text.span = self.span(span);
+            self.current().nodes.push(Node::Text(text));
+            return Control::Continue;
+        }
+
+        let (content, span) = match self.before_text(content.into_owned(), span) {
+            Some(text) => text,
+            None => return Control::Continue,
+        };
+
+        self.table_text(&content, span);
+
+        self.reconstruct_formatting(span);
+        let mut text = Text::new(content);
+        text.span = self.span(span);
+        self.insertion_target().nodes.push(Node::Text(text));
+        Control::Continue
+    }
+
+    fn comment(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.position = span.end;
+        if !self.count_node(span) {
+            return Control::Stop;
+        }
+        let mut comment = Comment::new(content);
+        comment.span = self.span(span);
+
+        match self.mode {
+            InsertionMode::AfterBody => self.after_body.push(Node::Comment(comment)),
+            InsertionMode::AfterAfterBody => self.after_html.push(Node::Comment(comment)),
+            _ => self.current().nodes.push(Node::Comment(comment)),
+        }
+
+        Control::Continue
+    }
+
+    fn cdata(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.position = span.end;
+        if !self.count_node(span) {
+            return Control::Stop;
+        }
+        self.current().add_cdata(content);
+        Control::Continue
+    }
+
+    fn doctype(&mut self, doctype: Doctype, span: Span) -> Control {
+        self.position = span.end;
+        self.doctype = Some(doctype);
+        Control::Continue
+    }
+
+    fn processing_instruction(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.position = span.end;
+        if !self.count_node(span) {
+            return Control::Stop;
+        }
+        // Only the first token of the input can be the XML declaration
+        let declaration = match span.start.offset {
+            0 => read_xml_declaration(&content),
+            _ => None,
+        };
+        if declaration.is_some() {
+            self.xml_declaration = declaration;
+            self.declaration_end = Some(span.end.offset);
+        } else {
+            self.current().add_processing_instruction(content);
+        }
+
+        Control::Continue
+    }
+
+    fn element_start(
+        &mut self,
+        name: Cow<str>,
+        attributes: TagAttributes,
+        autoclose: bool,
+        raw_content: Option<Cow<str>>,
+        span: Span,
+    ) -> Control {
+        let (name, attributes) = (name.into_owned(), Attributes::from(attributes));
+        self.position = span.end;
+        self.foster_parenting = false;
+
+        if !self.count_node(span) {
+            return Control::Stop;
+        }
+
+        if self.is_xml {
+            self.xml_element_start(name, attributes, autoclose, span);
+            return Control::Continue;
+        }
+
+        if !self.before_start_tag(&name, &attributes, span) {
+            return Control::Continue;
+        }
+
+        if !self.table_start_tag(&name, &attributes, span) {
+            return Control::Continue;
+        }
+
+        self.close_implied_by_start_tag(&name, span);
+
+        match name.as_str() {
+            "a" => {
+                if let Some((_, id)) = self.find_formatting("a") {
+                    let current = &self.stack[self.stack.len() - 1];
+                    if current.id == id {
+                        if let Some(span) = current.span {
+                            self.errors.push(ParseError::UnclosedElement { name: name.clone(), span });
+                        }
+                    }
+
+                    self.adopt("a", span, false);
+
+                    // The element is only removed from the list if it couldn't be closed
+                    if let Some(position) = self.formatting_position(id) {
+                        self.formatting.remove(position);
+                    }
+                }
+            }
+            "nobr" if self.in_scope("nobr", Scope::Default) => {
+                if let Some(span) = self.stack.iter().rev().find(|e| e.name == "nobr").and_then(|e| e.span) {
+                    self.errors.push(ParseError::UnclosedElement { name: name.clone(), span });
+                }
+                self.reconstruct_formatting(span);
+                self.adopt("nobr", span, false);
+            }
+            _ => {}
+        }
+
+        if reopens_formatting(&name) {
+            self.reconstruct_formatting(span);
+        }
+
+        self.limit_depth(&name, span);
+
+        let mut e = Element::new_with_attributes(name, attributes);
+        e.autoclosed = autoclose;
+
+        if autoclose {
+            e.span = self.span(span);
+            self.insertion_target().add_element(e);
+        } else if let Some(raw_content) = raw_content {
+            e.span = self.span(span);
+            if is_rcdata_element(&e.name) {
+                if !raw_content.is_empty() {
+                    e.nodes.push(Node::Text(Text::new(raw_content)));
+                }
+            } else {
+                e.nodes.push(Node::Raw(Raw::new(raw_content)));
+            }
+            self.insertion_target().add_element(e);
+        } else {
+            e.span = Some(span);
+
+            if is_formatting_element(&e.name) {
+                let (name, attributes) = (e.name.clone(), e.attributes.clone());
+                let id = self.open(e, span);
+                self.push_formatting(id, &name, &attributes);
+            } else {
+                if inserts_marker(&e.name) {
+                    self.formatting.push(Formatting::Marker);
+                }
+                self.open(e, span);
+            }
+        }
+
+        Control::Continue
+    }
+
+    fn element_end(&mut self, name: &str, span: Span) -> Control {
+        self.position = span.end;
+        self.foster_parenting = false;
+
+        if self.is_xml {
+            self.xml_element_end(name, span);
+            return Control::Continue;
+        }
+
+        if !self.table_end_tag(name, span) || !self.before_end_tag(name, span) {
+            return Control::Continue;
+        }
+
+        match name {
+            "p" => {
+                if !self.in_scope("p", Scope::Button) {
+                    // Like browsers an empty paragraph is created for a stray end tag
+                    self.unexpected_end_tag(name, span);
+                    let mut p = Element::new("p");
+                    p.span = Some(span);
+                    self.open(p, span);
+                }
+
+                self.generate_implied_end_tags(&["p"], span.start);
+                self.close_until("p", span, true);
+            }
+            "br" => {
+                // A stray </br> is treated like <br>
+                self.unexpected_end_tag(name, span);
+                let mut br = Element::new("br");
+                br.span = self.span(span);
+                self.insertion_target().add_element(br);
+            }
+            _ if is_heading(name) => {
+                let heading = if ["h1", "h2", "h3", "h4", "h5", "h6"].iter().any(|heading| self.has_open(heading)) {
+                    self.stack.iter()
+                        .rev()
+                        .take_while(|e| !Scope::Default.is_boundary(&e.name))
+                        .find(|e| is_heading(&e.name))
+                        .map(|e| e.name.clone())
+                } else {
+                    None
+                };
+
+                match heading {
+                    Some(heading) => {
+                        self.generate_implied_end_tags(&[], span.start);
+                        self.close_until(&heading, span, heading == name);
+                    }
+                    None => self.unexpected_end_tag(name, span),
+                }
+            }
+            _ if is_formatting_element(name) => self.adopt(name, span, true),
+            _ => match end_tag_scope(name) {
+                Some(scope) => self.close_in_scope(name, scope, span),
+                None => self.close_nearest(name, span),
+            },
+        }
+
+        Control::Continue
+    }
+
+    fn error(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+}
@@ -0,0 +1,272 @@
+//! Detect the character encoding of HTML bytes
+//!
+//! The encoding is determined in this order:
+//!
+//! 1. A byte order mark
+//! 2. An encoding hint supplied by the caller, i.e. the charset of a HTTP `Content-Type` header
+//! 3. A `<meta charset>` or `<meta http-equiv="Content-Type">` element in the first 1024 bytes
+//! 4. UTF-8 if the input is valid UTF-8 and windows-1252 otherwise
+
+use std::borrow::Cow;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252, X_USER_DEFINED};
+
+/// Number of bytes searched for a meta element
+const PRESCAN_LENGTH: usize = 1024;
+
+/// Returns the name of the detected encoding of the HTML
+///
+/// The hint is an encoding label like `latin1` or `utf-8`. Unknown labels are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::encoding::detect_encoding;
+///
+/// assert_eq!(detect_encoding(b"<meta charset=\"latin1\"><p>\xe4</p>", None), "windows-1252");
+/// assert_eq!(detect_encoding(b"<p>\xe4</p>", Some("shift_jis")), "Shift_JIS");
+/// ```
+pub fn detect_encoding(bytes: &[u8], hint: Option<&str>) -> &'static str {
+    sniff(bytes, hint).0.name()
+}
+
+/// Decodes HTML bytes into a string
+///
+/// Returns the decoded string and the name of the used encoding. Malformed byte sequences are
+/// replaced with U+FFFD.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::encoding::decode;
+///
+/// let (html, encoding) = decode(b"<p>\xe4</p>", None);
+///
+/// assert_eq!(html, "<p>ä</p>");
+/// assert_eq!(encoding, "windows-1252");
+/// ```
+pub fn decode<'a>(bytes: &'a [u8], hint: Option<&str>) -> (Cow<'a, str>, &'static str) {
+    let (encoding, bom_length) = sniff(bytes, hint);
+    let (content, _) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+
+    (content, encoding.name())
+}
+
+/// Returns the encoding and the length of the byte order mark
+fn sniff(bytes: &[u8], hint: Option<&str>) -> (&'static Encoding, usize) {
+    if let Some(result) = Encoding::for_bom(bytes) {
+        return result;
+    }
+
+    if let Some(encoding) = hint.and_then(|hint| Encoding::for_label(hint.trim().as_bytes())) {
+        return (encoding, 0);
+    }
+
+    if let Some(encoding) = prescan(&bytes[..bytes.len().min(PRESCAN_LENGTH)]) {
+        return (encoding, 0);
+    }
+
+    if std::str::from_utf8(bytes).is_ok() {
+        (UTF_8, 0)
+    } else {
+        (WINDOWS_1252, 0)
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+fn starts_with_ignore_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn find(bytes: &[u8], pattern: &[u8]) -> Option<usize> {
+    bytes.windows(pattern.len()).position(|w| w == pattern)
+}
+
+/// Searches for the encoding declared by a meta element
+///
+/// This is a simplified version of the prescan algorithm of the HTML specification.
+fn prescan(bytes: &[u8]) -> Option<&'static Encoding> {
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let rest = &bytes[pos..];
+
+        if rest.starts_with(b"<!--") {
+            pos += find(&rest[4..], b"-->").map_or(rest.len(), |end| end + 7);
+        } else if starts_with_ignore_case(rest, b"<meta")
+            && rest.get(5).is_some_and(|&b| is_whitespace(b) || b == b'/') {
+            pos += 5;
+            if let Some(encoding) = prescan_meta(bytes, &mut pos) {
+                return Some(encoding);
+            }
+        } else if rest.len() > 1 && rest[0] == b'<'
+            && (rest[1].is_ascii_alphabetic() || (rest[1] == b'/' && rest.get(2).is_some_and(u8::is_ascii_alphabetic))) {
+            pos += rest.iter().position(|&b| is_whitespace(b) || b == b'>').unwrap_or(rest.len());
+            while get_attribute(bytes, &mut pos).is_some() {}
+        } else if rest.starts_with(b"<!") || rest.starts_with(b"</") || rest.starts_with(b"<?") {
+            pos += rest.iter().position(|&b| b == b'>').map_or(rest.len(), |end| end + 1);
+        } else {
+            pos += 1;
+        }
+    }
+
+    None
+}
+
+/// Reads the attributes of a meta element and returns the declared encoding
+fn prescan_meta(bytes: &[u8], pos: &mut usize) -> Option<&'static Encoding> {
+    let mut seen = Vec::new();
+    let mut need_pragma = None;
+    let mut charset = None;
+    let mut got_pragma = false;
+
+    while let Some((name, value)) = get_attribute(bytes, pos) {
+        if seen.contains(&name) {
+            continue;
+        }
+
+        match name.as_slice() {
+            b"http-equiv" if value == b"content-type" => got_pragma = true,
+            b"content" if charset.is_none() => {
+                if let Some(encoding) = charset_from_content(&value) {
+                    charset = Some(encoding);
+                    need_pragma = Some(true);
+                }
+            }
+            b"charset" => {
+                charset = Encoding::for_label(&value);
+                need_pragma = Some(false);
+            }
+            _ => {}
+        }
+
+        seen.push(name);
+    }
+
+    let charset = match need_pragma {
+        Some(true) if !got_pragma => return None,
+        Some(_) => charset?,
+        None => return None,
+    };
+
+    if charset == UTF_16BE || charset == UTF_16LE {
+        Some(UTF_8)
+    } else if charset == X_USER_DEFINED {
+        Some(WINDOWS_1252)
+    } else {
+        Some(charset)
+    }
+}
+
+/// Extracts the charset from a content attribute like `text/html; charset=utf-8`
+fn charset_from_content(content: &[u8]) -> Option<&'static Encoding> {
+    let lower = content.to_ascii_lowercase();
+    let mut pos = 0;
+
+    loop {
+        pos += find(&lower[pos..], b"charset")? + 7;
+
+        let mut i = pos;
+        while i < lower.len() && is_whitespace(lower[i]) {
+            i += 1;
+        }
+
+        if lower.get(i) != Some(&b'=') {
+            continue;
+        }
+
+        i += 1;
+        while i < lower.len() && is_whitespace(lower[i]) {
+            i += 1;
+        }
+
+        let rest = &content[i..];
+
+        return match rest.first() {
+            Some(&quote) if quote == b'"' || quote == b'\'' => {
+                let end = rest[1..].iter().position(|&b| b == quote)?;
+                Encoding::for_label(&rest[1..=end])
+            }
+            Some(_) => {
+                let end = rest.iter().position(|&b| is_whitespace(b) || b == b';').unwrap_or(rest.len());
+                Encoding::for_label(&rest[..end])
+            }
+            None => None,
+        };
+    }
+}
+
+/// Reads the next attribute of a tag
+///
+/// Returns `None` at the end of the tag. Names are lowercase.
+fn get_attribute(bytes: &[u8], pos: &mut usize) -> Option<(Vec<u8>, Vec<u8>)> {
+    let next = |pos: &mut usize| -> Option<u8> {
+        let byte = bytes.get(*pos).cloned();
+        *pos += 1;
+        byte
+    };
+
+    while bytes.get(*pos).is_some_and(|&b| is_whitespace(b) || b == b'/') {
+        *pos += 1;
+    }
+
+    if bytes.get(*pos).is_none_or(|&b| b == b'>') {
+        *pos += 1;
+        return None;
+    }
+
+    let mut name = Vec::new();
+    let mut value = Vec::new();
+
+    loop {
+        match next(pos)? {
+            b'=' if !name.is_empty() => break,
+            b if is_whitespace(b) => {
+                while bytes.get(*pos).is_some_and(|&b| is_whitespace(b)) {
+                    *pos += 1;
+                }
+                if bytes.get(*pos) != Some(&b'=') {
+                    return Some((name, value));
+                }
+                *pos += 1;
+                break;
+            }
+            b'/' | b'>' => {
+                *pos -= 1;
+                return Some((name, value));
+            }
+            b => name.push(b.to_ascii_lowercase()),
+        }
+    }
+
+    while bytes.get(*pos).is_some_and(|&b| is_whitespace(b)) {
+        *pos += 1;
+    }
+
+    match next(pos)? {
+        quote @ b'"' | quote @ b'\'' => loop {
+            match next(pos)? {
+                b if b == quote => return Some((name, value)),
+                b => value.push(b.to_ascii_lowercase()),
+            }
+        },
+        b'>' => {
+            *pos -= 1;
+            Some((name, value))
+        }
+        b => {
+            value.push(b.to_ascii_lowercase());
+            loop {
+                match bytes.get(*pos) {
+                    Some(&b) if is_whitespace(b) || b == b'>' => return Some((name, value)),
+                    Some(&b) => value.push(b.to_ascii_lowercase()),
+                    None => return None,
+                }
+                *pos += 1;
+            }
+        }
+    }
+}
@@ -1,2037 +1,2131 @@
-
-fn create_entity_map() -> HashMap<&'static str, char> {
-    let mut map = HashMap::new();
-    map.insert("AElig", '\u{000C6}');
-    map.insert("AMP", '\u{00026}');
-    map.insert("Aacute", '\u{000C1}');
-    map.insert("Abreve", '\u{00102}');
-    map.insert("Acirc", '\u{000C2}');
-    map.insert("Acy", '\u{00410}');
-    map.insert("Afr", '\u{1D504}');
-    map.insert("Agrave", '\u{000C0}');
-    map.insert("Alpha", '\u{00391}');
-    map.insert("Amacr", '\u{00100}');
-    map.insert("And", '\u{02A53}');
-    map.insert("Aogon", '\u{00104}');
-    map.insert("Aopf", '\u{1D538}');
-    map.insert("ApplyFunction", '\u{02061}');
-    map.insert("Aring", '\u{000C5}');
-    map.insert("Ascr", '\u{1D49C}');
-    map.insert("Assign", '\u{02254}');
-    map.insert("Atilde", '\u{000C3}');
-    map.insert("Auml", '\u{000C4}');
-    map.insert("Backslash", '\u{02216}');
-    map.insert("Barv", '\u{02AE7}');
-    map.insert("Barwed", '\u{02306}');
-    map.insert("Bcy", '\u{00411}');
-    map.insert("Because", '\u{02235}');
-    map.insert("Bernoullis", '\u{0212C}');
-    map.insert("Beta", '\u{00392}');
-    map.insert("Bfr", '\u{1D505}');
-    map.insert("Bopf", '\u{1D539}');
-    map.insert("Breve", '\u{002D8}');
-    map.insert("Bscr", '\u{0212C}');
-    map.insert("Bumpeq", '\u{0224E}');
-    map.insert("CHcy", '\u{00427}');
-    map.insert("COPY", '\u{000A9}');
-    map.insert("Cacute", '\u{00106}');
-    map.insert("Cap", '\u{022D2}');
-    map.insert("CapitalDifferentialD", '\u{02145}');
-    map.insert("Cayleys", '\u{0212D}');
-    map.insert("Ccaron", '\u{0010C}');
-    map.insert("Ccedil", '\u{000C7}');
-    map.insert("Ccirc", '\u{00108}');
-    map.insert("Cconint", '\u{02230}');
-    map.insert("Cdot", '\u{0010A}');
-    map.insert("Cedilla", '\u{000B8}');
-    map.insert("CenterDot", '\u{000B7}');
-    map.insert("Cfr", '\u{0212D}');
-    map.insert("Chi", '\u{003A7}');
-    map.insert("CircleDot", '\u{02299}');
-    map.insert("CircleMinus", '\u{02296}');
-    map.insert("CirclePlus", '\u{02295}');
-    map.insert("CircleTimes", '\u{02297}');
-    map.insert("ClockwiseContourIntegral", '\u{02232}');
-    map.insert("CloseCurlyDoubleQuote", '\u{0201D}');
-    map.insert("CloseCurlyQuote", '\u{02019}');
-    map.insert("Colon", '\u{02237}');
-    map.insert("Colone", '\u{02A74}');
-    map.insert("Congruent", '\u{02261}');
-    map.insert("Conint", '\u{0222F}');
-    map.insert("ContourIntegral", '\u{0222E}');
-    map.insert("Copf", '\u{02102}');
-    map.insert("Coproduct", '\u{02210}');
-    map.insert("CounterClockwiseContourIntegral", '\u{02233}');
-    map.insert("Cross", '\u{02A2F}');
-    map.insert("Cscr", '\u{1D49E}');
-    map.insert("Cup", '\u{022D3}');
-    map.insert("CupCap", '\u{0224D}');
-    map.insert("DD", '\u{02145}');
-    map.insert("DDotrahd", '\u{02911}');
-    map.insert("DJcy", '\u{00402}');
-    map.insert("DScy", '\u{00405}');
-    map.insert("DZcy", '\u{0040F}');
-    map.insert("Dagger", '\u{02021}');
-    map.insert("Darr", '\u{021A1}');
-    map.insert("Dashv", '\u{02AE4}');
-    map.insert("Dcaron", '\u{0010E}');
-    map.insert("Dcy", '\u{00414}');
-    map.insert("Del", '\u{02207}');
-    map.insert("Delta", '\u{00394}');
-    map.insert("Dfr", '\u{1D507}');
-    map.insert("DiacriticalAcute", '\u{000B4}');
-    map.insert("DiacriticalDot", '\u{002D9}');
-    map.insert("DiacriticalDoubleAcute", '\u{002DD}');
-    map.insert("DiacriticalGrave", '\u{00060}');
-    map.insert("DiacriticalTilde", '\u{002DC}');
-    map.insert("Diamond", '\u{022C4}');
-    map.insert("DifferentialD", '\u{02146}');
-    map.insert("Dopf", '\u{1D53B}');
-    map.insert("Dot", '\u{000A8}');
-    map.insert("DotDot", '\u{020DC}');
-    map.insert("DotEqual", '\u{02250}');
-    map.insert("DoubleContourIntegral", '\u{0222F}');
-    map.insert("DoubleDot", '\u{000A8}');
-    map.insert("DoubleDownArrow", '\u{021D3}');
-    map.insert("DoubleLeftArrow", '\u{021D0}');
-    map.insert("DoubleLeftRightArrow", '\u{021D4}');
-    map.insert("DoubleLeftTee", '\u{02AE4}');
-    map.insert("DoubleLongLeftArrow", '\u{027F8}');
-    map.insert("DoubleLongLeftRightArrow", '\u{027FA}');
-    map.insert("DoubleLongRightArrow", '\u{027F9}');
-    map.insert("DoubleRightArrow", '\u{021D2}');
-    map.insert("DoubleRightTee", '\u{022A8}');
-    map.insert("DoubleUpArrow", '\u{021D1}');
-    map.insert("DoubleUpDownArrow", '\u{021D5}');
-    map.insert("DoubleVerticalBar", '\u{02225}');
-    map.insert("DownArrow", '\u{02193}');
-    map.insert("DownArrowBar", '\u{02913}');
-    map.insert("DownArrowUpArrow", '\u{021F5}');
-    map.insert("DownBreve", '\u{00311}');
-    map.insert("DownLeftRightVector", '\u{02950}');
-    map.insert("DownLeftTeeVector", '\u{0295E}');
-    map.insert("DownLeftVector", '\u{021BD}');
-    map.insert("DownLeftVectorBar", '\u{02956}');
-    map.insert("DownRightTeeVector", '\u{0295F}');
-    map.insert("DownRightVector", '\u{021C1}');
-    map.insert("DownRightVectorBar", '\u{02957}');
-    map.insert("DownTee", '\u{022A4}');
-    map.insert("DownTeeArrow", '\u{021A7}');
-    map.insert("Downarrow", '\u{021D3}');
-    map.insert("Dscr", '\u{1D49F}');
-    map.insert("Dstrok", '\u{00110}');
-    map.insert("ENG", '\u{0014A}');
-    map.insert("ETH", '\u{000D0}');
-    map.insert("Eacute", '\u{000C9}');
-    map.insert("Ecaron", '\u{0011A}');
-    map.insert("Ecirc", '\u{000CA}');
-    map.insert("Ecy", '\u{0042D}');
-    map.insert("Edot", '\u{00116}');
-    map.insert("Efr", '\u{1D508}');
-    map.insert("Egrave", '\u{000C8}');
-    map.insert("Element", '\u{02208}');
-    map.insert("Emacr", '\u{00112}');
-    map.insert("EmptySmallSquare", '\u{025FB}');
-    map.insert("EmptyVerySmallSquare", '\u{025AB}');
-    map.insert("Eogon", '\u{00118}');
-    map.insert("Eopf", '\u{1D53C}');
-    map.insert("Epsilon", '\u{00395}');
-    map.insert("Equal", '\u{02A75}');
-    map.insert("EqualTilde", '\u{02242}');
-    map.insert("Equilibrium", '\u{021CC}');
-    map.insert("Escr", '\u{02130}');
-    map.insert("Esim", '\u{02A73}');
-    map.insert("Eta", '\u{00397}');
-    map.insert("Euml", '\u{000CB}');
-    map.insert("Exists", '\u{02203}');
-    map.insert("ExponentialE", '\u{02147}');
-    map.insert("Fcy", '\u{00424}');
-    map.insert("Ffr", '\u{1D509}');
-    map.insert("FilledSmallSquare", '\u{025FC}');
-    map.insert("FilledVerySmallSquare", '\u{025AA}');
-    map.insert("Fopf", '\u{1D53D}');
-    map.insert("ForAll", '\u{02200}');
-    map.insert("Fouriertrf", '\u{02131}');
-    map.insert("Fscr", '\u{02131}');
-    map.insert("GJcy", '\u{00403}');
-    map.insert("GT", '\u{0003E}');
-    map.insert("Gamma", '\u{00393}');
-    map.insert("Gammad", '\u{003DC}');
-    map.insert("Gbreve", '\u{0011E}');
-    map.insert("Gcedil", '\u{00122}');
-    map.insert("Gcirc", '\u{0011C}');
-    map.insert("Gcy", '\u{00413}');
-    map.insert("Gdot", '\u{00120}');
-    map.insert("Gfr", '\u{1D50A}');
-    map.insert("Gg", '\u{022D9}');
-    map.insert("Gopf", '\u{1D53E}');
-    map.insert("GreaterEqual", '\u{02265}');
-    map.insert("GreaterEqualLess", '\u{022DB}');
-    map.insert("GreaterFullEqual", '\u{02267}');
-    map.insert("GreaterGreater", '\u{02AA2}');
-    map.insert("GreaterLess", '\u{02277}');
-    map.insert("GreaterSlantEqual", '\u{02A7E}');
-    map.insert("GreaterTilde", '\u{02273}');
-    map.insert("Gscr", '\u{1D4A2}');
-    map.insert("Gt", '\u{0226B}');
-    map.insert("HARDcy", '\u{0042A}');
-    map.insert("Hacek", '\u{002C7}');
-    map.insert("Hat", '\u{0005E}');
-    map.insert("Hcirc", '\u{00124}');
-    map.insert("Hfr", '\u{0210C}');
-    map.insert("HilbertSpace", '\u{0210B}');
-    map.insert("Hopf", '\u{0210D}');
-    map.insert("HorizontalLine", '\u{02500}');
-    map.insert("Hscr", '\u{0210B}');
-    map.insert("Hstrok", '\u{00126}');
-    map.insert("HumpDownHump", '\u{0224E}');
-    map.insert("HumpEqual", '\u{0224F}');
-    map.insert("IEcy", '\u{00415}');
-    map.insert("IJlig", '\u{00132}');
-    map.insert("IOcy", '\u{00401}');
-    map.insert("Iacute", '\u{000CD}');
-    map.insert("Icirc", '\u{000CE}');
-    map.insert("Icy", '\u{00418}');
-    map.insert("Idot", '\u{00130}');
-    map.insert("Ifr", '\u{02111}');
-    map.insert("Igrave", '\u{000CC}');
-    map.insert("Im", '\u{02111}');
-    map.insert("Imacr", '\u{0012A}');
-    map.insert("ImaginaryI", '\u{02148}');
-    map.insert("Implies", '\u{021D2}');
-    map.insert("Int", '\u{0222C}');
-    map.insert("Integral", '\u{0222B}');
-    map.insert("Intersection", '\u{022C2}');
-    map.insert("InvisibleComma", '\u{02063}');
-    map.insert("InvisibleTimes", '\u{02062}');
-    map.insert("Iogon", '\u{0012E}');
-    map.insert("Iopf", '\u{1D540}');
-    map.insert("Iota", '\u{00399}');
-    map.insert("Iscr", '\u{02110}');
-    map.insert("Itilde", '\u{00128}');
-    map.insert("Iukcy", '\u{00406}');
-    map.insert("Iuml", '\u{000CF}');
-    map.insert("Jcirc", '\u{00134}');
-    map.insert("Jcy", '\u{00419}');
-    map.insert("Jfr", '\u{1D50D}');
-    map.insert("Jopf", '\u{1D541}');
-    map.insert("Jscr", '\u{1D4A5}');
-    map.insert("Jsercy", '\u{00408}');
-    map.insert("Jukcy", '\u{00404}');
-    map.insert("KHcy", '\u{00425}');
-    map.insert("KJcy", '\u{0040C}');
-    map.insert("Kappa", '\u{0039A}');
-    map.insert("Kcedil", '\u{00136}');
-    map.insert("Kcy", '\u{0041A}');
-    map.insert("Kfr", '\u{1D50E}');
-    map.insert("Kopf", '\u{1D542}');
-    map.insert("Kscr", '\u{1D4A6}');
-    map.insert("LJcy", '\u{00409}');
-    map.insert("LT", '\u{0003C}');
-    map.insert("Lacute", '\u{00139}');
-    map.insert("Lambda", '\u{0039B}');
-    map.insert("Lang", '\u{027EA}');
-    map.insert("Laplacetrf", '\u{02112}');
-    map.insert("Larr", '\u{0219E}');
-    map.insert("Lcaron", '\u{0013D}');
-    map.insert("Lcedil", '\u{0013B}');
-    map.insert("Lcy", '\u{0041B}');
-    map.insert("LeftAngleBracket", '\u{027E8}');
-    map.insert("LeftArrow", '\u{02190}');
-    map.insert("LeftArrowBar", '\u{021E4}');
-    map.insert("LeftArrowRightArrow", '\u{021C6}');
-    map.insert("LeftCeiling", '\u{02308}');
-    map.insert("LeftDoubleBracket", '\u{027E6}');
-    map.insert("LeftDownTeeVector", '\u{02961}');
-    map.insert("LeftDownVector", '\u{021C3}');
-    map.insert("LeftDownVectorBar", '\u{02959}');
-    map.insert("LeftFloor", '\u{0230A}');
-    map.insert("LeftRightArrow", '\u{02194}');
-    map.insert("LeftRightVector", '\u{0294E}');
-    map.insert("LeftTee", '\u{022A3}');
-    map.insert("LeftTeeArrow", '\u{021A4}');
-    map.insert("LeftTeeVector", '\u{0295A}');
-    map.insert("LeftTriangle", '\u{022B2}');
-    map.insert("LeftTriangleBar", '\u{029CF}');
-    map.insert("LeftTriangleEqual", '\u{022B4}');
-    map.insert("LeftUpDownVector", '\u{02951}');
-    map.insert("LeftUpTeeVector", '\u{02960}');
-    map.insert("LeftUpVector", '\u{021BF}');
-    map.insert("LeftUpVectorBar", '\u{02958}');
-    map.insert("LeftVector", '\u{021BC}');
-    map.insert("LeftVectorBar", '\u{02952}');
-    map.insert("Leftarrow", '\u{021D0}');
-    map.insert("Leftrightarrow", '\u{021D4}');
-    map.insert("LessEqualGreater", '\u{022DA}');
-    map.insert("LessFullEqual", '\u{02266}');
-    map.insert("LessGreater", '\u{02276}');
-    map.insert("LessLess", '\u{02AA1}');
-    map.insert("LessSlantEqual", '\u{02A7D}');
-    map.insert("LessTilde", '\u{02272}');
-    map.insert("Lfr", '\u{1D50F}');
-    map.insert("Ll", '\u{022D8}');
-    map.insert("Lleftarrow", '\u{021DA}');
-    map.insert("Lmidot", '\u{0013F}');
-    map.insert("LongLeftArrow", '\u{027F5}');
-    map.insert("LongLeftRightArrow", '\u{027F7}');
-    map.insert("LongRightArrow", '\u{027F6}');
-    map.insert("Longleftarrow", '\u{027F8}');
-    map.insert("Longleftrightarrow", '\u{027FA}');
-    map.insert("Longrightarrow", '\u{027F9}');
-    map.insert("Lopf", '\u{1D543}');
-    map.insert("LowerLeftArrow", '\u{02199}');
-    map.insert("LowerRightArrow", '\u{02198}');
-    map.insert("Lscr", '\u{02112}');
-    map.insert("Lsh", '\u{021B0}');
-    map.insert("Lstrok", '\u{00141}');
-    map.insert("Lt", '\u{0226A}');
-    map.insert("Map", '\u{02905}');
-    map.insert("Mcy", '\u{0041C}');
-    map.insert("MediumSpace", '\u{0205F}');
-    map.insert("Mellintrf", '\u{02133}');
-    map.insert("Mfr", '\u{1D510}');
-    map.insert("MinusPlus", '\u{02213}');
-    map.insert("Mopf", '\u{1D544}');
-    map.insert("Mscr", '\u{02133}');
-    map.insert("Mu", '\u{0039C}');
-    map.insert("NJcy", '\u{0040A}');
-    map.insert("Nacute", '\u{00143}');
-    map.insert("Ncaron", '\u{00147}');
-    map.insert("Ncedil", '\u{00145}');
-    map.insert("Ncy", '\u{0041D}');
-    map.insert("NegativeMediumSpace", '\u{0200B}');
-    map.insert("NegativeThickSpace", '\u{0200B}');
-    map.insert("NegativeThinSpace", '\u{0200B}');
-    map.insert("NegativeVeryThinSpace", '\u{0200B}');
-    map.insert("NestedGreaterGreater", '\u{0226B}');
-    map.insert("NestedLessLess", '\u{0226A}');
-    map.insert("NewLine", '\u{0000A}');
-    map.insert("Nfr", '\u{1D511}');
-    map.insert("NoBreak", '\u{02060}');
-    map.insert("NonBreakingSpace", '\u{000A0}');
-    map.insert("Nopf", '\u{02115}');
-    map.insert("Not", '\u{02AEC}');
-    map.insert("NotCongruent", '\u{02262}');
-    map.insert("NotCupCap", '\u{0226D}');
-    map.insert("NotDoubleVerticalBar", '\u{02226}');
-    map.insert("NotElement", '\u{02209}');
-    map.insert("NotEqual", '\u{02260}');
-    map.insert("NotExists", '\u{02204}');
-    map.insert("NotGreater", '\u{0226F}');
-    map.insert("NotGreaterEqual", '\u{02271}');
-    map.insert("NotGreaterLess", '\u{02279}');
-    map.insert("NotGreaterTilde", '\u{02275}');
-    map.insert("NotLeftTriangle", '\u{022EA}');
-    map.insert("NotLeftTriangleEqual", '\u{022EC}');
-    map.insert("NotLess", '\u{0226E}');
-    map.insert("NotLessEqual", '\u{02270}');
-    map.insert("NotLessGreater", '\u{02278}');
-    map.insert("NotLessTilde", '\u{02274}');
-    map.insert("NotPrecedes", '\u{02280}');
-    map.insert("NotPrecedesSlantEqual", '\u{022E0}');
-    map.insert("NotReverseElement", '\u{0220C}');
-    map.insert("NotRightTriangle", '\u{022EB}');
-    map.insert("NotRightTriangleEqual", '\u{022ED}');
-    map.insert("NotSquareSubsetEqual", '\u{022E2}');
-    map.insert("NotSquareSupersetEqual", '\u{022E3}');
-    map.insert("NotSubsetEqual", '\u{02288}');
-    map.insert("NotSucceeds", '\u{02281}');
-    map.insert("NotSucceedsSlantEqual", '\u{022E1}');
-    map.insert("NotSupersetEqual", '\u{02289}');
-    map.insert("NotTilde", '\u{02241}');
-    map.insert("NotTildeEqual", '\u{02244}');
-    map.insert("NotTildeFullEqual", '\u{02247}');
-    map.insert("NotTildeTilde", '\u{02249}');
-    map.insert("NotVerticalBar", '\u{02224}');
-    map.insert("Nscr", '\u{1D4A9}');
-    map.insert("Ntilde", '\u{000D1}');
-    map.insert("Nu", '\u{0039D}');
-    map.insert("OElig", '\u{00152}');
-    map.insert("Oacute", '\u{000D3}');
-    map.insert("Ocirc", '\u{000D4}');
-    map.insert("Ocy", '\u{0041E}');
-    map.insert("Odblac", '\u{00150}');
-    map.insert("Ofr", '\u{1D512}');
-    map.insert("Ograve", '\u{000D2}');
-    map.insert("Omacr", '\u{0014C}');
-    map.insert("Omega", '\u{003A9}');
-    map.insert("Omicron", '\u{0039F}');
-    map.insert("Oopf", '\u{1D546}');
-    map.insert("OpenCurlyDoubleQuote", '\u{0201C}');
-    map.insert("OpenCurlyQuote", '\u{02018}');
-    map.insert("Or", '\u{02A54}');
-    map.insert("Oscr", '\u{1D4AA}');
-    map.insert("Oslash", '\u{000D8}');
-    map.insert("Otilde", '\u{000D5}');
-    map.insert("Otimes", '\u{02A37}');
-    map.insert("Ouml", '\u{000D6}');
-    map.insert("OverBar", '\u{0203E}');
-    map.insert("OverBrace", '\u{023DE}');
-    map.insert("OverBracket", '\u{023B4}');
-    map.insert("OverParenthesis", '\u{023DC}');
-    map.insert("PartialD", '\u{02202}');
-    map.insert("Pcy", '\u{0041F}');
-    map.insert("Pfr", '\u{1D513}');
-    map.insert("Phi", '\u{003A6}');
-    map.insert("Pi", '\u{003A0}');
-    map.insert("PlusMinus", '\u{000B1}');
-    map.insert("Poincareplane", '\u{0210C}');
-    map.insert("Popf", '\u{02119}');
-    map.insert("Pr", '\u{02ABB}');
-    map.insert("Precedes", '\u{0227A}');
-    map.insert("PrecedesEqual", '\u{02AAF}');
-    map.insert("PrecedesSlantEqual", '\u{0227C}');
-    map.insert("PrecedesTilde", '\u{0227E}');
-    map.insert("Prime", '\u{02033}');
-    map.insert("Product", '\u{0220F}');
-    map.insert("Proportion", '\u{02237}');
-    map.insert("Proportional", '\u{0221D}');
-    map.insert("Pscr", '\u{1D4AB}');
-    map.insert("Psi", '\u{003A8}');
-    map.insert("QUOT", '\u{00022}');
-    map.insert("Qfr", '\u{1D514}');
-    map.insert("Qopf", '\u{0211A}');
-    map.insert("Qscr", '\u{1D4AC}');
-    map.insert("RBarr", '\u{02910}');
-    map.insert("REG", '\u{000AE}');
-    map.insert("Racute", '\u{00154}');
-    map.insert("Rang", '\u{027EB}');
-    map.insert("Rarr", '\u{021A0}');
-    map.insert("Rarrtl", '\u{02916}');
-    map.insert("Rcaron", '\u{00158}');
-    map.insert("Rcedil", '\u{00156}');
-    map.insert("Rcy", '\u{00420}');
-    map.insert("Re", '\u{0211C}');
-    map.insert("ReverseElement", '\u{0220B}');
-    map.insert("ReverseEquilibrium", '\u{021CB}');
-    map.insert("ReverseUpEquilibrium", '\u{0296F}');
-    map.insert("Rfr", '\u{0211C}');
-    map.insert("Rho", '\u{003A1}');
-    map.insert("RightAngleBracket", '\u{027E9}');
-    map.insert("RightArrow", '\u{02192}');
-    map.insert("RightArrowBar", '\u{021E5}');
-    map.insert("RightArrowLeftArrow", '\u{021C4}');
-    map.insert("RightCeiling", '\u{02309}');
-    map.insert("RightDoubleBracket", '\u{027E7}');
-    map.insert("RightDownTeeVector", '\u{0295D}');
-    map.insert("RightDownVector", '\u{021C2}');
-    map.insert("RightDownVectorBar", '\u{02955}');
-    map.insert("RightFloor", '\u{0230B}');
-    map.insert("RightTee", '\u{022A2}');
-    map.insert("RightTeeArrow", '\u{021A6}');
-    map.insert("RightTeeVector", '\u{0295B}');
-    map.insert("RightTriangle", '\u{022B3}');
-    map.insert("RightTriangleBar", '\u{029D0}');
-    map.insert("RightTriangleEqual", '\u{022B5}');
-    map.insert("RightUpDownVector", '\u{0294F}');
-    map.insert("RightUpTeeVector", '\u{0295C}');
-    map.insert("RightUpVector", '\u{021BE}');
-    map.insert("RightUpVectorBar", '\u{02954}');
-    map.insert("RightVector", '\u{021C0}');
-    map.insert("RightVectorBar", '\u{02953}');
-    map.insert("Rightarrow", '\u{021D2}');
-    map.insert("Ropf", '\u{0211D}');
-    map.insert("RoundImplies", '\u{02970}');
-    map.insert("Rrightarrow", '\u{021DB}');
-    map.insert("Rscr", '\u{0211B}');
-    map.insert("Rsh", '\u{021B1}');
-    map.insert("RuleDelayed", '\u{029F4}');
-    map.insert("SHCHcy", '\u{00429}');
-    map.insert("SHcy", '\u{00428}');
-    map.insert("SOFTcy", '\u{0042C}');
-    map.insert("Sacute", '\u{0015A}');
-    map.insert("Sc", '\u{02ABC}');
-    map.insert("Scaron", '\u{00160}');
-    map.insert("Scedil", '\u{0015E}');
-    map.insert("Scirc", '\u{0015C}');
-    map.insert("Scy", '\u{00421}');
-    map.insert("Sfr", '\u{1D516}');
-    map.insert("ShortDownArrow", '\u{02193}');
-    map.insert("ShortLeftArrow", '\u{02190}');
-    map.insert("ShortRightArrow", '\u{02192}');
-    map.insert("ShortUpArrow", '\u{02191}');
-    map.insert("Sigma", '\u{003A3}');
-    map.insert("SmallCircle", '\u{02218}');
-    map.insert("Sopf", '\u{1D54A}');
-    map.insert("Sqrt", '\u{0221A}');
-    map.insert("Square", '\u{025A1}');
-    map.insert("SquareIntersection", '\u{02293}');
-    map.insert("SquareSubset", '\u{0228F}');
-    map.insert("SquareSubsetEqual", '\u{02291}');
-    map.insert("SquareSuperset", '\u{02290}');
-    map.insert("SquareSupersetEqual", '\u{02292}');
-    map.insert("SquareUnion", '\u{02294}');
-    map.insert("Sscr", '\u{1D4AE}');
-    map.insert("Star", '\u{022C6}');
-    map.insert("Sub", '\u{022D0}');
-    map.insert("Subset", '\u{022D0}');
-    map.insert("SubsetEqual", '\u{02286}');
-    map.insert("Succeeds", '\u{0227B}');
-    map.insert("SucceedsEqual", '\u{02AB0}');
-    map.insert("SucceedsSlantEqual", '\u{0227D}');
-    map.insert("SucceedsTilde", '\u{0227F}');
-    map.insert("SuchThat", '\u{0220B}');
-    map.insert("Sum", '\u{02211}');
-    map.insert("Sup", '\u{022D1}');
-    map.insert("Superset", '\u{02283}');
-    map.insert("SupersetEqual", '\u{02287}');
-    map.insert("Supset", '\u{022D1}');
-    map.insert("THORN", '\u{000DE}');
-    map.insert("TRADE", '\u{02122}');
-    map.insert("TSHcy", '\u{0040B}');
-    map.insert("TScy", '\u{00426}');
-    map.insert("Tab", '\u{00009}');
-    map.insert("Tau", '\u{003A4}');
-    map.insert("Tcaron", '\u{00164}');
-    map.insert("Tcedil", '\u{00162}');
-    map.insert("Tcy", '\u{00422}');
-    map.insert("Tfr", '\u{1D517}');
-    map.insert("Therefore", '\u{02234}');
-    map.insert("Theta", '\u{00398}');
-    map.insert("ThinSpace", '\u{02009}');
-    map.insert("Tilde", '\u{0223C}');
-    map.insert("TildeEqual", '\u{02243}');
-    map.insert("TildeFullEqual", '\u{02245}');
-    map.insert("TildeTilde", '\u{02248}');
-    map.insert("Topf", '\u{1D54B}');
-    map.insert("TripleDot", '\u{020DB}');
-    map.insert("Tscr", '\u{1D4AF}');
-    map.insert("Tstrok", '\u{00166}');
-    map.insert("Uacute", '\u{000DA}');
-    map.insert("Uarr", '\u{0219F}');
-    map.insert("Uarrocir", '\u{02949}');
-    map.insert("Ubrcy", '\u{0040E}');
-    map.insert("Ubreve", '\u{0016C}');
-    map.insert("Ucirc", '\u{000DB}');
-    map.insert("Ucy", '\u{00423}');
-    map.insert("Udblac", '\u{00170}');
-    map.insert("Ufr", '\u{1D518}');
-    map.insert("Ugrave", '\u{000D9}');
-    map.insert("Umacr", '\u{0016A}');
-    map.insert("UnderBar", '\u{0005F}');
-    map.insert("UnderBrace", '\u{023DF}');
-    map.insert("UnderBracket", '\u{023B5}');
-    map.insert("UnderParenthesis", '\u{023DD}');
-    map.insert("Union", '\u{022C3}');
-    map.insert("UnionPlus", '\u{0228E}');
-    map.insert("Uogon", '\u{00172}');
-    map.insert("Uopf", '\u{1D54C}');
-    map.insert("UpArrow", '\u{02191}');
-    map.insert("UpArrowBar", '\u{02912}');
-    map.insert("UpArrowDownArrow", '\u{021C5}');
-    map.insert("UpDownArrow", '\u{02195}');
-    map.insert("UpEquilibrium", '\u{0296E}');
-    map.insert("UpTee", '\u{022A5}');
-    map.insert("UpTeeArrow", '\u{021A5}');
-    map.insert("Uparrow", '\u{021D1}');
-    map.insert("Updownarrow", '\u{021D5}');
-    map.insert("UpperLeftArrow", '\u{02196}');
-    map.insert("UpperRightArrow", '\u{02197}');
-    map.insert("Upsi", '\u{003D2}');
-    map.insert("Upsilon", '\u{003A5}');
-    map.insert("Uring", '\u{0016E}');
-    map.insert("Uscr", '\u{1D4B0}');
-    map.insert("Utilde", '\u{00168}');
-    map.insert("Uuml", '\u{000DC}');
-    map.insert("VDash", '\u{022AB}');
-    map.insert("Vbar", '\u{02AEB}');
-    map.insert("Vcy", '\u{00412}');
-    map.insert("Vdash", '\u{022A9}');
-    map.insert("Vdashl", '\u{02AE6}');
-    map.insert("Vee", '\u{022C1}');
-    map.insert("Verbar", '\u{02016}');
-    map.insert("Vert", '\u{02016}');
-    map.insert("VerticalBar", '\u{02223}');
-    map.insert("VerticalLine", '\u{0007C}');
-    map.insert("VerticalSeparator", '\u{02758}');
-    map.insert("VerticalTilde", '\u{02240}');
-    map.insert("VeryThinSpace", '\u{0200A}');
-    map.insert("Vfr", '\u{1D519}');
-    map.insert("Vopf", '\u{1D54D}');
-    map.insert("Vscr", '\u{1D4B1}');
-    map.insert("Vvdash", '\u{022AA}');
-    map.insert("Wcirc", '\u{00174}');
-    map.insert("Wedge", '\u{022C0}');
-    map.insert("Wfr", '\u{1D51A}');
-    map.insert("Wopf", '\u{1D54E}');
-    map.insert("Wscr", '\u{1D4B2}');
-    map.insert("Xfr", '\u{1D51B}');
-    map.insert("Xi", '\u{0039E}');
-    map.insert("Xopf", '\u{1D54F}');
-    map.insert("Xscr", '\u{1D4B3}');
-    map.insert("YAcy", '\u{0042F}');
-    map.insert("YIcy", '\u{00407}');
-    map.insert("YUcy", '\u{0042E}');
-    map.insert("Yacute", '\u{000DD}');
-    map.insert("Ycirc", '\u{00176}');
-    map.insert("Ycy", '\u{0042B}');
-    map.insert("Yfr", '\u{1D51C}');
-    map.insert("Yopf", '\u{1D550}');
-    map.insert("Yscr", '\u{1D4B4}');
-    map.insert("Yuml", '\u{00178}');
-    map.insert("ZHcy", '\u{00416}');
-    map.insert("Zacute", '\u{00179}');
-    map.insert("Zcaron", '\u{0017D}');
-    map.insert("Zcy", '\u{00417}');
-    map.insert("Zdot", '\u{0017B}');
-    map.insert("ZeroWidthSpace", '\u{0200B}');
-    map.insert("Zeta", '\u{00396}');
-    map.insert("Zfr", '\u{02128}');
-    map.insert("Zopf", '\u{02124}');
-    map.insert("Zscr", '\u{1D4B5}');
-    map.insert("aacute", '\u{000E1}');
-    map.insert("abreve", '\u{00103}');
-    map.insert("ac", '\u{0223E}');
-    map.insert("acd", '\u{0223F}');
-    map.insert("acirc", '\u{000E2}');
-    map.insert("acute", '\u{000B4}');
-    map.insert("acy", '\u{00430}');
-    map.insert("aelig", '\u{000E6}');
-    map.insert("af", '\u{02061}');
-    map.insert("afr", '\u{1D51E}');
-    map.insert("agrave", '\u{000E0}');
-    map.insert("alefsym", '\u{02135}');
-    map.insert("aleph", '\u{02135}');
-    map.insert("alpha", '\u{003B1}');
-    map.insert("amacr", '\u{00101}');
-    map.insert("amalg", '\u{02A3F}');
-    map.insert("amp", '\u{00026}');
-    map.insert("and", '\u{02227}');
-    map.insert("andand", '\u{02A55}');
-    map.insert("andd", '\u{02A5C}');
-    map.insert("andslope", '\u{02A58}');
-    map.insert("andv", '\u{02A5A}');
-    map.insert("ang", '\u{02220}');
-    map.insert("ange", '\u{029A4}');
-    map.insert("angle", '\u{02220}');
-    map.insert("angmsd", '\u{02221}');
-    map.insert("angmsdaa", '\u{029A8}');
-    map.insert("angmsdab", '\u{029A9}');
-    map.insert("angmsdac", '\u{029AA}');
-    map.insert("angmsdad", '\u{029AB}');
-    map.insert("angmsdae", '\u{029AC}');
-    map.insert("angmsdaf", '\u{029AD}');
-    map.insert("angmsdag", '\u{029AE}');
-    map.insert("angmsdah", '\u{029AF}');
-    map.insert("angrt", '\u{0221F}');
-    map.insert("angrtvb", '\u{022BE}');
-    map.insert("angrtvbd", '\u{0299D}');
-    map.insert("angsph", '\u{02222}');
-    map.insert("angst", '\u{000C5}');
-    map.insert("angzarr", '\u{0237C}');
-    map.insert("aogon", '\u{00105}');
-    map.insert("aopf", '\u{1D552}');
-    map.insert("ap", '\u{02248}');
-    map.insert("apE", '\u{02A70}');
-    map.insert("apacir", '\u{02A6F}');
-    map.insert("ape", '\u{0224A}');
-    map.insert("apid", '\u{0224B}');
-    map.insert("apos", '\u{00027}');
-    map.insert("approx", '\u{02248}');
-    map.insert("approxeq", '\u{0224A}');
-    map.insert("aring", '\u{000E5}');
-    map.insert("ascr", '\u{1D4B6}');
-    map.insert("ast", '\u{0002A}');
-    map.insert("asymp", '\u{02248}');
-    map.insert("asympeq", '\u{0224D}');
-    map.insert("atilde", '\u{000E3}');
-    map.insert("auml", '\u{000E4}');
-    map.insert("awconint", '\u{02233}');
-    map.insert("awint", '\u{02A11}');
-    map.insert("bNot", '\u{02AED}');
-    map.insert("backcong", '\u{0224C}');
-    map.insert("backepsilon", '\u{003F6}');
-    map.insert("backprime", '\u{02035}');
-    map.insert("backsim", '\u{0223D}');
-    map.insert("backsimeq", '\u{022CD}');
-    map.insert("barvee", '\u{022BD}');
-    map.insert("barwed", '\u{02305}');
-    map.insert("barwedge", '\u{02305}');
-    map.insert("bbrk", '\u{023B5}');
-    map.insert("bbrktbrk", '\u{023B6}');
-    map.insert("bcong", '\u{0224C}');
-    map.insert("bcy", '\u{00431}');
-    map.insert("bdquo", '\u{0201E}');
-    map.insert("becaus", '\u{02235}');
-    map.insert("because", '\u{02235}');
-    map.insert("bemptyv", '\u{029B0}');
-    map.insert("bepsi", '\u{003F6}');
-    map.insert("bernou", '\u{0212C}');
-    map.insert("beta", '\u{003B2}');
-    map.insert("beth", '\u{02136}');
-    map.insert("between", '\u{0226C}');
-    map.insert("bfr", '\u{1D51F}');
-    map.insert("bigcap", '\u{022C2}');
-    map.insert("bigcirc", '\u{025EF}');
-    map.insert("bigcup", '\u{022C3}');
-    map.insert("bigodot", '\u{02A00}');
-    map.insert("bigoplus", '\u{02A01}');
-    map.insert("bigotimes", '\u{02A02}');
-    map.insert("bigsqcup", '\u{02A06}');
-    map.insert("bigstar", '\u{02605}');
-    map.insert("bigtriangledown", '\u{025BD}');
-    map.insert("bigtriangleup", '\u{025B3}');
-    map.insert("biguplus", '\u{02A04}');
-    map.insert("bigvee", '\u{022C1}');
-    map.insert("bigwedge", '\u{022C0}');
-    map.insert("bkarow", '\u{0290D}');
-    map.insert("blacklozenge", '\u{029EB}');
-    map.insert("blacksquare", '\u{025AA}');
-    map.insert("blacktriangle", '\u{025B4}');
-    map.insert("blacktriangledown", '\u{025BE}');
-    map.insert("blacktriangleleft", '\u{025C2}');
-    map.insert("blacktriangleright", '\u{025B8}');
-    map.insert("blank", '\u{02423}');
-    map.insert("blk12", '\u{02592}');
-    map.insert("blk14", '\u{02591}');
-    map.insert("blk34", '\u{02593}');
-    map.insert("block", '\u{02588}');
-    map.insert("bnot", '\u{02310}');
-    map.insert("bopf", '\u{1D553}');
-    map.insert("bot", '\u{022A5}');
-    map.insert("bottom", '\u{022A5}');
-    map.insert("bowtie", '\u{022C8}');
-    map.insert("boxDL", '\u{02557}');
-    map.insert("boxDR", '\u{02554}');
-    map.insert("boxDl", '\u{02556}');
-    map.insert("boxDr", '\u{02553}');
-    map.insert("boxH", '\u{02550}');
-    map.insert("boxHD", '\u{02566}');
-    map.insert("boxHU", '\u{02569}');
-    map.insert("boxHd", '\u{02564}');
-    map.insert("boxHu", '\u{02567}');
-    map.insert("boxUL", '\u{0255D}');
-    map.insert("boxUR", '\u{0255A}');
-    map.insert("boxUl", '\u{0255C}');
-    map.insert("boxUr", '\u{02559}');
-    map.insert("boxV", '\u{02551}');
-    map.insert("boxVH", '\u{0256C}');
-    map.insert("boxVL", '\u{02563}');
-    map.insert("boxVR", '\u{02560}');
-    map.insert("boxVh", '\u{0256B}');
-    map.insert("boxVl", '\u{02562}');
-    map.insert("boxVr", '\u{0255F}');
-    map.insert("boxbox", '\u{029C9}');
-    map.insert("boxdL", '\u{02555}');
-    map.insert("boxdR", '\u{02552}');
-    map.insert("boxdl", '\u{02510}');
-    map.insert("boxdr", '\u{0250C}');
-    map.insert("boxh", '\u{02500}');
-    map.insert("boxhD", '\u{02565}');
-    map.insert("boxhU", '\u{02568}');
-    map.insert("boxhd", '\u{0252C}');
-    map.insert("boxhu", '\u{02534}');
-    map.insert("boxminus", '\u{0229F}');
-    map.insert("boxplus", '\u{0229E}');
-    map.insert("boxtimes", '\u{022A0}');
-    map.insert("boxuL", '\u{0255B}');
-    map.insert("boxuR", '\u{02558}');
-    map.insert("boxul", '\u{02518}');
-    map.insert("boxur", '\u{02514}');
-    map.insert("boxv", '\u{02502}');
-    map.insert("boxvH", '\u{0256A}');
-    map.insert("boxvL", '\u{02561}');
-    map.insert("boxvR", '\u{0255E}');
-    map.insert("boxvh", '\u{0253C}');
-    map.insert("boxvl", '\u{02524}');
-    map.insert("boxvr", '\u{0251C}');
-    map.insert("bprime", '\u{02035}');
-    map.insert("breve", '\u{002D8}');
-    map.insert("brvbar", '\u{000A6}');
-    map.insert("bscr", '\u{1D4B7}');
-    map.insert("bsemi", '\u{0204F}');
-    map.insert("bsim", '\u{0223D}');
-    map.insert("bsime", '\u{022CD}');
-    map.insert("bsol", '\u{0005C}');
-    map.insert("bsolb", '\u{029C5}');
-    map.insert("bsolhsub", '\u{027C8}');
-    map.insert("bull", '\u{02022}');
-    map.insert("bullet", '\u{02022}');
-    map.insert("bump", '\u{0224E}');
-    map.insert("bumpE", '\u{02AAE}');
-    map.insert("bumpe", '\u{0224F}');
-    map.insert("bumpeq", '\u{0224F}');
-    map.insert("cacute", '\u{00107}');
-    map.insert("cap", '\u{02229}');
-    map.insert("capand", '\u{02A44}');
-    map.insert("capbrcup", '\u{02A49}');
-    map.insert("capcap", '\u{02A4B}');
-    map.insert("capcup", '\u{02A47}');
-    map.insert("capdot", '\u{02A40}');
-    map.insert("caret", '\u{02041}');
-    map.insert("caron", '\u{002C7}');
-    map.insert("ccaps", '\u{02A4D}');
-    map.insert("ccaron", '\u{0010D}');
-    map.insert("ccedil", '\u{000E7}');
-    map.insert("ccirc", '\u{00109}');
-    map.insert("ccups", '\u{02A4C}');
-    map.insert("ccupssm", '\u{02A50}');
-    map.insert("cdot", '\u{0010B}');
-    map.insert("cedil", '\u{000B8}');
-    map.insert("cemptyv", '\u{029B2}');
-    map.insert("cent", '\u{000A2}');
-    map.insert("centerdot", '\u{000B7}');
-    map.insert("cfr", '\u{1D520}');
-    map.insert("chcy", '\u{00447}');
-    map.insert("check", '\u{02713}');
-    map.insert("checkmark", '\u{02713}');
-    map.insert("chi", '\u{003C7}');
-    map.insert("cir", '\u{025CB}');
-    map.insert("cirE", '\u{029C3}');
-    map.insert("circ", '\u{002C6}');
-    map.insert("circeq", '\u{02257}');
-    map.insert("circlearrowleft", '\u{021BA}');
-    map.insert("circlearrowright", '\u{021BB}');
-    map.insert("circledR", '\u{000AE}');
-    map.insert("circledS", '\u{024C8}');
-    map.insert("circledast", '\u{0229B}');
-    map.insert("circledcirc", '\u{0229A}');
-    map.insert("circleddash", '\u{0229D}');
-    map.insert("cire", '\u{02257}');
-    map.insert("cirfnint", '\u{02A10}');
-    map.insert("cirmid", '\u{02AEF}');
-    map.insert("cirscir", '\u{029C2}');
-    map.insert("clubs", '\u{02663}');
-    map.insert("clubsuit", '\u{02663}');
-    map.insert("colon", '\u{0003A}');
-    map.insert("colone", '\u{02254}');
-    map.insert("coloneq", '\u{02254}');
-    map.insert("comma", '\u{0002C}');
-    map.insert("commat", '\u{00040}');
-    map.insert("comp", '\u{02201}');
-    map.insert("compfn", '\u{02218}');
-    map.insert("complement", '\u{02201}');
-    map.insert("complexes", '\u{02102}');
-    map.insert("cong", '\u{02245}');
-    map.insert("congdot", '\u{02A6D}');
-    map.insert("conint", '\u{0222E}');
-    map.insert("copf", '\u{1D554}');
-    map.insert("coprod", '\u{02210}');
-    map.insert("copy", '\u{000A9}');
-    map.insert("copysr", '\u{02117}');
-    map.insert("crarr", '\u{021B5}');
-    map.insert("cross", '\u{02717}');
-    map.insert("cscr", '\u{1D4B8}');
-    map.insert("csub", '\u{02ACF}');
-    map.insert("csube", '\u{02AD1}');
-    map.insert("csup", '\u{02AD0}');
-    map.insert("csupe", '\u{02AD2}');
-    map.insert("ctdot", '\u{022EF}');
-    map.insert("cudarrl", '\u{02938}');
-    map.insert("cudarrr", '\u{02935}');
-    map.insert("cuepr", '\u{022DE}');
-    map.insert("cuesc", '\u{022DF}');
-    map.insert("cularr", '\u{021B6}');
-    map.insert("cularrp", '\u{0293D}');
-    map.insert("cup", '\u{0222A}');
-    map.insert("cupbrcap", '\u{02A48}');
-    map.insert("cupcap", '\u{02A46}');
-    map.insert("cupcup", '\u{02A4A}');
-    map.insert("cupdot", '\u{0228D}');
-    map.insert("cupor", '\u{02A45}');
-    map.insert("curarr", '\u{021B7}');
-    map.insert("curarrm", '\u{0293C}');
-    map.insert("curlyeqprec", '\u{022DE}');
-    map.insert("curlyeqsucc", '\u{022DF}');
-    map.insert("curlyvee", '\u{022CE}');
-    map.insert("curlywedge", '\u{022CF}');
-    map.insert("curren", '\u{000A4}');
-    map.insert("curvearrowleft", '\u{021B6}');
-    map.insert("curvearrowright", '\u{021B7}');
-    map.insert("cuvee", '\u{022CE}');
-    map.insert("cuwed", '\u{022CF}');
-    map.insert("cwconint", '\u{02232}');
-    map.insert("cwint", '\u{02231}');
-    map.insert("cylcty", '\u{0232D}');
-    map.insert("dArr", '\u{021D3}');
-    map.insert("dHar", '\u{02965}');
-    map.insert("dagger", '\u{02020}');
-    map.insert("daleth", '\u{02138}');
-    map.insert("darr", '\u{02193}');
-    map.insert("dash", '\u{02010}');
-    map.insert("dashv", '\u{022A3}');
-    map.insert("dbkarow", '\u{0290F}');
-    map.insert("dblac", '\u{002DD}');
-    map.insert("dcaron", '\u{0010F}');
-    map.insert("dcy", '\u{00434}');
-    map.insert("dd", '\u{02146}');
-    map.insert("ddagger", '\u{02021}');
-    map.insert("ddarr", '\u{021CA}');
-    map.insert("ddotseq", '\u{02A77}');
-    map.insert("deg", '\u{000B0}');
-    map.insert("delta", '\u{003B4}');
-    map.insert("demptyv", '\u{029B1}');
-    map.insert("dfisht", '\u{0297F}');
-    map.insert("dfr", '\u{1D521}');
-    map.insert("dharl", '\u{021C3}');
-    map.insert("dharr", '\u{021C2}');
-    map.insert("diam", '\u{022C4}');
-    map.insert("diamond", '\u{022C4}');
-    map.insert("diamondsuit", '\u{02666}');
-    map.insert("diams", '\u{02666}');
-    map.insert("die", '\u{000A8}');
-    map.insert("digamma", '\u{003DD}');
-    map.insert("disin", '\u{022F2}');
-    map.insert("div", '\u{000F7}');
-    map.insert("divide", '\u{000F7}');
-    map.insert("divideontimes", '\u{022C7}');
-    map.insert("divonx", '\u{022C7}');
-    map.insert("djcy", '\u{00452}');
-    map.insert("dlcorn", '\u{0231E}');
-    map.insert("dlcrop", '\u{0230D}');
-    map.insert("dollar", '\u{00024}');
-    map.insert("dopf", '\u{1D555}');
-    map.insert("dot", '\u{002D9}');
-    map.insert("doteq", '\u{02250}');
-    map.insert("doteqdot", '\u{02251}');
-    map.insert("dotminus", '\u{02238}');
-    map.insert("dotplus", '\u{02214}');
-    map.insert("dotsquare", '\u{022A1}');
-    map.insert("doublebarwedge", '\u{02306}');
-    map.insert("downarrow", '\u{02193}');
-    map.insert("downdownarrows", '\u{021CA}');
-    map.insert("downharpoonleft", '\u{021C3}');
-    map.insert("downharpoonright", '\u{021C2}');
-    map.insert("drbkarow", '\u{02910}');
-    map.insert("drcorn", '\u{0231F}');
-    map.insert("drcrop", '\u{0230C}');
-    map.insert("dscr", '\u{1D4B9}');
-    map.insert("dscy", '\u{00455}');
-    map.insert("dsol", '\u{029F6}');
-    map.insert("dstrok", '\u{00111}');
-    map.insert("dtdot", '\u{022F1}');
-    map.insert("dtri", '\u{025BF}');
-    map.insert("dtrif", '\u{025BE}');
-    map.insert("duarr", '\u{021F5}');
-    map.insert("duhar", '\u{0296F}');
-    map.insert("dwangle", '\u{029A6}');
-    map.insert("dzcy", '\u{0045F}');
-    map.insert("dzigrarr", '\u{027FF}');
-    map.insert("eDDot", '\u{02A77}');
-    map.insert("eDot", '\u{02251}');
-    map.insert("eacute", '\u{000E9}');
-    map.insert("easter", '\u{02A6E}');
-    map.insert("ecaron", '\u{0011B}');
-    map.insert("ecir", '\u{02256}');
-    map.insert("ecirc", '\u{000EA}');
-    map.insert("ecolon", '\u{02255}');
-    map.insert("ecy", '\u{0044D}');
-    map.insert("edot", '\u{00117}');
-    map.insert("ee", '\u{02147}');
-    map.insert("efDot", '\u{02252}');
-    map.insert("efr", '\u{1D522}');
-    map.insert("eg", '\u{02A9A}');
-    map.insert("egrave", '\u{000E8}');
-    map.insert("egs", '\u{02A96}');
-    map.insert("egsdot", '\u{02A98}');
-    map.insert("el", '\u{02A99}');
-    map.insert("elinters", '\u{023E7}');
-    map.insert("ell", '\u{02113}');
-    map.insert("els", '\u{02A95}');
-    map.insert("elsdot", '\u{02A97}');
-    map.insert("emacr", '\u{00113}');
-    map.insert("empty", '\u{02205}');
-    map.insert("emptyset", '\u{02205}');
-    map.insert("emptyv", '\u{02205}');
-    map.insert("emsp13", '\u{02004}');
-    map.insert("emsp14", '\u{02005}');
-    map.insert("emsp", '\u{02003}');
-    map.insert("eng", '\u{0014B}');
-    map.insert("ensp", '\u{02002}');
-    map.insert("eogon", '\u{00119}');
-    map.insert("eopf", '\u{1D556}');
-    map.insert("epar", '\u{022D5}');
-    map.insert("eparsl", '\u{029E3}');
-    map.insert("eplus", '\u{02A71}');
-    map.insert("epsi", '\u{003B5}');
-    map.insert("epsilon", '\u{003B5}');
-    map.insert("epsiv", '\u{003F5}');
-    map.insert("eqcirc", '\u{02256}');
-    map.insert("eqcolon", '\u{02255}');
-    map.insert("eqsim", '\u{02242}');
-    map.insert("eqslantgtr", '\u{02A96}');
-    map.insert("eqslantless", '\u{02A95}');
-    map.insert("equals", '\u{0003D}');
-    map.insert("equest", '\u{0225F}');
-    map.insert("equiv", '\u{02261}');
-    map.insert("equivDD", '\u{02A78}');
-    map.insert("eqvparsl", '\u{029E5}');
-    map.insert("erDot", '\u{02253}');
-    map.insert("erarr", '\u{02971}');
-    map.insert("escr", '\u{0212F}');
-    map.insert("esdot", '\u{02250}');
-    map.insert("esim", '\u{02242}');
-    map.insert("eta", '\u{003B7}');
-    map.insert("eth", '\u{000F0}');
-    map.insert("euml", '\u{000EB}');
-    map.insert("euro", '\u{020AC}');
-    map.insert("excl", '\u{00021}');
-    map.insert("exist", '\u{02203}');
-    map.insert("expectation", '\u{02130}');
-    map.insert("exponentiale", '\u{02147}');
-    map.insert("fallingdotseq", '\u{02252}');
-    map.insert("fcy", '\u{00444}');
-    map.insert("female", '\u{02640}');
-    map.insert("ffilig", '\u{0FB03}');
-    map.insert("fflig", '\u{0FB00}');
-    map.insert("ffllig", '\u{0FB04}');
-    map.insert("ffr", '\u{1D523}');
-    map.insert("filig", '\u{0FB01}');
-    map.insert("flat", '\u{0266D}');
-    map.insert("fllig", '\u{0FB02}');
-    map.insert("fltns", '\u{025B1}');
-    map.insert("fnof", '\u{00192}');
-    map.insert("fopf", '\u{1D557}');
-    map.insert("forall", '\u{02200}');
-    map.insert("fork", '\u{022D4}');
-    map.insert("forkv", '\u{02AD9}');
-    map.insert("fpartint", '\u{02A0D}');
-    map.insert("frac12", '\u{000BD}');
-    map.insert("frac13", '\u{02153}');
-    map.insert("frac14", '\u{000BC}');
-    map.insert("frac15", '\u{02155}');
-    map.insert("frac16", '\u{02159}');
-    map.insert("frac18", '\u{0215B}');
-    map.insert("frac23", '\u{02154}');
-    map.insert("frac25", '\u{02156}');
-    map.insert("frac34", '\u{000BE}');
-    map.insert("frac35", '\u{02157}');
-    map.insert("frac38", '\u{0215C}');
-    map.insert("frac45", '\u{02158}');
-    map.insert("frac56", '\u{0215A}');
-    map.insert("frac58", '\u{0215D}');
-    map.insert("frac78", '\u{0215E}');
-    map.insert("frasl", '\u{02044}');
-    map.insert("frown", '\u{02322}');
-    map.insert("fscr", '\u{1D4BB}');
-    map.insert("gE", '\u{02267}');
-    map.insert("gEl", '\u{02A8C}');
-    map.insert("gacute", '\u{001F5}');
-    map.insert("gamma", '\u{003B3}');
-    map.insert("gammad", '\u{003DD}');
-    map.insert("gap", '\u{02A86}');
-    map.insert("gbreve", '\u{0011F}');
-    map.insert("gcirc", '\u{0011D}');
-    map.insert("gcy", '\u{00433}');
-    map.insert("gdot", '\u{00121}');
-    map.insert("ge", '\u{02265}');
-    map.insert("gel", '\u{022DB}');
-    map.insert("geq", '\u{02265}');
-    map.insert("geqq", '\u{02267}');
-    map.insert("geqslant", '\u{02A7E}');
-    map.insert("ges", '\u{02A7E}');
-    map.insert("gescc", '\u{02AA9}');
-    map.insert("gesdot", '\u{02A80}');
-    map.insert("gesdoto", '\u{02A82}');
-    map.insert("gesdotol", '\u{02A84}');
-    map.insert("gesles", '\u{02A94}');
-    map.insert("gfr", '\u{1D524}');
-    map.insert("gg", '\u{0226B}');
-    map.insert("ggg", '\u{022D9}');
-    map.insert("gimel", '\u{02137}');
-    map.insert("gjcy", '\u{00453}');
-    map.insert("gl", '\u{02277}');
-    map.insert("glE", '\u{02A92}');
-    map.insert("gla", '\u{02AA5}');
-    map.insert("glj", '\u{02AA4}');
-    map.insert("gnE", '\u{02269}');
-    map.insert("gnap", '\u{02A8A}');
-    map.insert("gnapprox", '\u{02A8A}');
-    map.insert("gne", '\u{02A88}');
-    map.insert("gneq", '\u{02A88}');
-    map.insert("gneqq", '\u{02269}');
-    map.insert("gnsim", '\u{022E7}');
-    map.insert("gopf", '\u{1D558}');
-    map.insert("grave", '\u{00060}');
-    map.insert("gscr", '\u{0210A}');
-    map.insert("gsim", '\u{02273}');
-    map.insert("gsime", '\u{02A8E}');
-    map.insert("gsiml", '\u{02A90}');
-    map.insert("gt", '\u{0003E}');
-    map.insert("gtcc", '\u{02AA7}');
-    map.insert("gtcir", '\u{02A7A}');
-    map.insert("gtdot", '\u{022D7}');
-    map.insert("gtlPar", '\u{02995}');
-    map.insert("gtquest", '\u{02A7C}');
-    map.insert("gtrapprox", '\u{02A86}');
-    map.insert("gtrarr", '\u{02978}');
-    map.insert("gtrdot", '\u{022D7}');
-    map.insert("gtreqless", '\u{022DB}');
-    map.insert("gtreqqless", '\u{02A8C}');
-    map.insert("gtrless", '\u{02277}');
-    map.insert("gtrsim", '\u{02273}');
-    map.insert("hArr", '\u{021D4}');
-    map.insert("hairsp", '\u{0200A}');
-    map.insert("half", '\u{000BD}');
-    map.insert("hamilt", '\u{0210B}');
-    map.insert("hardcy", '\u{0044A}');
-    map.insert("harr", '\u{02194}');
-    map.insert("harrcir", '\u{02948}');
-    map.insert("harrw", '\u{021AD}');
-    map.insert("hbar", '\u{0210F}');
-    map.insert("hcirc", '\u{00125}');
-    map.insert("hearts", '\u{02665}');
-    map.insert("heartsuit", '\u{02665}');
-    map.insert("hellip", '\u{02026}');
-    map.insert("hercon", '\u{022B9}');
-    map.insert("hfr", '\u{1D525}');
-    map.insert("hksearow", '\u{02925}');
-    map.insert("hkswarow", '\u{02926}');
-    map.insert("hoarr", '\u{021FF}');
-    map.insert("homtht", '\u{0223B}');
-    map.insert("hookleftarrow", '\u{021A9}');
-    map.insert("hookrightarrow", '\u{021AA}');
-    map.insert("hopf", '\u{1D559}');
-    map.insert("horbar", '\u{02015}');
-    map.insert("hscr", '\u{1D4BD}');
-    map.insert("hslash", '\u{0210F}');
-    map.insert("hstrok", '\u{00127}');
-    map.insert("hybull", '\u{02043}');
-    map.insert("hyphen", '\u{02010}');
-    map.insert("iacute", '\u{000ED}');
-    map.insert("ic", '\u{02063}');
-    map.insert("icirc", '\u{000EE}');
-    map.insert("icy", '\u{00438}');
-    map.insert("iecy", '\u{00435}');
-    map.insert("iexcl", '\u{000A1}');
-    map.insert("iff", '\u{021D4}');
-    map.insert("ifr", '\u{1D526}');
-    map.insert("igrave", '\u{000EC}');
-    map.insert("ii", '\u{02148}');
-    map.insert("iiiint", '\u{02A0C}');
-    map.insert("iiint", '\u{0222D}');
-    map.insert("iinfin", '\u{029DC}');
-    map.insert("iiota", '\u{02129}');
-    map.insert("ijlig", '\u{00133}');
-    map.insert("imacr", '\u{0012B}');
-    map.insert("image", '\u{02111}');
-    map.insert("imagline", '\u{02110}');
-    map.insert("imagpart", '\u{02111}');
-    map.insert("imath", '\u{00131}');
-    map.insert("imof", '\u{022B7}');
-    map.insert("imped", '\u{001B5}');
-    map.insert("in", '\u{02208}');
-    map.insert("incare", '\u{02105}');
-    map.insert("infin", '\u{0221E}');
-    map.insert("infintie", '\u{029DD}');
-    map.insert("inodot", '\u{00131}');
-    map.insert("int", '\u{0222B}');
-    map.insert("intcal", '\u{022BA}');
-    map.insert("integers", '\u{02124}');
-    map.insert("intercal", '\u{022BA}');
-    map.insert("intlarhk", '\u{02A17}');
-    map.insert("intprod", '\u{02A3C}');
-    map.insert("iocy", '\u{00451}');
-    map.insert("iogon", '\u{0012F}');
-    map.insert("iopf", '\u{1D55A}');
-    map.insert("iota", '\u{003B9}');
-    map.insert("iprod", '\u{02A3C}');
-    map.insert("iquest", '\u{000BF}');
-    map.insert("iscr", '\u{1D4BE}');
-    map.insert("isin", '\u{02208}');
-    map.insert("isinE", '\u{022F9}');
-    map.insert("isindot", '\u{022F5}');
-    map.insert("isins", '\u{022F4}');
-    map.insert("isinsv", '\u{022F3}');
-    map.insert("isinv", '\u{02208}');
-    map.insert("it", '\u{02062}');
-    map.insert("itilde", '\u{00129}');
-    map.insert("iukcy", '\u{00456}');
-    map.insert("iuml", '\u{000EF}');
-    map.insert("jcirc", '\u{00135}');
-    map.insert("jcy", '\u{00439}');
-    map.insert("jfr", '\u{1D527}');
-    map.insert("jmath", '\u{00237}');
-    map.insert("jopf", '\u{1D55B}');
-    map.insert("jscr", '\u{1D4BF}');
-    map.insert("jsercy", '\u{00458}');
-    map.insert("jukcy", '\u{00454}');
-    map.insert("kappa", '\u{003BA}');
-    map.insert("kappav", '\u{003F0}');
-    map.insert("kcedil", '\u{00137}');
-    map.insert("kcy", '\u{0043A}');
-    map.insert("kfr", '\u{1D528}');
-    map.insert("kgreen", '\u{00138}');
-    map.insert("khcy", '\u{00445}');
-    map.insert("kjcy", '\u{0045C}');
-    map.insert("kopf", '\u{1D55C}');
-    map.insert("kscr", '\u{1D4C0}');
-    map.insert("lAarr", '\u{021DA}');
-    map.insert("lArr", '\u{021D0}');
-    map.insert("lAtail", '\u{0291B}');
-    map.insert("lBarr", '\u{0290E}');
-    map.insert("lE", '\u{02266}');
-    map.insert("lEg", '\u{02A8B}');
-    map.insert("lHar", '\u{02962}');
-    map.insert("lacute", '\u{0013A}');
-    map.insert("laemptyv", '\u{029B4}');
-    map.insert("lagran", '\u{02112}');
-    map.insert("lambda", '\u{003BB}');
-    map.insert("lang", '\u{027E8}');
-    map.insert("langd", '\u{02991}');
-    map.insert("langle", '\u{027E8}');
-    map.insert("lap", '\u{02A85}');
-    map.insert("laquo", '\u{000AB}');
-    map.insert("larr", '\u{02190}');
-    map.insert("larrb", '\u{021E4}');
-    map.insert("larrbfs", '\u{0291F}');
-    map.insert("larrfs", '\u{0291D}');
-    map.insert("larrhk", '\u{021A9}');
-    map.insert("larrlp", '\u{021AB}');
-    map.insert("larrpl", '\u{02939}');
-    map.insert("larrsim", '\u{02973}');
-    map.insert("larrtl", '\u{021A2}');
-    map.insert("lat", '\u{02AAB}');
-    map.insert("latail", '\u{02919}');
-    map.insert("late", '\u{02AAD}');
-    map.insert("lbarr", '\u{0290C}');
-    map.insert("lbbrk", '\u{02772}');
-    map.insert("lbrace", '\u{0007B}');
-    map.insert("lbrack", '\u{0005B}');
-    map.insert("lbrke", '\u{0298B}');
-    map.insert("lbrksld", '\u{0298F}');
-    map.insert("lbrkslu", '\u{0298D}');
-    map.insert("lcaron", '\u{0013E}');
-    map.insert("lcedil", '\u{0013C}');
-    map.insert("lceil", '\u{02308}');
-    map.insert("lcub", '\u{0007B}');
-    map.insert("lcy", '\u{0043B}');
-    map.insert("ldca", '\u{02936}');
-    map.insert("ldquo", '\u{0201C}');
-    map.insert("ldquor", '\u{0201E}');
-    map.insert("ldrdhar", '\u{02967}');
-    map.insert("ldrushar", '\u{0294B}');
-    map.insert("ldsh", '\u{021B2}');
-    map.insert("le", '\u{02264}');
-    map.insert("leftarrow", '\u{02190}');
-    map.insert("leftarrowtail", '\u{021A2}');
-    map.insert("leftharpoondown", '\u{021BD}');
-    map.insert("leftharpoonup", '\u{021BC}');
-    map.insert("leftleftarrows", '\u{021C7}');
-    map.insert("leftrightarrow", '\u{02194}');
-    map.insert("leftrightarrows", '\u{021C6}');
-    map.insert("leftrightharpoons", '\u{021CB}');
-    map.insert("leftrightsquigarrow", '\u{021AD}');
-    map.insert("leftthreetimes", '\u{022CB}');
-    map.insert("leg", '\u{022DA}');
-    map.insert("leq", '\u{02264}');
-    map.insert("leqq", '\u{02266}');
-    map.insert("leqslant", '\u{02A7D}');
-    map.insert("les", '\u{02A7D}');
-    map.insert("lescc", '\u{02AA8}');
-    map.insert("lesdot", '\u{02A7F}');
-    map.insert("lesdoto", '\u{02A81}');
-    map.insert("lesdotor", '\u{02A83}');
-    map.insert("lesges", '\u{02A93}');
-    map.insert("lessapprox", '\u{02A85}');
-    map.insert("lessdot", '\u{022D6}');
-    map.insert("lesseqgtr", '\u{022DA}');
-    map.insert("lesseqqgtr", '\u{02A8B}');
-    map.insert("lessgtr", '\u{02276}');
-    map.insert("lesssim", '\u{02272}');
-    map.insert("lfisht", '\u{0297C}');
-    map.insert("lfloor", '\u{0230A}');
-    map.insert("lfr", '\u{1D529}');
-    map.insert("lg", '\u{02276}');
-    map.insert("lgE", '\u{02A91}');
-    map.insert("lhard", '\u{021BD}');
-    map.insert("lharu", '\u{021BC}');
-    map.insert("lharul", '\u{0296A}');
-    map.insert("lhblk", '\u{02584}');
-    map.insert("ljcy", '\u{00459}');
-    map.insert("ll", '\u{0226A}');
-    map.insert("llarr", '\u{021C7}');
-    map.insert("llcorner", '\u{0231E}');
-    map.insert("llhard", '\u{0296B}');
-    map.insert("lltri", '\u{025FA}');
-    map.insert("lmidot", '\u{00140}');
-    map.insert("lmoust", '\u{023B0}');
-    map.insert("lmoustache", '\u{023B0}');
-    map.insert("lnE", '\u{02268}');
-    map.insert("lnap", '\u{02A89}');
-    map.insert("lnapprox", '\u{02A89}');
-    map.insert("lne", '\u{02A87}');
-    map.insert("lneq", '\u{02A87}');
-    map.insert("lneqq", '\u{02268}');
-    map.insert("lnsim", '\u{022E6}');
-    map.insert("loang", '\u{027EC}');
-    map.insert("loarr", '\u{021FD}');
-    map.insert("lobrk", '\u{027E6}');
-    map.insert("longleftarrow", '\u{027F5}');
-    map.insert("longleftrightarrow", '\u{027F7}');
-    map.insert("longmapsto", '\u{027FC}');
-    map.insert("longrightarrow", '\u{027F6}');
-    map.insert("looparrowleft", '\u{021AB}');
-    map.insert("looparrowright", '\u{021AC}');
-    map.insert("lopar", '\u{02985}');
-    map.insert("lopf", '\u{1D55D}');
-    map.insert("loplus", '\u{02A2D}');
-    map.insert("lotimes", '\u{02A34}');
-    map.insert("lowast", '\u{02217}');
-    map.insert("lowbar", '\u{0005F}');
-    map.insert("loz", '\u{025CA}');
-    map.insert("lozenge", '\u{025CA}');
-    map.insert("lozf", '\u{029EB}');
-    map.insert("lpar", '\u{00028}');
-    map.insert("lparlt", '\u{02993}');
-    map.insert("lrarr", '\u{021C6}');
-    map.insert("lrcorner", '\u{0231F}');
-    map.insert("lrhar", '\u{021CB}');
-    map.insert("lrhard", '\u{0296D}');
-    map.insert("lrm", '\u{0200E}');
-    map.insert("lrtri", '\u{022BF}');
-    map.insert("lsaquo", '\u{02039}');
-    map.insert("lscr", '\u{1D4C1}');
-    map.insert("lsh", '\u{021B0}');
-    map.insert("lsim", '\u{02272}');
-    map.insert("lsime", '\u{02A8D}');
-    map.insert("lsimg", '\u{02A8F}');
-    map.insert("lsqb", '\u{0005B}');
-    map.insert("lsquo", '\u{02018}');
-    map.insert("lsquor", '\u{0201A}');
-    map.insert("lstrok", '\u{00142}');
-    map.insert("lt", '\u{0003C}');
-    map.insert("ltcc", '\u{02AA6}');
-    map.insert("ltcir", '\u{02A79}');
-    map.insert("ltdot", '\u{022D6}');
-    map.insert("lthree", '\u{022CB}');
-    map.insert("ltimes", '\u{022C9}');
-    map.insert("ltlarr", '\u{02976}');
-    map.insert("ltquest", '\u{02A7B}');
-    map.insert("ltrPar", '\u{02996}');
-    map.insert("ltri", '\u{025C3}');
-    map.insert("ltrie", '\u{022B4}');
-    map.insert("ltrif", '\u{025C2}');
-    map.insert("lurdshar", '\u{0294A}');
-    map.insert("luruhar", '\u{02966}');
-    map.insert("mDDot", '\u{0223A}');
-    map.insert("macr", '\u{000AF}');
-    map.insert("male", '\u{02642}');
-    map.insert("malt", '\u{02720}');
-    map.insert("maltese", '\u{02720}');
-    map.insert("map", '\u{021A6}');
-    map.insert("mapsto", '\u{021A6}');
-    map.insert("mapstodown", '\u{021A7}');
-    map.insert("mapstoleft", '\u{021A4}');
-    map.insert("mapstoup", '\u{021A5}');
-    map.insert("marker", '\u{025AE}');
-    map.insert("mcomma", '\u{02A29}');
-    map.insert("mcy", '\u{0043C}');
-    map.insert("mdash", '\u{02014}');
-    map.insert("measuredangle", '\u{02221}');
-    map.insert("mfr", '\u{1D52A}');
-    map.insert("mho", '\u{02127}');
-    map.insert("micro", '\u{000B5}');
-    map.insert("mid", '\u{02223}');
-    map.insert("midast", '\u{0002A}');
-    map.insert("midcir", '\u{02AF0}');
-    map.insert("middot", '\u{000B7}');
-    map.insert("minus", '\u{02212}');
-    map.insert("minusb", '\u{0229F}');
-    map.insert("minusd", '\u{02238}');
-    map.insert("minusdu", '\u{02A2A}');
-    map.insert("mlcp", '\u{02ADB}');
-    map.insert("mldr", '\u{02026}');
-    map.insert("mnplus", '\u{02213}');
-    map.insert("models", '\u{022A7}');
-    map.insert("mopf", '\u{1D55E}');
-    map.insert("mp", '\u{02213}');
-    map.insert("mscr", '\u{1D4C2}');
-    map.insert("mstpos", '\u{0223E}');
-    map.insert("mu", '\u{003BC}');
-    map.insert("multimap", '\u{022B8}');
-    map.insert("mumap", '\u{022B8}');
-    map.insert("nLeftarrow", '\u{021CD}');
-    map.insert("nLeftrightarrow", '\u{021CE}');
-    map.insert("nRightarrow", '\u{021CF}');
-    map.insert("nVDash", '\u{022AF}');
-    map.insert("nVdash", '\u{022AE}');
-    map.insert("nabla", '\u{02207}');
-    map.insert("nacute", '\u{00144}');
-    map.insert("nap", '\u{02249}');
-    map.insert("napos", '\u{00149}');
-    map.insert("napprox", '\u{02249}');
-    map.insert("natur", '\u{0266E}');
-    map.insert("natural", '\u{0266E}');
-    map.insert("naturals", '\u{02115}');
-    map.insert("nbsp", '\u{000A0}');
-    map.insert("ncap", '\u{02A43}');
-    map.insert("ncaron", '\u{00148}');
-    map.insert("ncedil", '\u{00146}');
-    map.insert("ncong", '\u{02247}');
-    map.insert("ncup", '\u{02A42}');
-    map.insert("ncy", '\u{0043D}');
-    map.insert("ndash", '\u{02013}');
-    map.insert("ne", '\u{02260}');
-    map.insert("neArr", '\u{021D7}');
-    map.insert("nearhk", '\u{02924}');
-    map.insert("nearr", '\u{02197}');
-    map.insert("nearrow", '\u{02197}');
-    map.insert("nequiv", '\u{02262}');
-    map.insert("nesear", '\u{02928}');
-    map.insert("nexist", '\u{02204}');
-    map.insert("nexists", '\u{02204}');
-    map.insert("nfr", '\u{1D52B}');
-    map.insert("nge", '\u{02271}');
-    map.insert("ngeq", '\u{02271}');
-    map.insert("ngsim", '\u{02275}');
-    map.insert("ngt", '\u{0226F}');
-    map.insert("ngtr", '\u{0226F}');
-    map.insert("nhArr", '\u{021CE}');
-    map.insert("nharr", '\u{021AE}');
-    map.insert("nhpar", '\u{02AF2}');
-    map.insert("ni", '\u{0220B}');
-    map.insert("nis", '\u{022FC}');
-    map.insert("nisd", '\u{022FA}');
-    map.insert("niv", '\u{0220B}');
-    map.insert("njcy", '\u{0045A}');
-    map.insert("nlArr", '\u{021CD}');
-    map.insert("nlarr", '\u{0219A}');
-    map.insert("nldr", '\u{02025}');
-    map.insert("nle", '\u{02270}');
-    map.insert("nleftarrow", '\u{0219A}');
-    map.insert("nleftrightarrow", '\u{021AE}');
-    map.insert("nleq", '\u{02270}');
-    map.insert("nless", '\u{0226E}');
-    map.insert("nlsim", '\u{02274}');
-    map.insert("nlt", '\u{0226E}');
-    map.insert("nltri", '\u{022EA}');
-    map.insert("nltrie", '\u{022EC}');
-    map.insert("nmid", '\u{02224}');
-    map.insert("nopf", '\u{1D55F}');
-    map.insert("not", '\u{000AC}');
-    map.insert("notin", '\u{02209}');
-    map.insert("notinva", '\u{02209}');
-    map.insert("notinvb", '\u{022F7}');
-    map.insert("notinvc", '\u{022F6}');
-    map.insert("notni", '\u{0220C}');
-    map.insert("notniva", '\u{0220C}');
-    map.insert("notnivb", '\u{022FE}');
-    map.insert("notnivc", '\u{022FD}');
-    map.insert("npar", '\u{02226}');
-    map.insert("nparallel", '\u{02226}');
-    map.insert("npolint", '\u{02A14}');
-    map.insert("npr", '\u{02280}');
-    map.insert("nprcue", '\u{022E0}');
-    map.insert("nprec", '\u{02280}');
-    map.insert("nrArr", '\u{021CF}');
-    map.insert("nrarr", '\u{0219B}');
-    map.insert("nrightarrow", '\u{0219B}');
-    map.insert("nrtri", '\u{022EB}');
-    map.insert("nrtrie", '\u{022ED}');
-    map.insert("nsc", '\u{02281}');
-    map.insert("nsccue", '\u{022E1}');
-    map.insert("nscr", '\u{1D4C3}');
-    map.insert("nshortmid", '\u{02224}');
-    map.insert("nshortparallel", '\u{02226}');
-    map.insert("nsim", '\u{02241}');
-    map.insert("nsime", '\u{02244}');
-    map.insert("nsimeq", '\u{02244}');
-    map.insert("nsmid", '\u{02224}');
-    map.insert("nspar", '\u{02226}');
-    map.insert("nsqsube", '\u{022E2}');
-    map.insert("nsqsupe", '\u{022E3}');
-    map.insert("nsub", '\u{02284}');
-    map.insert("nsube", '\u{02288}');
-    map.insert("nsubseteq", '\u{02288}');
-    map.insert("nsucc", '\u{02281}');
-    map.insert("nsup", '\u{02285}');
-    map.insert("nsupe", '\u{02289}');
-    map.insert("nsupseteq", '\u{02289}');
-    map.insert("ntgl", '\u{02279}');
-    map.insert("ntilde", '\u{000F1}');
-    map.insert("ntlg", '\u{02278}');
-    map.insert("ntriangleleft", '\u{022EA}');
-    map.insert("ntrianglelefteq", '\u{022EC}');
-    map.insert("ntriangleright", '\u{022EB}');
-    map.insert("ntrianglerighteq", '\u{022ED}');
-    map.insert("nu", '\u{003BD}');
-    map.insert("num", '\u{00023}');
-    map.insert("numero", '\u{02116}');
-    map.insert("numsp", '\u{02007}');
-    map.insert("nvDash", '\u{022AD}');
-    map.insert("nvHarr", '\u{02904}');
-    map.insert("nvdash", '\u{022AC}');
-    map.insert("nvinfin", '\u{029DE}');
-    map.insert("nvlArr", '\u{02902}');
-    map.insert("nvrArr", '\u{02903}');
-    map.insert("nwArr", '\u{021D6}');
-    map.insert("nwarhk", '\u{02923}');
-    map.insert("nwarr", '\u{02196}');
-    map.insert("nwarrow", '\u{02196}');
-    map.insert("nwnear", '\u{02927}');
-    map.insert("oS", '\u{024C8}');
-    map.insert("oacute", '\u{000F3}');
-    map.insert("oast", '\u{0229B}');
-    map.insert("ocir", '\u{0229A}');
-    map.insert("ocirc", '\u{000F4}');
-    map.insert("ocy", '\u{0043E}');
-    map.insert("odash", '\u{0229D}');
-    map.insert("odblac", '\u{00151}');
-    map.insert("odiv", '\u{02A38}');
-    map.insert("odot", '\u{02299}');
-    map.insert("odsold", '\u{029BC}');
-    map.insert("oelig", '\u{00153}');
-    map.insert("ofcir", '\u{029BF}');
-    map.insert("ofr", '\u{1D52C}');
-    map.insert("ogon", '\u{002DB}');
-    map.insert("ograve", '\u{000F2}');
-    map.insert("ogt", '\u{029C1}');
-    map.insert("ohbar", '\u{029B5}');
-    map.insert("ohm", '\u{003A9}');
-    map.insert("oint", '\u{0222E}');
-    map.insert("olarr", '\u{021BA}');
-    map.insert("olcir", '\u{029BE}');
-    map.insert("olcross", '\u{029BB}');
-    map.insert("oline", '\u{0203E}');
-    map.insert("olt", '\u{029C0}');
-    map.insert("omacr", '\u{0014D}');
-    map.insert("omega", '\u{003C9}');
-    map.insert("omicron", '\u{003BF}');
-    map.insert("omid", '\u{029B6}');
-    map.insert("ominus", '\u{02296}');
-    map.insert("oopf", '\u{1D560}');
-    map.insert("opar", '\u{029B7}');
-    map.insert("operp", '\u{029B9}');
-    map.insert("oplus", '\u{02295}');
-    map.insert("or", '\u{02228}');
-    map.insert("orarr", '\u{021BB}');
-    map.insert("ord", '\u{02A5D}');
-    map.insert("order", '\u{02134}');
-    map.insert("orderof", '\u{02134}');
-    map.insert("ordf", '\u{000AA}');
-    map.insert("ordm", '\u{000BA}');
-    map.insert("origof", '\u{022B6}');
-    map.insert("oror", '\u{02A56}');
-    map.insert("orslope", '\u{02A57}');
-    map.insert("orv", '\u{02A5B}');
-    map.insert("oscr", '\u{02134}');
-    map.insert("oslash", '\u{000F8}');
-    map.insert("osol", '\u{02298}');
-    map.insert("otilde", '\u{000F5}');
-    map.insert("otimes", '\u{02297}');
-    map.insert("otimesas", '\u{02A36}');
-    map.insert("ouml", '\u{000F6}');
-    map.insert("ovbar", '\u{0233D}');
-    map.insert("par", '\u{02225}');
-    map.insert("para", '\u{000B6}');
-    map.insert("parallel", '\u{02225}');
-    map.insert("parsim", '\u{02AF3}');
-    map.insert("parsl", '\u{02AFD}');
-    map.insert("part", '\u{02202}');
-    map.insert("pcy", '\u{0043F}');
-    map.insert("percnt", '\u{00025}');
-    map.insert("period", '\u{0002E}');
-    map.insert("permil", '\u{02030}');
-    map.insert("perp", '\u{022A5}');
-    map.insert("pertenk", '\u{02031}');
-    map.insert("pfr", '\u{1D52D}');
-    map.insert("phi", '\u{003C6}');
-    map.insert("phiv", '\u{003D5}');
-    map.insert("phmmat", '\u{02133}');
-    map.insert("phone", '\u{0260E}');
-    map.insert("pi", '\u{003C0}');
-    map.insert("pitchfork", '\u{022D4}');
-    map.insert("piv", '\u{003D6}');
-    map.insert("planck", '\u{0210F}');
-    map.insert("planckh", '\u{0210E}');
-    map.insert("plankv", '\u{0210F}');
-    map.insert("plus", '\u{0002B}');
-    map.insert("plusacir", '\u{02A23}');
-    map.insert("plusb", '\u{0229E}');
-    map.insert("pluscir", '\u{02A22}');
-    map.insert("plusdo", '\u{02214}');
-    map.insert("plusdu", '\u{02A25}');
-    map.insert("pluse", '\u{02A72}');
-    map.insert("plusmn", '\u{000B1}');
-    map.insert("plussim", '\u{02A26}');
-    map.insert("plustwo", '\u{02A27}');
-    map.insert("pm", '\u{000B1}');
-    map.insert("pointint", '\u{02A15}');
-    map.insert("popf", '\u{1D561}');
-    map.insert("pound", '\u{000A3}');
-    map.insert("pr", '\u{0227A}');
-    map.insert("prE", '\u{02AB3}');
-    map.insert("prap", '\u{02AB7}');
-    map.insert("prcue", '\u{0227C}');
-    map.insert("pre", '\u{02AAF}');
-    map.insert("prec", '\u{0227A}');
-    map.insert("precapprox", '\u{02AB7}');
-    map.insert("preccurlyeq", '\u{0227C}');
-    map.insert("preceq", '\u{02AAF}');
-    map.insert("precnapprox", '\u{02AB9}');
-    map.insert("precneqq", '\u{02AB5}');
-    map.insert("precnsim", '\u{022E8}');
-    map.insert("precsim", '\u{0227E}');
-    map.insert("prime", '\u{02032}');
-    map.insert("primes", '\u{02119}');
-    map.insert("prnE", '\u{02AB5}');
-    map.insert("prnap", '\u{02AB9}');
-    map.insert("prnsim", '\u{022E8}');
-    map.insert("prod", '\u{0220F}');
-    map.insert("profalar", '\u{0232E}');
-    map.insert("profline", '\u{02312}');
-    map.insert("profsurf", '\u{02313}');
-    map.insert("prop", '\u{0221D}');
-    map.insert("propto", '\u{0221D}');
-    map.insert("prsim", '\u{0227E}');
-    map.insert("prurel", '\u{022B0}');
-    map.insert("pscr", '\u{1D4C5}');
-    map.insert("psi", '\u{003C8}');
-    map.insert("puncsp", '\u{02008}');
-    map.insert("qfr", '\u{1D52E}');
-    map.insert("qint", '\u{02A0C}');
-    map.insert("qopf", '\u{1D562}');
-    map.insert("qprime", '\u{02057}');
-    map.insert("qscr", '\u{1D4C6}');
-    map.insert("quaternions", '\u{0210D}');
-    map.insert("quatint", '\u{02A16}');
-    map.insert("quest", '\u{0003F}');
-    map.insert("questeq", '\u{0225F}');
-    map.insert("quot", '\u{00022}');
-    map.insert("rAarr", '\u{021DB}');
-    map.insert("rArr", '\u{021D2}');
-    map.insert("rAtail", '\u{0291C}');
-    map.insert("rBarr", '\u{0290F}');
-    map.insert("rHar", '\u{02964}');
-    map.insert("racute", '\u{00155}');
-    map.insert("radic", '\u{0221A}');
-    map.insert("raemptyv", '\u{029B3}');
-    map.insert("rang", '\u{027E9}');
-    map.insert("rangd", '\u{02992}');
-    map.insert("range", '\u{029A5}');
-    map.insert("rangle", '\u{027E9}');
-    map.insert("raquo", '\u{000BB}');
-    map.insert("rarr", '\u{02192}');
-    map.insert("rarrap", '\u{02975}');
-    map.insert("rarrb", '\u{021E5}');
-    map.insert("rarrbfs", '\u{02920}');
-    map.insert("rarrc", '\u{02933}');
-    map.insert("rarrfs", '\u{0291E}');
-    map.insert("rarrhk", '\u{021AA}');
-    map.insert("rarrlp", '\u{021AC}');
-    map.insert("rarrpl", '\u{02945}');
-    map.insert("rarrsim", '\u{02974}');
-    map.insert("rarrtl", '\u{021A3}');
-    map.insert("rarrw", '\u{0219D}');
-    map.insert("ratail", '\u{0291A}');
-    map.insert("ratio", '\u{02236}');
-    map.insert("rationals", '\u{0211A}');
-    map.insert("rbarr", '\u{0290D}');
-    map.insert("rbbrk", '\u{02773}');
-    map.insert("rbrace", '\u{0007D}');
-    map.insert("rbrack", '\u{0005D}');
-    map.insert("rbrke", '\u{0298C}');
-    map.insert("rbrksld", '\u{0298E}');
-    map.insert("rbrkslu", '\u{02990}');
-    map.insert("rcaron", '\u{00159}');
-    map.insert("rcedil", '\u{00157}');
-    map.insert("rceil", '\u{02309}');
-    map.insert("rcub", '\u{0007D}');
-    map.insert("rcy", '\u{00440}');
-    map.insert("rdca", '\u{02937}');
-    map.insert("rdldhar", '\u{02969}');
-    map.insert("rdquo", '\u{0201D}');
-    map.insert("rdquor", '\u{0201D}');
-    map.insert("rdsh", '\u{021B3}');
-    map.insert("real", '\u{0211C}');
-    map.insert("realine", '\u{0211B}');
-    map.insert("realpart", '\u{0211C}');
-    map.insert("reals", '\u{0211D}');
-    map.insert("rect", '\u{025AD}');
-    map.insert("reg", '\u{000AE}');
-    map.insert("rfisht", '\u{0297D}');
-    map.insert("rfloor", '\u{0230B}');
-    map.insert("rfr", '\u{1D52F}');
-    map.insert("rhard", '\u{021C1}');
-    map.insert("rharu", '\u{021C0}');
-    map.insert("rharul", '\u{0296C}');
-    map.insert("rho", '\u{003C1}');
-    map.insert("rhov", '\u{003F1}');
-    map.insert("rightarrow", '\u{02192}');
-    map.insert("rightarrowtail", '\u{021A3}');
-    map.insert("rightharpoondown", '\u{021C1}');
-    map.insert("rightharpoonup", '\u{021C0}');
-    map.insert("rightleftarrows", '\u{021C4}');
-    map.insert("rightleftharpoons", '\u{021CC}');
-    map.insert("rightrightarrows", '\u{021C9}');
-    map.insert("rightsquigarrow", '\u{0219D}');
-    map.insert("rightthreetimes", '\u{022CC}');
-    map.insert("ring", '\u{002DA}');
-    map.insert("risingdotseq", '\u{02253}');
-    map.insert("rlarr", '\u{021C4}');
-    map.insert("rlhar", '\u{021CC}');
-    map.insert("rlm", '\u{0200F}');
-    map.insert("rmoust", '\u{023B1}');
-    map.insert("rmoustache", '\u{023B1}');
-    map.insert("rnmid", '\u{02AEE}');
-    map.insert("roang", '\u{027ED}');
-    map.insert("roarr", '\u{021FE}');
-    map.insert("robrk", '\u{027E7}');
-    map.insert("ropar", '\u{02986}');
-    map.insert("ropf", '\u{1D563}');
-    map.insert("roplus", '\u{02A2E}');
-    map.insert("rotimes", '\u{02A35}');
-    map.insert("rpar", '\u{00029}');
-    map.insert("rpargt", '\u{02994}');
-    map.insert("rppolint", '\u{02A12}');
-    map.insert("rrarr", '\u{021C9}');
-    map.insert("rsaquo", '\u{0203A}');
-    map.insert("rscr", '\u{1D4C7}');
-    map.insert("rsh", '\u{021B1}');
-    map.insert("rsqb", '\u{0005D}');
-    map.insert("rsquo", '\u{02019}');
-    map.insert("rsquor", '\u{02019}');
-    map.insert("rthree", '\u{022CC}');
-    map.insert("rtimes", '\u{022CA}');
-    map.insert("rtri", '\u{025B9}');
-    map.insert("rtrie", '\u{022B5}');
-    map.insert("rtrif", '\u{025B8}');
-    map.insert("rtriltri", '\u{029CE}');
-    map.insert("ruluhar", '\u{02968}');
-    map.insert("rx", '\u{0211E}');
-    map.insert("sacute", '\u{0015B}');
-    map.insert("sbquo", '\u{0201A}');
-    map.insert("sc", '\u{0227B}');
-    map.insert("scE", '\u{02AB4}');
-    map.insert("scap", '\u{02AB8}');
-    map.insert("scaron", '\u{00161}');
-    map.insert("sccue", '\u{0227D}');
-    map.insert("sce", '\u{02AB0}');
-    map.insert("scedil", '\u{0015F}');
-    map.insert("scirc", '\u{0015D}');
-    map.insert("scnE", '\u{02AB6}');
-    map.insert("scnap", '\u{02ABA}');
-    map.insert("scnsim", '\u{022E9}');
-    map.insert("scpolint", '\u{02A13}');
-    map.insert("scsim", '\u{0227F}');
-    map.insert("scy", '\u{00441}');
-    map.insert("sdot", '\u{022C5}');
-    map.insert("sdotb", '\u{022A1}');
-    map.insert("sdote", '\u{02A66}');
-    map.insert("seArr", '\u{021D8}');
-    map.insert("searhk", '\u{02925}');
-    map.insert("searr", '\u{02198}');
-    map.insert("searrow", '\u{02198}');
-    map.insert("sect", '\u{000A7}');
-    map.insert("semi", '\u{0003B}');
-    map.insert("seswar", '\u{02929}');
-    map.insert("setminus", '\u{02216}');
-    map.insert("setmn", '\u{02216}');
-    map.insert("sext", '\u{02736}');
-    map.insert("sfr", '\u{1D530}');
-    map.insert("sfrown", '\u{02322}');
-    map.insert("sharp", '\u{0266F}');
-    map.insert("shchcy", '\u{00449}');
-    map.insert("shcy", '\u{00448}');
-    map.insert("shortmid", '\u{02223}');
-    map.insert("shortparallel", '\u{02225}');
-    map.insert("shy", '\u{000AD}');
-    map.insert("sigma", '\u{003C3}');
-    map.insert("sigmaf", '\u{003C2}');
-    map.insert("sigmav", '\u{003C2}');
-    map.insert("sim", '\u{0223C}');
-    map.insert("simdot", '\u{02A6A}');
-    map.insert("sime", '\u{02243}');
-    map.insert("simeq", '\u{02243}');
-    map.insert("simg", '\u{02A9E}');
-    map.insert("simgE", '\u{02AA0}');
-    map.insert("siml", '\u{02A9D}');
-    map.insert("simlE", '\u{02A9F}');
-    map.insert("simne", '\u{02246}');
-    map.insert("simplus", '\u{02A24}');
-    map.insert("simrarr", '\u{02972}');
-    map.insert("slarr", '\u{02190}');
-    map.insert("smallsetminus", '\u{02216}');
-    map.insert("smashp", '\u{02A33}');
-    map.insert("smeparsl", '\u{029E4}');
-    map.insert("smid", '\u{02223}');
-    map.insert("smile", '\u{02323}');
-    map.insert("smt", '\u{02AAA}');
-    map.insert("smte", '\u{02AAC}');
-    map.insert("softcy", '\u{0044C}');
-    map.insert("sol", '\u{0002F}');
-    map.insert("solb", '\u{029C4}');
-    map.insert("solbar", '\u{0233F}');
-    map.insert("sopf", '\u{1D564}');
-    map.insert("spades", '\u{02660}');
-    map.insert("spadesuit", '\u{02660}');
-    map.insert("spar", '\u{02225}');
-    map.insert("sqcap", '\u{02293}');
-    map.insert("sqcup", '\u{02294}');
-    map.insert("sqsub", '\u{0228F}');
-    map.insert("sqsube", '\u{02291}');
-    map.insert("sqsubset", '\u{0228F}');
-    map.insert("sqsubseteq", '\u{02291}');
-    map.insert("sqsup", '\u{02290}');
-    map.insert("sqsupe", '\u{02292}');
-    map.insert("sqsupset", '\u{02290}');
-    map.insert("sqsupseteq", '\u{02292}');
-    map.insert("squ", '\u{025A1}');
-    map.insert("square", '\u{025A1}');
-    map.insert("squarf", '\u{025AA}');
-    map.insert("squf", '\u{025AA}');
-    map.insert("srarr", '\u{02192}');
-    map.insert("sscr", '\u{1D4C8}');
-    map.insert("ssetmn", '\u{02216}');
-    map.insert("ssmile", '\u{02323}');
-    map.insert("sstarf", '\u{022C6}');
-    map.insert("star", '\u{02606}');
-    map.insert("starf", '\u{02605}');
-    map.insert("straightepsilon", '\u{003F5}');
-    map.insert("straightphi", '\u{003D5}');
-    map.insert("strns", '\u{000AF}');
-    map.insert("sub", '\u{02282}');
-    map.insert("subE", '\u{02AC5}');
-    map.insert("subdot", '\u{02ABD}');
-    map.insert("sube", '\u{02286}');
-    map.insert("subedot", '\u{02AC3}');
-    map.insert("submult", '\u{02AC1}');
-    map.insert("subnE", '\u{02ACB}');
-    map.insert("subne", '\u{0228A}');
-    map.insert("subplus", '\u{02ABF}');
-    map.insert("subrarr", '\u{02979}');
-    map.insert("subset", '\u{02282}');
-    map.insert("subseteq", '\u{02286}');
-    map.insert("subseteqq", '\u{02AC5}');
-    map.insert("subsetneq", '\u{0228A}');
-    map.insert("subsetneqq", '\u{02ACB}');
-    map.insert("subsim", '\u{02AC7}');
-    map.insert("subsub", '\u{02AD5}');
-    map.insert("subsup", '\u{02AD3}');
-    map.insert("succ", '\u{0227B}');
-    map.insert("succapprox", '\u{02AB8}');
-    map.insert("succcurlyeq", '\u{0227D}');
-    map.insert("succeq", '\u{02AB0}');
-    map.insert("succnapprox", '\u{02ABA}');
-    map.insert("succneqq", '\u{02AB6}');
-    map.insert("succnsim", '\u{022E9}');
-    map.insert("succsim", '\u{0227F}');
-    map.insert("sum", '\u{02211}');
-    map.insert("sung", '\u{0266A}');
-    map.insert("sup1", '\u{000B9}');
-    map.insert("sup2", '\u{000B2}');
-    map.insert("sup3", '\u{000B3}');
-    map.insert("sup", '\u{02283}');
-    map.insert("supE", '\u{02AC6}');
-    map.insert("supdot", '\u{02ABE}');
-    map.insert("supdsub", '\u{02AD8}');
-    map.insert("supe", '\u{02287}');
-    map.insert("supedot", '\u{02AC4}');
-    map.insert("suphsol", '\u{027C9}');
-    map.insert("suphsub", '\u{02AD7}');
-    map.insert("suplarr", '\u{0297B}');
-    map.insert("supmult", '\u{02AC2}');
-    map.insert("supnE", '\u{02ACC}');
-    map.insert("supne", '\u{0228B}');
-    map.insert("supplus", '\u{02AC0}');
-    map.insert("supset", '\u{02283}');
-    map.insert("supseteq", '\u{02287}');
-    map.insert("supseteqq", '\u{02AC6}');
-    map.insert("supsetneq", '\u{0228B}');
-    map.insert("supsetneqq", '\u{02ACC}');
-    map.insert("supsim", '\u{02AC8}');
-    map.insert("supsub", '\u{02AD4}');
-    map.insert("supsup", '\u{02AD6}');
-    map.insert("swArr", '\u{021D9}');
-    map.insert("swarhk", '\u{02926}');
-    map.insert("swarr", '\u{02199}');
-    map.insert("swarrow", '\u{02199}');
-    map.insert("swnwar", '\u{0292A}');
-    map.insert("szlig", '\u{000DF}');
-    map.insert("target", '\u{02316}');
-    map.insert("tau", '\u{003C4}');
-    map.insert("tbrk", '\u{023B4}');
-    map.insert("tcaron", '\u{00165}');
-    map.insert("tcedil", '\u{00163}');
-    map.insert("tcy", '\u{00442}');
-    map.insert("tdot", '\u{020DB}');
-    map.insert("telrec", '\u{02315}');
-    map.insert("tfr", '\u{1D531}');
-    map.insert("there4", '\u{02234}');
-    map.insert("therefore", '\u{02234}');
-    map.insert("theta", '\u{003B8}');
-    map.insert("thetasym", '\u{003D1}');
-    map.insert("thetav", '\u{003D1}');
-    map.insert("thickapprox", '\u{02248}');
-    map.insert("thicksim", '\u{0223C}');
-    map.insert("thinsp", '\u{02009}');
-    map.insert("thkap", '\u{02248}');
-    map.insert("thksim", '\u{0223C}');
-    map.insert("thorn", '\u{000FE}');
-    map.insert("tilde", '\u{002DC}');
-    map.insert("times", '\u{000D7}');
-    map.insert("timesb", '\u{022A0}');
-    map.insert("timesbar", '\u{02A31}');
-    map.insert("timesd", '\u{02A30}');
-    map.insert("tint", '\u{0222D}');
-    map.insert("toea", '\u{02928}');
-    map.insert("top", '\u{022A4}');
-    map.insert("topbot", '\u{02336}');
-    map.insert("topcir", '\u{02AF1}');
-    map.insert("topf", '\u{1D565}');
-    map.insert("topfork", '\u{02ADA}');
-    map.insert("tosa", '\u{02929}');
-    map.insert("tprime", '\u{02034}');
-    map.insert("trade", '\u{02122}');
-    map.insert("triangle", '\u{025B5}');
-    map.insert("triangledown", '\u{025BF}');
-    map.insert("triangleleft", '\u{025C3}');
-    map.insert("trianglelefteq", '\u{022B4}');
-    map.insert("triangleq", '\u{0225C}');
-    map.insert("triangleright", '\u{025B9}');
-    map.insert("trianglerighteq", '\u{022B5}');
-    map.insert("tridot", '\u{025EC}');
-    map.insert("trie", '\u{0225C}');
-    map.insert("triminus", '\u{02A3A}');
-    map.insert("triplus", '\u{02A39}');
-    map.insert("trisb", '\u{029CD}');
-    map.insert("tritime", '\u{02A3B}');
-    map.insert("trpezium", '\u{023E2}');
-    map.insert("tscr", '\u{1D4C9}');
-    map.insert("tscy", '\u{00446}');
-    map.insert("tshcy", '\u{0045B}');
-    map.insert("tstrok", '\u{00167}');
-    map.insert("twixt", '\u{0226C}');
-    map.insert("twoheadleftarrow", '\u{0219E}');
-    map.insert("twoheadrightarrow", '\u{021A0}');
-    map.insert("uArr", '\u{021D1}');
-    map.insert("uHar", '\u{02963}');
-    map.insert("uacute", '\u{000FA}');
-    map.insert("uarr", '\u{02191}');
-    map.insert("ubrcy", '\u{0045E}');
-    map.insert("ubreve", '\u{0016D}');
-    map.insert("ucirc", '\u{000FB}');
-    map.insert("ucy", '\u{00443}');
-    map.insert("udarr", '\u{021C5}');
-    map.insert("udblac", '\u{00171}');
-    map.insert("udhar", '\u{0296E}');
-    map.insert("ufisht", '\u{0297E}');
-    map.insert("ufr", '\u{1D532}');
-    map.insert("ugrave", '\u{000F9}');
-    map.insert("uharl", '\u{021BF}');
-    map.insert("uharr", '\u{021BE}');
-    map.insert("uhblk", '\u{02580}');
-    map.insert("ulcorn", '\u{0231C}');
-    map.insert("ulcorner", '\u{0231C}');
-    map.insert("ulcrop", '\u{0230F}');
-    map.insert("ultri", '\u{025F8}');
-    map.insert("umacr", '\u{0016B}');
-    map.insert("uml", '\u{000A8}');
-    map.insert("uogon", '\u{00173}');
-    map.insert("uopf", '\u{1D566}');
-    map.insert("uparrow", '\u{02191}');
-    map.insert("updownarrow", '\u{02195}');
-    map.insert("upharpoonleft", '\u{021BF}');
-    map.insert("upharpoonright", '\u{021BE}');
-    map.insert("uplus", '\u{0228E}');
-    map.insert("upsi", '\u{003C5}');
-    map.insert("upsih", '\u{003D2}');
-    map.insert("upsilon", '\u{003C5}');
-    map.insert("upuparrows", '\u{021C8}');
-    map.insert("urcorn", '\u{0231D}');
-    map.insert("urcorner", '\u{0231D}');
-    map.insert("urcrop", '\u{0230E}');
-    map.insert("uring", '\u{0016F}');
-    map.insert("urtri", '\u{025F9}');
-    map.insert("uscr", '\u{1D4CA}');
-    map.insert("utdot", '\u{022F0}');
-    map.insert("utilde", '\u{00169}');
-    map.insert("utri", '\u{025B5}');
-    map.insert("utrif", '\u{025B4}');
-    map.insert("uuarr", '\u{021C8}');
-    map.insert("uuml", '\u{000FC}');
-    map.insert("uwangle", '\u{029A7}');
-    map.insert("vArr", '\u{021D5}');
-    map.insert("vBar", '\u{02AE8}');
-    map.insert("vBarv", '\u{02AE9}');
-    map.insert("vDash", '\u{022A8}');
-    map.insert("vangrt", '\u{0299C}');
-    map.insert("varepsilon", '\u{003F5}');
-    map.insert("varkappa", '\u{003F0}');
-    map.insert("varnothing", '\u{02205}');
-    map.insert("varphi", '\u{003D5}');
-    map.insert("varpi", '\u{003D6}');
-    map.insert("varpropto", '\u{0221D}');
-    map.insert("varr", '\u{02195}');
-    map.insert("varrho", '\u{003F1}');
-    map.insert("varsigma", '\u{003C2}');
-    map.insert("vartheta", '\u{003D1}');
-    map.insert("vartriangleleft", '\u{022B2}');
-    map.insert("vartriangleright", '\u{022B3}');
-    map.insert("vcy", '\u{00432}');
-    map.insert("vdash", '\u{022A2}');
-    map.insert("vee", '\u{02228}');
-    map.insert("veebar", '\u{022BB}');
-    map.insert("veeeq", '\u{0225A}');
-    map.insert("vellip", '\u{022EE}');
-    map.insert("verbar", '\u{0007C}');
-    map.insert("vert", '\u{0007C}');
-    map.insert("vfr", '\u{1D533}');
-    map.insert("vltri", '\u{022B2}');
-    map.insert("vopf", '\u{1D567}');
-    map.insert("vprop", '\u{0221D}');
-    map.insert("vrtri", '\u{022B3}');
-    map.insert("vscr", '\u{1D4CB}');
-    map.insert("vzigzag", '\u{0299A}');
-    map.insert("wcirc", '\u{00175}');
-    map.insert("wedbar", '\u{02A5F}');
-    map.insert("wedge", '\u{02227}');
-    map.insert("wedgeq", '\u{02259}');
-    map.insert("weierp", '\u{02118}');
-    map.insert("wfr", '\u{1D534}');
-    map.insert("wopf", '\u{1D568}');
-    map.insert("wp", '\u{02118}');
-    map.insert("wr", '\u{02240}');
-    map.insert("wreath", '\u{02240}');
-    map.insert("wscr", '\u{1D4CC}');
-    map.insert("xcap", '\u{022C2}');
-    map.insert("xcirc", '\u{025EF}');
-    map.insert("xcup", '\u{022C3}');
-    map.insert("xdtri", '\u{025BD}');
-    map.insert("xfr", '\u{1D535}');
-    map.insert("xhArr", '\u{027FA}');
-    map.insert("xharr", '\u{027F7}');
-    map.insert("xi", '\u{003BE}');
-    map.insert("xlArr", '\u{027F8}');
-    map.insert("xlarr", '\u{027F5}');
-    map.insert("xmap", '\u{027FC}');
-    map.insert("xnis", '\u{022FB}');
-    map.insert("xodot", '\u{02A00}');
-    map.insert("xopf", '\u{1D569}');
-    map.insert("xoplus", '\u{02A01}');
-    map.insert("xotime", '\u{02A02}');
-    map.insert("xrArr", '\u{027F9}');
-    map.insert("xrarr", '\u{027F6}');
-    map.insert("xscr", '\u{1D4CD}');
-    map.insert("xsqcup", '\u{02A06}');
-    map.insert("xuplus", '\u{02A04}');
-    map.insert("xutri", '\u{025B3}');
-    map.insert("xvee", '\u{022C1}');
-    map.insert("xwedge", '\u{022C0}');
-    map.insert("yacute", '\u{000FD}');
-    map.insert("yacy", '\u{0044F}');
-    map.insert("ycirc", '\u{00177}');
-    map.insert("ycy", '\u{0044B}');
-    map.insert("yen", '\u{000A5}');
-    map.insert("yfr", '\u{1D536}');
-    map.insert("yicy", '\u{00457}');
-    map.insert("yopf", '\u{1D56A}');
-    map.insert("yscr", '\u{1D4CE}');
-    map.insert("yucy", '\u{0044E}');
-    map.insert("yuml", '\u{000FF}');
-    map.insert("zacute", '\u{0017A}');
-    map.insert("zcaron", '\u{0017E}');
-    map.insert("zcy", '\u{00437}');
-    map.insert("zdot", '\u{0017C}');
-    map.insert("zeetrf", '\u{02128}');
-    map.insert("zeta", '\u{003B6}');
-    map.insert("zfr", '\u{1D537}');
-    map.insert("zhcy", '\u{00436}');
-    map.insert("zigrarr", '\u{021DD}');
-    map.insert("zopf", '\u{1D56B}');
-    map.insert("zscr", '\u{1D4CF}');
-    map.insert("zwj", '\u{0200D}');
-    map.insert("zwnj", '\u{0200C}');
-    map
-}
+/// Named character references of the HTML specification sorted by name
+///
+/// Names are stored without the trailing `;`. The flag marks legacy entities that are also
+/// recognized without a semicolon.
+pub(crate) static ENTITIES: [(&str, &str, bool); 2125] = [
+    ("AElig", "\u{000C6}", true),
+    ("AMP", "\u{00026}", true),
+    ("Aacute", "\u{000C1}", true),
+    ("Abreve", "\u{00102}", false),
+    ("Acirc", "\u{000C2}", true),
+    ("Acy", "\u{00410}", false),
+    ("Afr", "\u{1D504}", false),
+    ("Agrave", "\u{000C0}", true),
+    ("Alpha", "\u{00391}", false),
+    ("Amacr", "\u{00100}", false),
+    ("And", "\u{02A53}", false),
+    ("Aogon", "\u{00104}", false),
+    ("Aopf", "\u{1D538}", false),
+    ("ApplyFunction", "\u{02061}", false),
+    ("Aring", "\u{000C5}", true),
+    ("Ascr", "\u{1D49C}", false),
+    ("Assign", "\u{02254}", false),
+    ("Atilde", "\u{000C3}", true),
+    ("Auml", "\u{000C4}", true),
+    ("Backslash", "\u{02216}", false),
+    ("Barv", "\u{02AE7}", false),
+    ("Barwed", "\u{02306}", false),
+    ("Bcy", "\u{00411}", false),
+    ("Because", "\u{02235}", false),
+    ("Bernoullis", "\u{0212C}", false),
+    ("Beta", "\u{00392}", false),
+    ("Bfr", "\u{1D505}", false),
+    ("Bopf", "\u{1D539}", false),
+    ("Breve", "\u{002D8}", false),
+    ("Bscr", "\u{0212C}", false),
+    ("Bumpeq", "\u{0224E}", false),
+    ("CHcy", "\u{00427}", false),
+    ("COPY", "\u{000A9}", true),
+    ("Cacute", "\u{00106}", false),
+    ("Cap", "\u{022D2}", false),
+    ("CapitalDifferentialD", "\u{02145}", false),
+    ("Cayleys", "\u{0212D}", false),
+    ("Ccaron", "\u{0010C}", false),
+    ("Ccedil", "\u{000C7}", true),
+    ("Ccirc", "\u{00108}", false),
+    ("Cconint", "\u{02230}", false),
+    ("Cdot", "\u{0010A}", false),
+    ("Cedilla", "\u{000B8}", false),
+    ("CenterDot", "\u{000B7}", false),
+    ("Cfr", "\u{0212D}", false),
+    ("Chi", "\u{003A7}", false),
+    ("CircleDot", "\u{02299}", false),
+    ("CircleMinus", "\u{02296}", false),
+    ("CirclePlus", "\u{02295}", false),
+    ("CircleTimes", "\u{02297}", false),
+    ("ClockwiseContourIntegral", "\u{02232}", false),
+    ("CloseCurlyDoubleQuote", "\u{0201D}", false),
+    ("CloseCurlyQuote", "\u{02019}", false),
+    ("Colon", "\u{02237}", false),
+    ("Colone", "\u{02A74}", false),
+    ("Congruent", "\u{02261}", false),
+    ("Conint", "\u{0222F}", false),
+    ("ContourIntegral", "\u{0222E}", false),
+    ("Copf", "\u{02102}", false),
+    ("Coproduct", "\u{02210}", false),
+    ("CounterClockwiseContourIntegral", "\u{02233}", false),
+    ("Cross", "\u{02A2F}", false),
+    ("Cscr", "\u{1D49E}", false),
+    ("Cup", "\u{022D3}", false),
+    ("CupCap", "\u{0224D}", false),
+    ("DD", "\u{02145}", false),
+    ("DDotrahd", "\u{02911}", false),
+    ("DJcy", "\u{00402}", false),
+    ("DScy", "\u{00405}", false),
+    ("DZcy", "\u{0040F}", false),
+    ("Dagger", "\u{02021}", false),
+    ("Darr", "\u{021A1}", false),
+    ("Dashv", "\u{02AE4}", false),
+    ("Dcaron", "\u{0010E}", false),
+    ("Dcy", "\u{00414}", false),
+    ("Del", "\u{02207}", false),
+    ("Delta", "\u{00394}", false),
+    ("Dfr", "\u{1D507}", false),
+    ("DiacriticalAcute", "\u{000B4}", false),
+    ("DiacriticalDot", "\u{002D9}", false),
+    ("DiacriticalDoubleAcute", "\u{002DD}", false),
+    ("DiacriticalGrave", "\u{00060}", false),
+    ("DiacriticalTilde", "\u{002DC}", false),
+    ("Diamond", "\u{022C4}", false),
+    ("DifferentialD", "\u{02146}", false),
+    ("Dopf", "\u{1D53B}", false),
+    ("Dot", "\u{000A8}", false),
+    ("DotDot", "\u{020DC}", false),
+    ("DotEqual", "\u{02250}", false),
+    ("DoubleContourIntegral", "\u{0222F}", false),
+    ("DoubleDot", "\u{000A8}", false),
+    ("DoubleDownArrow", "\u{021D3}", false),
+    ("DoubleLeftArrow", "\u{021D0}", false),
+    ("DoubleLeftRightArrow", "\u{021D4}", false),
+    ("DoubleLeftTee", "\u{02AE4}", false),
+    ("DoubleLongLeftArrow", "\u{027F8}", false),
+    ("DoubleLongLeftRightArrow", "\u{027FA}", false),
+    ("DoubleLongRightArrow", "\u{027F9}", false),
+    ("DoubleRightArrow", "\u{021D2}", false),
+    ("DoubleRightTee", "\u{022A8}", false),
+    ("DoubleUpArrow", "\u{021D1}", false),
+    ("DoubleUpDownArrow", "\u{021D5}", false),
+    ("DoubleVerticalBar", "\u{02225}", false),
+    ("DownArrow", "\u{02193}", false),
+    ("DownArrowBar", "\u{02913}", false),
+    ("DownArrowUpArrow", "\u{021F5}", false),
+    ("DownBreve", "\u{00311}", false),
+    ("DownLeftRightVector", "\u{02950}", false),
+    ("DownLeftTeeVector", "\u{0295E}", false),
+    ("DownLeftVector", "\u{021BD}", false),
+    ("DownLeftVectorBar", "\u{02956}", false),
+    ("DownRightTeeVector", "\u{0295F}", false),
+    ("DownRightVector", "\u{021C1}", false),
+    ("DownRightVectorBar", "\u{02957}", false),
+    ("DownTee", "\u{022A4}", false),
+    ("DownTeeArrow", "\u{021A7}", false),
+    ("Downarrow", "\u{021D3}", false),
+    ("Dscr", "\u{1D49F}", false),
+    ("Dstrok", "\u{00110}", false),
+    ("ENG", "\u{0014A}", false),
+    ("ETH", "\u{000D0}", true),
+    ("Eacute", "\u{000C9}", true),
+    ("Ecaron", "\u{0011A}", false),
+    ("Ecirc", "\u{000CA}", true),
+    ("Ecy", "\u{0042D}", false),
+    ("Edot", "\u{00116}", false),
+    ("Efr", "\u{1D508}", false),
+    ("Egrave", "\u{000C8}", true),
+    ("Element", "\u{02208}", false),
+    ("Emacr", "\u{00112}", false),
+    ("EmptySmallSquare", "\u{025FB}", false),
+    ("EmptyVerySmallSquare", "\u{025AB}", false),
+    ("Eogon", "\u{00118}", false),
+    ("Eopf", "\u{1D53C}", false),
+    ("Epsilon", "\u{00395}", false),
+    ("Equal", "\u{02A75}", false),
+    ("EqualTilde", "\u{02242}", false),
+    ("Equilibrium", "\u{021CC}", false),
+    ("Escr", "\u{02130}", false),
+    ("Esim", "\u{02A73}", false),
+    ("Eta", "\u{00397}", false),
+    ("Euml", "\u{000CB}", true),
+    ("Exists", "\u{02203}", false),
+    ("ExponentialE", "\u{02147}", false),
+    ("Fcy", "\u{00424}", false),
+    ("Ffr", "\u{1D509}", false),
+    ("FilledSmallSquare", "\u{025FC}", false),
+    ("FilledVerySmallSquare", "\u{025AA}", false),
+    ("Fopf", "\u{1D53D}", false),
+    ("ForAll", "\u{02200}", false),
+    ("Fouriertrf", "\u{02131}", false),
+    ("Fscr", "\u{02131}", false),
+    ("GJcy", "\u{00403}", false),
+    ("GT", "\u{0003E}", true),
+    ("Gamma", "\u{00393}", false),
+    ("Gammad", "\u{003DC}", false),
+    ("Gbreve", "\u{0011E}", false),
+    ("Gcedil", "\u{00122}", false),
+    ("Gcirc", "\u{0011C}", false),
+    ("Gcy", "\u{00413}", false),
+    ("Gdot", "\u{00120}", false),
+    ("Gfr", "\u{1D50A}", false),
+    ("Gg", "\u{022D9}", false),
+    ("Gopf", "\u{1D53E}", false),
+    ("GreaterEqual", "\u{02265}", false),
+    ("GreaterEqualLess", "\u{022DB}", false),
+    ("GreaterFullEqual", "\u{02267}", false),
+    ("GreaterGreater", "\u{02AA2}", false),
+    ("GreaterLess", "\u{02277}", false),
+    ("GreaterSlantEqual", "\u{02A7E}", false),
+    ("GreaterTilde", "\u{02273}", false),
+    ("Gscr", "\u{1D4A2}", false),
+    ("Gt", "\u{0226B}", false),
+    ("HARDcy", "\u{0042A}", false),
+    ("Hacek", "\u{002C7}", false),
+    ("Hat", "\u{0005E}", false),
+    ("Hcirc", "\u{00124}", false),
+    ("Hfr", "\u{0210C}", false),
+    ("HilbertSpace", "\u{0210B}", false),
+    ("Hopf", "\u{0210D}", false),
+    ("HorizontalLine", "\u{02500}", false),
+    ("Hscr", "\u{0210B}", false),
+    ("Hstrok", "\u{00126}", false),
+    ("HumpDownHump", "\u{0224E}", false),
+    ("HumpEqual", "\u{0224F}", false),
+    ("IEcy", "\u{00415}", false),
+    ("IJlig", "\u{00132}", false),
+    ("IOcy", "\u{00401}", false),
+    ("Iacute", "\u{000CD}", true),
+    ("Icirc", "\u{000CE}", true),
+    ("Icy", "\u{00418}", false),
+    ("Idot", "\u{00130}", false),
+    ("Ifr", "\u{02111}", false),
+    ("Igrave", "\u{000CC}", true),
+    ("Im", "\u{02111}", false),
+    ("Imacr", "\u{0012A}", false),
+    ("ImaginaryI", "\u{02148}", false),
+    ("Implies", "\u{021D2}", false),
+    ("Int", "\u{0222C}", false),
+    ("Integral", "\u{0222B}", false),
+    ("Intersection", "\u{022C2}", false),
+    ("InvisibleComma", "\u{02063}", false),
+    ("InvisibleTimes", "\u{02062}", false),
+    ("Iogon", "\u{0012E}", false),
+    ("Iopf", "\u{1D540}", false),
+    ("Iota", "\u{00399}", false),
+    ("Iscr", "\u{02110}", false),
+    ("Itilde", "\u{00128}", false),
+    ("Iukcy", "\u{00406}", false),
+    ("Iuml", "\u{000CF}", true),
+    ("Jcirc", "\u{00134}", false),
+    ("Jcy", "\u{00419}", false),
+    ("Jfr", "\u{1D50D}", false),
+    ("Jopf", "\u{1D541}", false),
+    ("Jscr", "\u{1D4A5}", false),
+    ("Jsercy", "\u{00408}", false),
+    ("Jukcy", "\u{00404}", false),
+    ("KHcy", "\u{00425}", false),
+    ("KJcy", "\u{0040C}", false),
+    ("Kappa", "\u{0039A}", false),
+    ("Kcedil", "\u{00136}", false),
+    ("Kcy", "\u{0041A}", false),
+    ("Kfr", "\u{1D50E}", false),
+    ("Kopf", "\u{1D542}", false),
+    ("Kscr", "\u{1D4A6}", false),
+    ("LJcy", "\u{00409}", false),
+    ("LT", "\u{0003C}", true),
+    ("Lacute", "\u{00139}", false),
+    ("Lambda", "\u{0039B}", false),
+    ("Lang", "\u{027EA}", false),
+    ("Laplacetrf", "\u{02112}", false),
+    ("Larr", "\u{0219E}", false),
+    ("Lcaron", "\u{0013D}", false),
+    ("Lcedil", "\u{0013B}", false),
+    ("Lcy", "\u{0041B}", false),
+    ("LeftAngleBracket", "\u{027E8}", false),
+    ("LeftArrow", "\u{02190}", false),
+    ("LeftArrowBar", "\u{021E4}", false),
+    ("LeftArrowRightArrow", "\u{021C6}", false),
+    ("LeftCeiling", "\u{02308}", false),
+    ("LeftDoubleBracket", "\u{027E6}", false),
+    ("LeftDownTeeVector", "\u{02961}", false),
+    ("LeftDownVector", "\u{021C3}", false),
+    ("LeftDownVectorBar", "\u{02959}", false),
+    ("LeftFloor", "\u{0230A}", false),
+    ("LeftRightArrow", "\u{02194}", false),
+    ("LeftRightVector", "\u{0294E}", false),
+    ("LeftTee", "\u{022A3}", false),
+    ("LeftTeeArrow", "\u{021A4}", false),
+    ("LeftTeeVector", "\u{0295A}", false),
+    ("LeftTriangle", "\u{022B2}", false),
+    ("LeftTriangleBar", "\u{029CF}", false),
+    ("LeftTriangleEqual", "\u{022B4}", false),
+    ("LeftUpDownVector", "\u{02951}", false),
+    ("LeftUpTeeVector", "\u{02960}", false),
+    ("LeftUpVector", "\u{021BF}", false),
+    ("LeftUpVectorBar", "\u{02958}", false),
+    ("LeftVector", "\u{021BC}", false),
+    ("LeftVectorBar", "\u{02952}", false),
+    ("Leftarrow", "\u{021D0}", false),
+    ("Leftrightarrow", "\u{021D4}", false),
+    ("LessEqualGreater", "\u{022DA}", false),
+    ("LessFullEqual", "\u{02266}", false),
+    ("LessGreater", "\u{02276}", false),
+    ("LessLess", "\u{02AA1}", false),
+    ("LessSlantEqual", "\u{02A7D}", false),
+    ("LessTilde", "\u{02272}", false),
+    ("Lfr", "\u{1D50F}", false),
+    ("Ll", "\u{022D8}", false),
+    ("Lleftarrow", "\u{021DA}", false),
+    ("Lmidot", "\u{0013F}", false),
+    ("LongLeftArrow", "\u{027F5}", false),
+    ("LongLeftRightArrow", "\u{027F7}", false),
+    ("LongRightArrow", "\u{027F6}", false),
+    ("Longleftarrow", "\u{027F8}", false),
+    ("Longleftrightarrow", "\u{027FA}", false),
+    ("Longrightarrow", "\u{027F9}", false),
+    ("Lopf", "\u{1D543}", false),
+    ("LowerLeftArrow", "\u{02199}", false),
+    ("LowerRightArrow", "\u{02198}", false),
+    ("Lscr", "\u{02112}", false),
+    ("Lsh", "\u{021B0}", false),
+    ("Lstrok", "\u{00141}", false),
+    ("Lt", "\u{0226A}", false),
+    ("Map", "\u{02905}", false),
+    ("Mcy", "\u{0041C}", false),
+    ("MediumSpace", "\u{0205F}", false),
+    ("Mellintrf", "\u{02133}", false),
+    ("Mfr", "\u{1D510}", false),
+    ("MinusPlus", "\u{02213}", false),
+    ("Mopf", "\u{1D544}", false),
+    ("Mscr", "\u{02133}", false),
+    ("Mu", "\u{0039C}", false),
+    ("NJcy", "\u{0040A}", false),
+    ("Nacute", "\u{00143}", false),
+    ("Ncaron", "\u{00147}", false),
+    ("Ncedil", "\u{00145}", false),
+    ("Ncy", "\u{0041D}", false),
+    ("NegativeMediumSpace", "\u{0200B}", false),
+    ("NegativeThickSpace", "\u{0200B}", false),
+    ("NegativeThinSpace", "\u{0200B}", false),
+    ("NegativeVeryThinSpace", "\u{0200B}", false),
+    ("NestedGreaterGreater", "\u{0226B}", false),
+    ("NestedLessLess", "\u{0226A}", false),
+    ("NewLine", "\u{0000A}", false),
+    ("Nfr", "\u{1D511}", false),
+    ("NoBreak", "\u{02060}", false),
+    ("NonBreakingSpace", "\u{000A0}", false),
+    ("Nopf", "\u{02115}", false),
+    ("Not", "\u{02AEC}", false),
+    ("NotCongruent", "\u{02262}", false),
+    ("NotCupCap", "\u{0226D}", false),
+    ("NotDoubleVerticalBar", "\u{02226}", false),
+    ("NotElement", "\u{02209}", false),
+    ("NotEqual", "\u{02260}", false),
+    ("NotEqualTilde", "\u{02242}\u{00338}", false),
+    ("NotExists", "\u{02204}", false),
+    ("NotGreater", "\u{0226F}", false),
+    ("NotGreaterEqual", "\u{02271}", false),
+    ("NotGreaterFullEqual", "\u{02267}\u{00338}", false),
+    ("NotGreaterGreater", "\u{0226B}\u{00338}", false),
+    ("NotGreaterLess", "\u{02279}", false),
+    ("NotGreaterSlantEqual", "\u{02A7E}\u{00338}", false),
+    ("NotGreaterTilde", "\u{02275}", false),
+    ("NotHumpDownHump", "\u{0224E}\u{00338}", false),
+    ("NotHumpEqual", "\u{0224F}\u{00338}", false),
+    ("NotLeftTriangle", "\u{022EA}", false),
+    ("NotLeftTriangleBar", "\u{029CF}\u{00338}", false),
+    ("NotLeftTriangleEqual", "\u{022EC}", false),
+    ("NotLess", "\u{0226E}", false),
+    ("NotLessEqual", "\u{02270}", false),
+    ("NotLessGreater", "\u{02278}", false),
+    ("NotLessLess", "\u{0226A}\u{00338}", false),
+    ("NotLessSlantEqual", "\u{02A7D}\u{00338}", false),
+    ("NotLessTilde", "\u{02274}", false),
+    ("NotNestedGreaterGreater", "\u{02AA2}\u{00338}", false),
+    ("NotNestedLessLess", "\u{02AA1}\u{00338}", false),
+    ("NotPrecedes", "\u{02280}", false),
+    ("NotPrecedesEqual", "\u{02AAF}\u{00338}", false),
+    ("NotPrecedesSlantEqual", "\u{022E0}", false),
+    ("NotReverseElement", "\u{0220C}", false),
+    ("NotRightTriangle", "\u{022EB}", false),
+    ("NotRightTriangleBar", "\u{029D0}\u{00338}", false),
+    ("NotRightTriangleEqual", "\u{022ED}", false),
+    ("NotSquareSubset", "\u{0228F}\u{00338}", false),
+    ("NotSquareSubsetEqual", "\u{022E2}", false),
+    ("NotSquareSuperset", "\u{02290}\u{00338}", false),
+    ("NotSquareSupersetEqual", "\u{022E3}", false),
+    ("NotSubset", "\u{02282}\u{020D2}", false),
+    ("NotSubsetEqual", "\u{02288}", false),
+    ("NotSucceeds", "\u{02281}", false),
+    ("NotSucceedsEqual", "\u{02AB0}\u{00338}", false),
+    ("NotSucceedsSlantEqual", "\u{022E1}", false),
+    ("NotSucceedsTilde", "\u{0227F}\u{00338}", false),
+    ("NotSuperset", "\u{02283}\u{020D2}", false),
+    ("NotSupersetEqual", "\u{02289}", false),
+    ("NotTilde", "\u{02241}", false),
+    ("NotTildeEqual", "\u{02244}", false),
+    ("NotTildeFullEqual", "\u{02247}", false),
+    ("NotTildeTilde", "\u{02249}", false),
+    ("NotVerticalBar", "\u{02224}", false),
+    ("Nscr", "\u{1D4A9}", false),
+    ("Ntilde", "\u{000D1}", true),
+    ("Nu", "\u{0039D}", false),
+    ("OElig", "\u{00152}", false),
+    ("Oacute", "\u{000D3}", true),
+    ("Ocirc", "\u{000D4}", true),
+    ("Ocy", "\u{0041E}", false),
+    ("Odblac", "\u{00150}", false),
+    ("Ofr", "\u{1D512}", false),
+    ("Ograve", "\u{000D2}", true),
+    ("Omacr", "\u{0014C}", false),
+    ("Omega", "\u{003A9}", false),
+    ("Omicron", "\u{0039F}", false),
+    ("Oopf", "\u{1D546}", false),
+    ("OpenCurlyDoubleQuote", "\u{0201C}", false),
+    ("OpenCurlyQuote", "\u{02018}", false),
+    ("Or", "\u{02A54}", false),
+    ("Oscr", "\u{1D4AA}", false),
+    ("Oslash", "\u{000D8}", true),
+    ("Otilde", "\u{000D5}", true),
+    ("Otimes", "\u{02A37}", false),
+    ("Ouml", "\u{000D6}", true),
+    ("OverBar", "\u{0203E}", false),
+    ("OverBrace", "\u{023DE}", false),
+    ("OverBracket", "\u{023B4}", false),
+    ("OverParenthesis", "\u{023DC}", false),
+    ("PartialD", "\u{02202}", false),
+    ("Pcy", "\u{0041F}", false),
+    ("Pfr", "\u{1D513}", false),
+    ("Phi", "\u{003A6}", false),
+    ("Pi", "\u{003A0}", false),
+    ("PlusMinus", "\u{000B1}", false),
+    ("Poincareplane", "\u{0210C}", false),
+    ("Popf", "\u{02119}", false),
+    ("Pr", "\u{02ABB}", false),
+    ("Precedes", "\u{0227A}", false),
+    ("PrecedesEqual", "\u{02AAF}", false),
+    ("PrecedesSlantEqual", "\u{0227C}", false),
+    ("PrecedesTilde", "\u{0227E}", false),
+    ("Prime", "\u{02033}", false),
+    ("Product", "\u{0220F}", false),
+    ("Proportion", "\u{02237}", false),
+    ("Proportional", "\u{0221D}", false),
+    ("Pscr", "\u{1D4AB}", false),
+    ("Psi", "\u{003A8}", false),
+    ("QUOT", "\u{00022}", true),
+    ("Qfr", "\u{1D514}", false),
+    ("Qopf", "\u{0211A}", false),
+    ("Qscr", "\u{1D4AC}", false),
+    ("RBarr", "\u{02910}", false),
+    ("REG", "\u{000AE}", true),
+    ("Racute", "\u{00154}", false),
+    ("Rang", "\u{027EB}", false),
+    ("Rarr", "\u{021A0}", false),
+    ("Rarrtl", "\u{02916}", false),
+    ("Rcaron", "\u{00158}", false),
+    ("Rcedil", "\u{00156}", false),
+    ("Rcy", "\u{00420}", false),
+    ("Re", "\u{0211C}", false),
+    ("ReverseElement", "\u{0220B}", false),
+    ("ReverseEquilibrium", "\u{021CB}", false),
+    ("ReverseUpEquilibrium", "\u{0296F}", false),
+    ("Rfr", "\u{0211C}", false),
+    ("Rho", "\u{003A1}", false),
+    ("RightAngleBracket", "\u{027E9}", false),
+    ("RightArrow", "\u{02192}", false),
+    ("RightArrowBar", "\u{021E5}", false),
+    ("RightArrowLeftArrow", "\u{021C4}", false),
+    ("RightCeiling", "\u{02309}", false),
+    ("RightDoubleBracket", "\u{027E7}", false),
+    ("RightDownTeeVector", "\u{0295D}", false),
+    ("RightDownVector", "\u{021C2}", false),
+    ("RightDownVectorBar", "\u{02955}", false),
+    ("RightFloor", "\u{0230B}", false),
+    ("RightTee", "\u{022A2}", false),
+    ("RightTeeArrow", "\u{021A6}", false),
+    ("RightTeeVector", "\u{0295B}", false),
+    ("RightTriangle", "\u{022B3}", false),
+    ("RightTriangleBar", "\u{029D0}", false),
+    ("RightTriangleEqual", "\u{022B5}", false),
+    ("RightUpDownVector", "\u{0294F}", false),
+    ("RightUpTeeVector", "\u{0295C}", false),
+    ("RightUpVector", "\u{021BE}", false),
+    ("RightUpVectorBar", "\u{02954}", false),
+    ("RightVector", "\u{021C0}", false),
+    ("RightVectorBar", "\u{02953}", false),
+    ("Rightarrow", "\u{021D2}", false),
+    ("Ropf", "\u{0211D}", false),
+    ("RoundImplies", "\u{02970}", false),
+    ("Rrightarrow", "\u{021DB}", false),
+    ("Rscr", "\u{0211B}", false),
+    ("Rsh", "\u{021B1}", false),
+    ("RuleDelayed", "\u{029F4}", false),
+    ("SHCHcy", "\u{00429}", false),
+    ("SHcy", "\u{00428}", false),
+    ("SOFTcy", "\u{0042C}", false),
+    ("Sacute", "\u{0015A}", false),
+    ("Sc", "\u{02ABC}", false),
+    ("Scaron", "\u{00160}", false),
+    ("Scedil", "\u{0015E}", false),
+    ("Scirc", "\u{0015C}", false),
+    ("Scy", "\u{00421}", false),
+    ("Sfr", "\u{1D516}", false),
+    ("ShortDownArrow", "\u{02193}", false),
+    ("ShortLeftArrow", "\u{02190}", false),
+    ("ShortRightArrow", "\u{02192}", false),
+    ("ShortUpArrow", "\u{02191}", false),
+    ("Sigma", "\u{003A3}", false),
+    ("SmallCircle", "\u{02218}", false),
+    ("Sopf", "\u{1D54A}", false),
+    ("Sqrt", "\u{0221A}", false),
+    ("Square", "\u{025A1}", false),
+    ("SquareIntersection", "\u{02293}", false),
+    ("SquareSubset", "\u{0228F}", false),
+    ("SquareSubsetEqual", "\u{02291}", false),
+    ("SquareSuperset", "\u{02290}", false),
+    ("SquareSupersetEqual", "\u{02292}", false),
+    ("SquareUnion", "\u{02294}", false),
+    ("Sscr", "\u{1D4AE}", false),
+    ("Star", "\u{022C6}", false),
+    ("Sub", "\u{022D0}", false),
+    ("Subset", "\u{022D0}", false),
+    ("SubsetEqual", "\u{02286}", false),
+    ("Succeeds", "\u{0227B}", false),
+    ("SucceedsEqual", "\u{02AB0}", false),
+    ("SucceedsSlantEqual", "\u{0227D}", false),
+    ("SucceedsTilde", "\u{0227F}", false),
+    ("SuchThat", "\u{0220B}", false),
+    ("Sum", "\u{02211}", false),
+    ("Sup", "\u{022D1}", false),
+    ("Superset", "\u{02283}", false),
+    ("SupersetEqual", "\u{02287}", false),
+    ("Supset", "\u{022D1}", false),
+    ("THORN", "\u{000DE}", true),
+    ("TRADE", "\u{02122}", false),
+    ("TSHcy", "\u{0040B}", false),
+    ("TScy", "\u{00426}", false),
+    ("Tab", "\u{00009}", false),
+    ("Tau", "\u{003A4}", false),
+    ("Tcaron", "\u{00164}", false),
+    ("Tcedil", "\u{00162}", false),
+    ("Tcy", "\u{00422}", false),
+    ("Tfr", "\u{1D517}", false),
+    ("Therefore", "\u{02234}", false),
+    ("Theta", "\u{00398}", false),
+    ("ThickSpace", "\u{0205F}\u{0200A}", false),
+    ("ThinSpace", "\u{02009}", false),
+    ("Tilde", "\u{0223C}", false),
+    ("TildeEqual", "\u{02243}", false),
+    ("TildeFullEqual", "\u{02245}", false),
+    ("TildeTilde", "\u{02248}", false),
+    ("Topf", "\u{1D54B}", false),
+    ("TripleDot", "\u{020DB}", false),
+    ("Tscr", "\u{1D4AF}", false),
+    ("Tstrok", "\u{00166}", false),
+    ("Uacute", "\u{000DA}", true),
+    ("Uarr", "\u{0219F}", false),
+    ("Uarrocir", "\u{02949}", false),
+    ("Ubrcy", "\u{0040E}", false),
+    ("Ubreve", "\u{0016C}", false),
+    ("Ucirc", "\u{000DB}", true),
+    ("Ucy", "\u{00423}", false),
+    ("Udblac", "\u{00170}", false),
+    ("Ufr", "\u{1D518}", false),
+    ("Ugrave", "\u{000D9}", true),
+    ("Umacr", "\u{0016A}", false),
+    ("UnderBar", "\u{0005F}", false),
+    ("UnderBrace", "\u{023DF}", false),
+    ("UnderBracket", "\u{023B5}", false),
+    ("UnderParenthesis", "\u{023DD}", false),
+    ("Union", "\u{022C3}", false),
+    ("UnionPlus", "\u{0228E}", false),
+    ("Uogon", "\u{00172}", false),
+    ("Uopf", "\u{1D54C}", false),
+    ("UpArrow", "\u{02191}", false),
+    ("UpArrowBar", "\u{02912}", false),
+    ("UpArrowDownArrow", "\u{021C5}", false),
+    ("UpDownArrow", "\u{02195}", false),
+    ("UpEquilibrium", "\u{0296E}", false),
+    ("UpTee", "\u{022A5}", false),
+    ("UpTeeArrow", "\u{021A5}", false),
+    ("Uparrow", "\u{021D1}", false),
+    ("Updownarrow", "\u{021D5}", false),
+    ("UpperLeftArrow", "\u{02196}", false),
+    ("UpperRightArrow", "\u{02197}", false),
+    ("Upsi", "\u{003D2}", false),
+    ("Upsilon", "\u{003A5}", false),
+    ("Uring", "\u{0016E}", false),
+    ("Uscr", "\u{1D4B0}", false),
+    ("Utilde", "\u{00168}", false),
+    ("Uuml", "\u{000DC}", true),
+    ("VDash", "\u{022AB}", false),
+    ("Vbar", "\u{02AEB}", false),
+    ("Vcy", "\u{00412}", false),
+    ("Vdash", "\u{022A9}", false),
+    ("Vdashl", "\u{02AE6}", false),
+    ("Vee", "\u{022C1}", false),
+    ("Verbar", "\u{02016}", false),
+    ("Vert", "\u{02016}", false),
+    ("VerticalBar", "\u{02223}", false),
+    ("VerticalLine", "\u{0007C}", false),
+    ("VerticalSeparator", "\u{02758}", false),
+    ("VerticalTilde", "\u{02240}", false),
+    ("VeryThinSpace", "\u{0200A}", false),
+    ("Vfr", "\u{1D519}", false),
+    ("Vopf", "\u{1D54D}", false),
+    ("Vscr", "\u{1D4B1}", false),
+    ("Vvdash", "\u{022AA}", false),
+    ("Wcirc", "\u{00174}", false),
+    ("Wedge", "\u{022C0}", false),
+    ("Wfr", "\u{1D51A}", false),
+    ("Wopf", "\u{1D54E}", false),
+    ("Wscr", "\u{1D4B2}", false),
+    ("Xfr", "\u{1D51B}", false),
+    ("Xi", "\u{0039E}", false),
+    ("Xopf", "\u{1D54F}", false),
+    ("Xscr", "\u{1D4B3}", false),
+    ("YAcy", "\u{0042F}", false),
+    ("YIcy", "\u{00407}", false),
+    ("YUcy", "\u{0042E}", false),
+    ("Yacute", "\u{000DD}", true),
+    ("Ycirc", "\u{00176}", false),
+    ("Ycy", "\u{0042B}", false),
+    ("Yfr", "\u{1D51C}", false),
+    ("Yopf", "\u{1D550}", false),
+    ("Yscr", "\u{1D4B4}", false),
+    ("Yuml", "\u{00178}", false),
+    ("ZHcy", "\u{00416}", false),
+    ("Zacute", "\u{00179}", false),
+    ("Zcaron", "\u{0017D}", false),
+    ("Zcy", "\u{00417}", false),
+    ("Zdot", "\u{0017B}", false),
+    ("ZeroWidthSpace", "\u{0200B}", false),
+    ("Zeta", "\u{00396}", false),
+    ("Zfr", "\u{02128}", false),
+    ("Zopf", "\u{02124}", false),
+    ("Zscr", "\u{1D4B5}", false),
+    ("aacute", "\u{000E1}", true),
+    ("abreve", "\u{00103}", false),
+    ("ac", "\u{0223E}", false),
+    ("acE", "\u{0223E}\u{00333}", false),
+    ("acd", "\u{0223F}", false),
+    ("acirc", "\u{000E2}", true),
+    ("acute", "\u{000B4}", true),
+    ("acy", "\u{00430}", false),
+    ("aelig", "\u{000E6}", true),
+    ("af", "\u{02061}", false),
+    ("afr", "\u{1D51E}", false),
+    ("agrave", "\u{000E0}", true),
+    ("alefsym", "\u{02135}", false),
+    ("aleph", "\u{02135}", false),
+    ("alpha", "\u{003B1}", false),
+    ("amacr", "\u{00101}", false),
+    ("amalg", "\u{02A3F}", false),
+    ("amp", "\u{00026}", true),
+    ("and", "\u{02227}", false),
+    ("andand", "\u{02A55}", false),
+    ("andd", "\u{02A5C}", false),
+    ("andslope", "\u{02A58}", false),
+    ("andv", "\u{02A5A}", false),
+    ("ang", "\u{02220}", false),
+    ("ange", "\u{029A4}", false),
+    ("angle", "\u{02220}", false),
+    ("angmsd", "\u{02221}", false),
+    ("angmsdaa", "\u{029A8}", false),
+    ("angmsdab", "\u{029A9}", false),
+    ("angmsdac", "\u{029AA}", false),
+    ("angmsdad", "\u{029AB}", false),
+    ("angmsdae", "\u{029AC}", false),
+    ("angmsdaf", "\u{029AD}", false),
+    ("angmsdag", "\u{029AE}", false),
+    ("angmsdah", "\u{029AF}", false),
+    ("angrt", "\u{0221F}", false),
+    ("angrtvb", "\u{022BE}", false),
+    ("angrtvbd", "\u{0299D}", false),
+    ("angsph", "\u{02222}", false),
+    ("angst", "\u{000C5}", false),
+    ("angzarr", "\u{0237C}", false),
+    ("aogon", "\u{00105}", false),
+    ("aopf", "\u{1D552}", false),
+    ("ap", "\u{02248}", false),
+    ("apE", "\u{02A70}", false),
+    ("apacir", "\u{02A6F}", false),
+    ("ape", "\u{0224A}", false),
+    ("apid", "\u{0224B}", false),
+    ("apos", "\u{00027}", false),
+    ("approx", "\u{02248}", false),
+    ("approxeq", "\u{0224A}", false),
+    ("aring", "\u{000E5}", true),
+    ("ascr", "\u{1D4B6}", false),
+    ("ast", "\u{0002A}", false),
+    ("asymp", "\u{02248}", false),
+    ("asympeq", "\u{0224D}", false),
+    ("atilde", "\u{000E3}", true),
+    ("auml", "\u{000E4}", true),
+    ("awconint", "\u{02233}", false),
+    ("awint", "\u{02A11}", false),
+    ("bNot", "\u{02AED}", false),
+    ("backcong", "\u{0224C}", false),
+    ("backepsilon", "\u{003F6}", false),
+    ("backprime", "\u{02035}", false),
+    ("backsim", "\u{0223D}", false),
+    ("backsimeq", "\u{022CD}", false),
+    ("barvee", "\u{022BD}", false),
+    ("barwed", "\u{02305}", false),
+    ("barwedge", "\u{02305}", false),
+    ("bbrk", "\u{023B5}", false),
+    ("bbrktbrk", "\u{023B6}", false),
+    ("bcong", "\u{0224C}", false),
+    ("bcy", "\u{00431}", false),
+    ("bdquo", "\u{0201E}", false),
+    ("becaus", "\u{02235}", false),
+    ("because", "\u{02235}", false),
+    ("bemptyv", "\u{029B0}", false),
+    ("bepsi", "\u{003F6}", false),
+    ("bernou", "\u{0212C}", false),
+    ("beta", "\u{003B2}", false),
+    ("beth", "\u{02136}", false),
+    ("between", "\u{0226C}", false),
+    ("bfr", "\u{1D51F}", false),
+    ("bigcap", "\u{022C2}", false),
+    ("bigcirc", "\u{025EF}", false),
+    ("bigcup", "\u{022C3}", false),
+    ("bigodot", "\u{02A00}", false),
+    ("bigoplus", "\u{02A01}", false),
+    ("bigotimes", "\u{02A02}", false),
+    ("bigsqcup", "\u{02A06}", false),
+    ("bigstar", "\u{02605}", false),
+    ("bigtriangledown", "\u{025BD}", false),
+    ("bigtriangleup", "\u{025B3}", false),
+    ("biguplus", "\u{02A04}", false),
+    ("bigvee", "\u{022C1}", false),
+    ("bigwedge", "\u{022C0}", false),
+    ("bkarow", "\u{0290D}", false),
+    ("blacklozenge", "\u{029EB}", false),
+    ("blacksquare", "\u{025AA}", false),
+    ("blacktriangle", "\u{025B4}", false),
+    ("blacktriangledown", "\u{025BE}", false),
+    ("blacktriangleleft", "\u{025C2}", false),
+    ("blacktriangleright", "\u{025B8}", false),
+    ("blank", "\u{02423}", false),
+    ("blk12", "\u{02592}", false),
+    ("blk14", "\u{02591}", false),
+    ("blk34", "\u{02593}", false),
+    ("block", "\u{02588}", false),
+    ("bne", "\u{0003D}\u{020E5}", false),
+    ("bnequiv", "\u{02261}\u{020E5}", false),
+    ("bnot", "\u{02310}", false),
+    ("bopf", "\u{1D553}", false),
+    ("bot", "\u{022A5}", false),
+    ("bottom", "\u{022A5}", false),
+    ("bowtie", "\u{022C8}", false),
+    ("boxDL", "\u{02557}", false),
+    ("boxDR", "\u{02554}", false),
+    ("boxDl", "\u{02556}", false),
+    ("boxDr", "\u{02553}", false),
+    ("boxH", "\u{02550}", false),
+    ("boxHD", "\u{02566}", false),
+    ("boxHU", "\u{02569}", false),
+    ("boxHd", "\u{02564}", false),
+    ("boxHu", "\u{02567}", false),
+    ("boxUL", "\u{0255D}", false),
+    ("boxUR", "\u{0255A}", false),
+    ("boxUl", "\u{0255C}", false),
+    ("boxUr", "\u{02559}", false),
+    ("boxV", "\u{02551}", false),
+    ("boxVH", "\u{0256C}", false),
+    ("boxVL", "\u{02563}", false),
+    ("boxVR", "\u{02560}", false),
+    ("boxVh", "\u{0256B}", false),
+    ("boxVl", "\u{02562}", false),
+    ("boxVr", "\u{0255F}", false),
+    ("boxbox", "\u{029C9}", false),
+    ("boxdL", "\u{02555}", false),
+    ("boxdR", "\u{02552}", false),
+    ("boxdl", "\u{02510}", false),
+    ("boxdr", "\u{0250C}", false),
+    ("boxh", "\u{02500}", false),
+    ("boxhD", "\u{02565}", false),
+    ("boxhU", "\u{02568}", false),
+    ("boxhd", "\u{0252C}", false),
+    ("boxhu", "\u{02534}", false),
+    ("boxminus", "\u{0229F}", false),
+    ("boxplus", "\u{0229E}", false),
+    ("boxtimes", "\u{022A0}", false),
+    ("boxuL", "\u{0255B}", false),
+    ("boxuR", "\u{02558}", false),
+    ("boxul", "\u{02518}", false),
+    ("boxur", "\u{02514}", false),
+    ("boxv", "\u{02502}", false),
+    ("boxvH", "\u{0256A}", false),
+    ("boxvL", "\u{02561}", false),
+    ("boxvR", "\u{0255E}", false),
+    ("boxvh", "\u{0253C}", false),
+    ("boxvl", "\u{02524}", false),
+    ("boxvr", "\u{0251C}", false),
+    ("bprime", "\u{02035}", false),
+    ("breve", "\u{002D8}", false),
+    ("brvbar", "\u{000A6}", true),
+    ("bscr", "\u{1D4B7}", false),
+    ("bsemi", "\u{0204F}", false),
+    ("bsim", "\u{0223D}", false),
+    ("bsime", "\u{022CD}", false),
+    ("bsol", "\u{0005C}", false),
+    ("bsolb", "\u{029C5}", false),
+    ("bsolhsub", "\u{027C8}", false),
+    ("bull", "\u{02022}", false),
+    ("bullet", "\u{02022}", false),
+    ("bump", "\u{0224E}", false),
+    ("bumpE", "\u{02AAE}", false),
+    ("bumpe", "\u{0224F}", false),
+    ("bumpeq", "\u{0224F}", false),
+    ("cacute", "\u{00107}", false),
+    ("cap", "\u{02229}", false),
+    ("capand", "\u{02A44}", false),
+    ("capbrcup", "\u{02A49}", false),
+    ("capcap", "\u{02A4B}", false),
+    ("capcup", "\u{02A47}", false),
+    ("capdot", "\u{02A40}", false),
+    ("caps", "\u{02229}\u{0FE00}", false),
+    ("caret", "\u{02041}", false),
+    ("caron", "\u{002C7}", false),
+    ("ccaps", "\u{02A4D}", false),
+    ("ccaron", "\u{0010D}", false),
+    ("ccedil", "\u{000E7}", true),
+    ("ccirc", "\u{00109}", false),
+    ("ccups", "\u{02A4C}", false),
+    ("ccupssm", "\u{02A50}", false),
+    ("cdot", "\u{0010B}", false),
+    ("cedil", "\u{000B8}", true),
+    ("cemptyv", "\u{029B2}", false),
+    ("cent", "\u{000A2}", true),
+    ("centerdot", "\u{000B7}", false),
+    ("cfr", "\u{1D520}", false),
+    ("chcy", "\u{00447}", false),
+    ("check", "\u{02713}", false),
+    ("checkmark", "\u{02713}", false),
+    ("chi", "\u{003C7}", false),
+    ("cir", "\u{025CB}", false),
+    ("cirE", "\u{029C3}", false),
+    ("circ", "\u{002C6}", false),
+    ("circeq", "\u{02257}", false),
+    ("circlearrowleft", "\u{021BA}", false),
+    ("circlearrowright", "\u{021BB}", false),
+    ("circledR", "\u{000AE}", false),
+    ("circledS", "\u{024C8}", false),
+    ("circledast", "\u{0229B}", false),
+    ("circledcirc", "\u{0229A}", false),
+    ("circleddash", "\u{0229D}", false),
+    ("cire", "\u{02257}", false),
+    ("cirfnint", "\u{02A10}", false),
+    ("cirmid", "\u{02AEF}", false),
+    ("cirscir", "\u{029C2}", false),
+    ("clubs", "\u{02663}", false),
+    ("clubsuit", "\u{02663}", false),
+    ("colon", "\u{0003A}", false),
+    ("colone", "\u{02254}", false),
+    ("coloneq", "\u{02254}", false),
+    ("comma", "\u{0002C}", false),
+    ("commat", "\u{00040}", false),
+    ("comp", "\u{02201}", false),
+    ("compfn", "\u{02218}", false),
+    ("complement", "\u{02201}", false),
+    ("complexes", "\u{02102}", false),
+    ("cong", "\u{02245}", false),
+    ("congdot", "\u{02A6D}", false),
+    ("conint", "\u{0222E}", false),
+    ("copf", "\u{1D554}", false),
+    ("coprod", "\u{02210}", false),
+    ("copy", "\u{000A9}", true),
+    ("copysr", "\u{02117}", false),
+    ("crarr", "\u{021B5}", false),
+    ("cross", "\u{02717}", false),
+    ("cscr", "\u{1D4B8}", false),
+    ("csub", "\u{02ACF}", false),
+    ("csube", "\u{02AD1}", false),
+    ("csup", "\u{02AD0}", false),
+    ("csupe", "\u{02AD2}", false),
+    ("ctdot", "\u{022EF}", false),
+    ("cudarrl", "\u{02938}", false),
+    ("cudarrr", "\u{02935}", false),
+    ("cuepr", "\u{022DE}", false),
+    ("cuesc", "\u{022DF}", false),
+    ("cularr", "\u{021B6}", false),
+    ("cularrp", "\u{0293D}", false),
+    ("cup", "\u{0222A}", false),
+    ("cupbrcap", "\u{02A48}", false),
+    ("cupcap", "\u{02A46}", false),
+    ("cupcup", "\u{02A4A}", false),
+    ("cupdot", "\u{0228D}", false),
+    ("cupor", "\u{02A45}", false),
+    ("cups", "\u{0222A}\u{0FE00}", false),
+    ("curarr", "\u{021B7}", false),
+    ("curarrm", "\u{0293C}", false),
+    ("curlyeqprec", "\u{022DE}", false),
+    ("curlyeqsucc", "\u{022DF}", false),
+    ("curlyvee", "\u{022CE}", false),
+    ("curlywedge", "\u{022CF}", false),
+    ("curren", "\u{000A4}", true),
+    ("curvearrowleft", "\u{021B6}", false),
+    ("curvearrowright", "\u{021B7}", false),
+    ("cuvee", "\u{022CE}", false),
+    ("cuwed", "\u{022CF}", false),
+    ("cwconint", "\u{02232}", false),
+    ("cwint", "\u{02231}", false),
+    ("cylcty", "\u{0232D}", false),
+    ("dArr", "\u{021D3}", false),
+    ("dHar", "\u{02965}", false),
+    ("dagger", "\u{02020}", false),
+    ("daleth", "\u{02138}", false),
+    ("darr", "\u{02193}", false),
+    ("dash", "\u{02010}", false),
+    ("dashv", "\u{022A3}", false),
+    ("dbkarow", "\u{0290F}", false),
+    ("dblac", "\u{002DD}", false),
+    ("dcaron", "\u{0010F}", false),
+    ("dcy", "\u{00434}", false),
+    ("dd", "\u{02146}", false),
+    ("ddagger", "\u{02021}", false),
+    ("ddarr", "\u{021CA}", false),
+    ("ddotseq", "\u{02A77}", false),
+    ("deg", "\u{000B0}", true),
+    ("delta", "\u{003B4}", false),
+    ("demptyv", "\u{029B1}", false),
+    ("dfisht", "\u{0297F}", false),
+    ("dfr", "\u{1D521}", false),
+    ("dharl", "\u{021C3}", false),
+    ("dharr", "\u{021C2}", false),
+    ("diam", "\u{022C4}", false),
+    ("diamond", "\u{022C4}", false),
+    ("diamondsuit", "\u{02666}", false),
+    ("diams", "\u{02666}", false),
+    ("die", "\u{000A8}", false),
+    ("digamma", "\u{003DD}", false),
+    ("disin", "\u{022F2}", false),
+    ("div", "\u{000F7}", false),
+    ("divide", "\u{000F7}", true),
+    ("divideontimes", "\u{022C7}", false),
+    ("divonx", "\u{022C7}", false),
+    ("djcy", "\u{00452}", false),
+    ("dlcorn", "\u{0231E}", false),
+    ("dlcrop", "\u{0230D}", false),
+    ("dollar", "\u{00024}", false),
+    ("dopf", "\u{1D555}", false),
+    ("dot", "\u{002D9}", false),
+    ("doteq", "\u{02250}", false),
+    ("doteqdot", "\u{02251}", false),
+    ("dotminus", "\u{02238}", false),
+    ("dotplus", "\u{02214}", false),
+    ("dotsquare", "\u{022A1}", false),
+    ("doublebarwedge", "\u{02306}", false),
+    ("downarrow", "\u{02193}", false),
+    ("downdownarrows", "\u{021CA}", false),
+    ("downharpoonleft", "\u{021C3}", false),
+    ("downharpoonright", "\u{021C2}", false),
+    ("drbkarow", "\u{02910}", false),
+    ("drcorn", "\u{0231F}", false),
+    ("drcrop", "\u{0230C}", false),
+    ("dscr", "\u{1D4B9}", false),
+    ("dscy", "\u{00455}", false),
+    ("dsol", "\u{029F6}", false),
+    ("dstrok", "\u{00111}", false),
+    ("dtdot", "\u{022F1}", false),
+    ("dtri", "\u{025BF}", false),
+    ("dtrif", "\u{025BE}", false),
+    ("duarr", "\u{021F5}", false),
+    ("duhar", "\u{0296F}", false),
+    ("dwangle", "\u{029A6}", false),
+    ("dzcy", "\u{0045F}", false),
+    ("dzigrarr", "\u{027FF}", false),
+    ("eDDot", "\u{02A77}", false),
+    ("eDot", "\u{02251}", false),
+    ("eacute", "\u{000E9}", true),
+    ("easter", "\u{02A6E}", false),
+    ("ecaron", "\u{0011B}", false),
+    ("ecir", "\u{02256}", false),
+    ("ecirc", "\u{000EA}", true),
+    ("ecolon", "\u{02255}", false),
+    ("ecy", "\u{0044D}", false),
+    ("edot", "\u{00117}", false),
+    ("ee", "\u{02147}", false),
+    ("efDot", "\u{02252}", false),
+    ("efr", "\u{1D522}", false),
+    ("eg", "\u{02A9A}", false),
+    ("egrave", "\u{000E8}", true),
+    ("egs", "\u{02A96}", false),
+    ("egsdot", "\u{02A98}", false),
+    ("el", "\u{02A99}", false),
+    ("elinters", "\u{023E7}", false),
+    ("ell", "\u{02113}", false),
+    ("els", "\u{02A95}", false),
+    ("elsdot", "\u{02A97}", false),
+    ("emacr", "\u{00113}", false),
+    ("empty", "\u{02205}", false),
+    ("emptyset", "\u{02205}", false),
+    ("emptyv", "\u{02205}", false),
+    ("emsp", "\u{02003}", false),
+    ("emsp13", "\u{02004}", false),
+    ("emsp14", "\u{02005}", false),
+    ("eng", "\u{0014B}", false),
+    ("ensp", "\u{02002}", false),
+    ("eogon", "\u{00119}", false),
+    ("eopf", "\u{1D556}", false),
+    ("epar", "\u{022D5}", false),
+    ("eparsl", "\u{029E3}", false),
+    ("eplus", "\u{02A71}", false),
+    ("epsi", "\u{003B5}", false),
+    ("epsilon", "\u{003B5}", false),
+    ("epsiv", "\u{003F5}", false),
+    ("eqcirc", "\u{02256}", false),
+    ("eqcolon", "\u{02255}", false),
+    ("eqsim", "\u{02242}", false),
+    ("eqslantgtr", "\u{02A96}", false),
+    ("eqslantless", "\u{02A95}", false),
+    ("equals", "\u{0003D}", false),
+    ("equest", "\u{0225F}", false),
+    ("equiv", "\u{02261}", false),
+    ("equivDD", "\u{02A78}", false),
+    ("eqvparsl", "\u{029E5}", false),
+    ("erDot", "\u{02253}", false),
+    ("erarr", "\u{02971}", false),
+    ("escr", "\u{0212F}", false),
+    ("esdot", "\u{02250}", false),
+    ("esim", "\u{02242}", false),
+    ("eta", "\u{003B7}", false),
+    ("eth", "\u{000F0}", true),
+    ("euml", "\u{000EB}", true),
+    ("euro", "\u{020AC}", false),
+    ("excl", "\u{00021}", false),
+    ("exist", "\u{02203}", false),
+    ("expectation", "\u{02130}", false),
+    ("exponentiale", "\u{02147}", false),
+    ("fallingdotseq", "\u{02252}", false),
+    ("fcy", "\u{00444}", false),
+    ("female", "\u{02640}", false),
+    ("ffilig", "\u{0FB03}", false),
+    ("fflig", "\u{0FB00}", false),
+    ("ffllig", "\u{0FB04}", false),
+    ("ffr", "\u{1D523}", false),
+    ("filig", "\u{0FB01}", false),
+    ("fjlig", "\u{00066}\u{0006A}", false),
+    ("flat", "\u{0266D}", false),
+    ("fllig", "\u{0FB02}", false),
+    ("fltns", "\u{025B1}", false),
+    ("fnof", "\u{00192}", false),
+    ("fopf", "\u{1D557}", false),
+    ("forall", "\u{02200}", false),
+    ("fork", "\u{022D4}", false),
+    ("forkv", "\u{02AD9}", false),
+    ("fpartint", "\u{02A0D}", false),
+    ("frac12", "\u{000BD}", true),
+    ("frac13", "\u{02153}", false),
+    ("frac14", "\u{000BC}", true),
+    ("frac15", "\u{02155}", false),
+    ("frac16", "\u{02159}", false),
+    ("frac18", "\u{0215B}", false),
+    ("frac23", "\u{02154}", false),
+    ("frac25", "\u{02156}", false),
+    ("frac34", "\u{000BE}", true),
+    ("frac35", "\u{02157}", false),
+    ("frac38", "\u{0215C}", false),
+    ("frac45", "\u{02158}", false),
+    ("frac56", "\u{0215A}", false),
+    ("frac58", "\u{0215D}", false),
+    ("frac78", "\u{0215E}", false),
+    ("frasl", "\u{02044}", false),
+    ("frown", "\u{02322}", false),
+    ("fscr", "\u{1D4BB}", false),
+    ("gE", "\u{02267}", false),
+    ("gEl", "\u{02A8C}", false),
+    ("gacute", "\u{001F5}", false),
+    ("gamma", "\u{003B3}", false),
+    ("gammad", "\u{003DD}", false),
+    ("gap", "\u{02A86}", false),
+    ("gbreve", "\u{0011F}", false),
+    ("gcirc", "\u{0011D}", false),
+    ("gcy", "\u{00433}", false),
+    ("gdot", "\u{00121}", false),
+    ("ge", "\u{02265}", false),
+    ("gel", "\u{022DB}", false),
+    ("geq", "\u{02265}", false),
+    ("geqq", "\u{02267}", false),
+    ("geqslant", "\u{02A7E}", false),
+    ("ges", "\u{02A7E}", false),
+    ("gescc", "\u{02AA9}", false),
+    ("gesdot", "\u{02A80}", false),
+    ("gesdoto", "\u{02A82}", false),
+    ("gesdotol", "\u{02A84}", false),
+    ("gesl", "\u{022DB}\u{0FE00}", false),
+    ("gesles", "\u{02A94}", false),
+    ("gfr", "\u{1D524}", false),
+    ("gg", "\u{0226B}", false),
+    ("ggg", "\u{022D9}", false),
+    ("gimel", "\u{02137}", false),
+    ("gjcy", "\u{00453}", false),
+    ("gl", "\u{02277}", false),
+    ("glE", "\u{02A92}", false),
+    ("gla", "\u{02AA5}", false),
+    ("glj", "\u{02AA4}", false),
+    ("gnE", "\u{02269}", false),
+    ("gnap", "\u{02A8A}", false),
+    ("gnapprox", "\u{02A8A}", false),
+    ("gne", "\u{02A88}", false),
+    ("gneq", "\u{02A88}", false),
+    ("gneqq", "\u{02269}", false),
+    ("gnsim", "\u{022E7}", false),
+    ("gopf", "\u{1D558}", false),
+    ("grave", "\u{00060}", false),
+    ("gscr", "\u{0210A}", false),
+    ("gsim", "\u{02273}", false),
+    ("gsime", "\u{02A8E}", false),
+    ("gsiml", "\u{02A90}", false),
+    ("gt", "\u{0003E}", true),
+    ("gtcc", "\u{02AA7}", false),
+    ("gtcir", "\u{02A7A}", false),
+    ("gtdot", "\u{022D7}", false),
+    ("gtlPar", "\u{02995}", false),
+    ("gtquest", "\u{02A7C}", false),
+    ("gtrapprox", "\u{02A86}", false),
+    ("gtrarr", "\u{02978}", false),
+    ("gtrdot", "\u{022D7}", false),
+    ("gtreqless", "\u{022DB}", false),
+    ("gtreqqless", "\u{02A8C}", false),
+    ("gtrless", "\u{02277}", false),
+    ("gtrsim", "\u{02273}", false),
+    ("gvertneqq", "\u{02269}\u{0FE00}", false),
+    ("gvnE", "\u{02269}\u{0FE00}", false),
+    ("hArr", "\u{021D4}", false),
+    ("hairsp", "\u{0200A}", false),
+    ("half", "\u{000BD}", false),
+    ("hamilt", "\u{0210B}", false),
+    ("hardcy", "\u{0044A}", false),
+    ("harr", "\u{02194}", false),
+    ("harrcir", "\u{02948}", false),
+    ("harrw", "\u{021AD}", false),
+    ("hbar", "\u{0210F}", false),
+    ("hcirc", "\u{00125}", false),
+    ("hearts", "\u{02665}", false),
+    ("heartsuit", "\u{02665}", false),
+    ("hellip", "\u{02026}", false),
+    ("hercon", "\u{022B9}", false),
+    ("hfr", "\u{1D525}", false),
+    ("hksearow", "\u{02925}", false),
+    ("hkswarow", "\u{02926}", false),
+    ("hoarr", "\u{021FF}", false),
+    ("homtht", "\u{0223B}", false),
+    ("hookleftarrow", "\u{021A9}", false),
+    ("hookrightarrow", "\u{021AA}", false),
+    ("hopf", "\u{1D559}", false),
+    ("horbar", "\u{02015}", false),
+    ("hscr", "\u{1D4BD}", false),
+    ("hslash", "\u{0210F}", false),
+    ("hstrok", "\u{00127}", false),
+    ("hybull", "\u{02043}", false),
+    ("hyphen", "\u{02010}", false),
+    ("iacute", "\u{000ED}", true),
+    ("ic", "\u{02063}", false),
+    ("icirc", "\u{000EE}", true),
+    ("icy", "\u{00438}", false),
+    ("iecy", "\u{00435}", false),
+    ("iexcl", "\u{000A1}", true),
+    ("iff", "\u{021D4}", false),
+    ("ifr", "\u{1D526}", false),
+    ("igrave", "\u{000EC}", true),
+    ("ii", "\u{02148}", false),
+    ("iiiint", "\u{02A0C}", false),
+    ("iiint", "\u{0222D}", false),
+    ("iinfin", "\u{029DC}", false),
+    ("iiota", "\u{02129}", false),
+    ("ijlig", "\u{00133}", false),
+    ("imacr", "\u{0012B}", false),
+    ("image", "\u{02111}", false),
+    ("imagline", "\u{02110}", false),
+    ("imagpart", "\u{02111}", false),
+    ("imath", "\u{00131}", false),
+    ("imof", "\u{022B7}", false),
+    ("imped", "\u{001B5}", false),
+    ("in", "\u{02208}", false),
+    ("incare", "\u{02105}", false),
+    ("infin", "\u{0221E}", false),
+    ("infintie", "\u{029DD}", false),
+    ("inodot", "\u{00131}", false),
+    ("int", "\u{0222B}", false),
+    ("intcal", "\u{022BA}", false),
+    ("integers", "\u{02124}", false),
+    ("intercal", "\u{022BA}", false),
+    ("intlarhk", "\u{02A17}", false),
+    ("intprod", "\u{02A3C}", false),
+    ("iocy", "\u{00451}", false),
+    ("iogon", "\u{0012F}", false),
+    ("iopf", "\u{1D55A}", false),
+    ("iota", "\u{003B9}", false),
+    ("iprod", "\u{02A3C}", false),
+    ("iquest", "\u{000BF}", true),
+    ("iscr", "\u{1D4BE}", false),
+    ("isin", "\u{02208}", false),
+    ("isinE", "\u{022F9}", false),
+    ("isindot", "\u{022F5}", false),
+    ("isins", "\u{022F4}", false),
+    ("isinsv", "\u{022F3}", false),
+    ("isinv", "\u{02208}", false),
+    ("it", "\u{02062}", false),
+    ("itilde", "\u{00129}", false),
+    ("iukcy", "\u{00456}", false),
+    ("iuml", "\u{000EF}", true),
+    ("jcirc", "\u{00135}", false),
+    ("jcy", "\u{00439}", false),
+    ("jfr", "\u{1D527}", false),
+    ("jmath", "\u{00237}", false),
+    ("jopf", "\u{1D55B}", false),
+    ("jscr", "\u{1D4BF}", false),
+    ("jsercy", "\u{00458}", false),
+    ("jukcy", "\u{00454}", false),
+    ("kappa", "\u{003BA}", false),
+    ("kappav", "\u{003F0}", false),
+    ("kcedil", "\u{00137}", false),
+    ("kcy", "\u{0043A}", false),
+    ("kfr", "\u{1D528}", false),
+    ("kgreen", "\u{00138}", false),
+    ("khcy", "\u{00445}", false),
+    ("kjcy", "\u{0045C}", false),
+    ("kopf", "\u{1D55C}", false),
+    ("kscr", "\u{1D4C0}", false),
+    ("lAarr", "\u{021DA}", false),
+    ("lArr", "\u{021D0}", false),
+    ("lAtail", "\u{0291B}", false),
+    ("lBarr", "\u{0290E}", false),
+    ("lE", "\u{02266}", false),
+    ("lEg", "\u{02A8B}", false),
+    ("lHar", "\u{02962}", false),
+    ("lacute", "\u{0013A}", false),
+    ("laemptyv", "\u{029B4}", false),
+    ("lagran", "\u{02112}", false),
+    ("lambda", "\u{003BB}", false),
+    ("lang", "\u{027E8}", false),
+    ("langd", "\u{02991}", false),
+    ("langle", "\u{027E8}", false),
+    ("lap", "\u{02A85}", false),
+    ("laquo", "\u{000AB}", true),
+    ("larr", "\u{02190}", false),
+    ("larrb", "\u{021E4}", false),
+    ("larrbfs", "\u{0291F}", false),
+    ("larrfs", "\u{0291D}", false),
+    ("larrhk", "\u{021A9}", false),
+    ("larrlp", "\u{021AB}", false),
+    ("larrpl", "\u{02939}", false),
+    ("larrsim", "\u{02973}", false),
+    ("larrtl", "\u{021A2}", false),
+    ("lat", "\u{02AAB}", false),
+    ("latail", "\u{02919}", false),
+    ("late", "\u{02AAD}", false),
+    ("lates", "\u{02AAD}\u{0FE00}", false),
+    ("lbarr", "\u{0290C}", false),
+    ("lbbrk", "\u{02772}", false),
+    ("lbrace", "\u{0007B}", false),
+    ("lbrack", "\u{0005B}", false),
+    ("lbrke", "\u{0298B}", false),
+    ("lbrksld", "\u{0298F}", false),
+    ("lbrkslu", "\u{0298D}", false),
+    ("lcaron", "\u{0013E}", false),
+    ("lcedil", "\u{0013C}", false),
+    ("lceil", "\u{02308}", false),
+    ("lcub", "\u{0007B}", false),
+    ("lcy", "\u{0043B}", false),
+    ("ldca", "\u{02936}", false),
+    ("ldquo", "\u{0201C}", false),
+    ("ldquor", "\u{0201E}", false),
+    ("ldrdhar", "\u{02967}", false),
+    ("ldrushar", "\u{0294B}", false),
+    ("ldsh", "\u{021B2}", false),
+    ("le", "\u{02264}", false),
+    ("leftarrow", "\u{02190}", false),
+    ("leftarrowtail", "\u{021A2}", false),
+    ("leftharpoondown", "\u{021BD}", false),
+    ("leftharpoonup", "\u{021BC}", false),
+    ("leftleftarrows", "\u{021C7}", false),
+    ("leftrightarrow", "\u{02194}", false),
+    ("leftrightarrows", "\u{021C6}", false),
+    ("leftrightharpoons", "\u{021CB}", false),
+    ("leftrightsquigarrow", "\u{021AD}", false),
+    ("leftthreetimes", "\u{022CB}", false),
+    ("leg", "\u{022DA}", false),
+    ("leq", "\u{02264}", false),
+    ("leqq", "\u{02266}", false),
+    ("leqslant", "\u{02A7D}", false),
+    ("les", "\u{02A7D}", false),
+    ("lescc", "\u{02AA8}", false),
+    ("lesdot", "\u{02A7F}", false),
+    ("lesdoto", "\u{02A81}", false),
+    ("lesdotor", "\u{02A83}", false),
+    ("lesg", "\u{022DA}\u{0FE00}", false),
+    ("lesges", "\u{02A93}", false),
+    ("lessapprox", "\u{02A85}", false),
+    ("lessdot", "\u{022D6}", false),
+    ("lesseqgtr", "\u{022DA}", false),
+    ("lesseqqgtr", "\u{02A8B}", false),
+    ("lessgtr", "\u{02276}", false),
+    ("lesssim", "\u{02272}", false),
+    ("lfisht", "\u{0297C}", false),
+    ("lfloor", "\u{0230A}", false),
+    ("lfr", "\u{1D529}", false),
+    ("lg", "\u{02276}", false),
+    ("lgE", "\u{02A91}", false),
+    ("lhard", "\u{021BD}", false),
+    ("lharu", "\u{021BC}", false),
+    ("lharul", "\u{0296A}", false),
+    ("lhblk", "\u{02584}", false),
+    ("ljcy", "\u{00459}", false),
+    ("ll", "\u{0226A}", false),
+    ("llarr", "\u{021C7}", false),
+    ("llcorner", "\u{0231E}", false),
+    ("llhard", "\u{0296B}", false),
+    ("lltri", "\u{025FA}", false),
+    ("lmidot", "\u{00140}", false),
+    ("lmoust", "\u{023B0}", false),
+    ("lmoustache", "\u{023B0}", false),
+    ("lnE", "\u{02268}", false),
+    ("lnap", "\u{02A89}", false),
+    ("lnapprox", "\u{02A89}", false),
+    ("lne", "\u{02A87}", false),
+    ("lneq", "\u{02A87}", false),
+    ("lneqq", "\u{02268}", false),
+    ("lnsim", "\u{022E6}", false),
+    ("loang", "\u{027EC}", false),
+    ("loarr", "\u{021FD}", false),
+    ("lobrk", "\u{027E6}", false),
+    ("longleftarrow", "\u{027F5}", false),
+    ("longleftrightarrow", "\u{027F7}", false),
+    ("longmapsto", "\u{027FC}", false),
+    ("longrightarrow", "\u{027F6}", false),
+    ("looparrowleft", "\u{021AB}", false),
+    ("looparrowright", "\u{021AC}", false),
+    ("lopar", "\u{02985}", false),
+    ("lopf", "\u{1D55D}", false),
+    ("loplus", "\u{02A2D}", false),
+    ("lotimes", "\u{02A34}", false),
+    ("lowast", "\u{02217}", false),
+    ("lowbar", "\u{0005F}", false),
+    ("loz", "\u{025CA}", false),
+    ("lozenge", "\u{025CA}", false),
+    ("lozf", "\u{029EB}", false),
+    ("lpar", "\u{00028}", false),
+    ("lparlt", "\u{02993}", false),
+    ("lrarr", "\u{021C6}", false),
+    ("lrcorner", "\u{0231F}", false),
+    ("lrhar", "\u{021CB}", false),
+    ("lrhard", "\u{0296D}", false),
+    ("lrm", "\u{0200E}", false),
+    ("lrtri", "\u{022BF}", false),
+    ("lsaquo", "\u{02039}", false),
+    ("lscr", "\u{1D4C1}", false),
+    ("lsh", "\u{021B0}", false),
+    ("lsim", "\u{02272}", false),
+    ("lsime", "\u{02A8D}", false),
+    ("lsimg", "\u{02A8F}", false),
+    ("lsqb", "\u{0005B}", false),
+    ("lsquo", "\u{02018}", false),
+    ("lsquor", "\u{0201A}", false),
+    ("lstrok", "\u{00142}", false),
+    ("lt", "\u{0003C}", true),
+    ("ltcc", "\u{02AA6}", false),
+    ("ltcir", "\u{02A79}", false),
+    ("ltdot", "\u{022D6}", false),
+    ("lthree", "\u{022CB}", false),
+    ("ltimes", "\u{022C9}", false),
+    ("ltlarr", "\u{02976}", false),
+    ("ltquest", "\u{02A7B}", false),
+    ("ltrPar", "\u{02996}", false),
+    ("ltri", "\u{025C3}", false),
+    ("ltrie", "\u{022B4}", false),
+    ("ltrif", "\u{025C2}", false),
+    ("lurdshar", "\u{0294A}", false),
+    ("luruhar", "\u{02966}", false),
+    ("lvertneqq", "\u{02268}\u{0FE00}", false),
+    ("lvnE", "\u{02268}\u{0FE00}", false),
+    ("mDDot", "\u{0223A}", false),
+    ("macr", "\u{000AF}", true),
+    ("male", "\u{02642}", false),
+    ("malt", "\u{02720}", false),
+    ("maltese", "\u{02720}", false),
+    ("map", "\u{021A6}", false),
+    ("mapsto", "\u{021A6}", false),
+    ("mapstodown", "\u{021A7}", false),
+    ("mapstoleft", "\u{021A4}", false),
+    ("mapstoup", "\u{021A5}", false),
+    ("marker", "\u{025AE}", false),
+    ("mcomma", "\u{02A29}", false),
+    ("mcy", "\u{0043C}", false),
+    ("mdash", "\u{02014}", false),
+    ("measuredangle", "\u{02221}", false),
+    ("mfr", "\u{1D52A}", false),
+    ("mho", "\u{02127}", false),
+    ("micro", "\u{000B5}", true),
+    ("mid", "\u{02223}", false),
+    ("midast", "\u{0002A}", false),
+    ("midcir", "\u{02AF0}", false),
+    ("middot", "\u{000B7}", true),
+    ("minus", "\u{02212}", false),
+    ("minusb", "\u{0229F}", false),
+    ("minusd", "\u{02238}", false),
+    ("minusdu", "\u{02A2A}", false),
+    ("mlcp", "\u{02ADB}", false),
+    ("mldr", "\u{02026}", false),
+    ("mnplus", "\u{02213}", false),
+    ("models", "\u{022A7}", false),
+    ("mopf", "\u{1D55E}", false),
+    ("mp", "\u{02213}", false),
+    ("mscr", "\u{1D4C2}", false),
+    ("mstpos", "\u{0223E}", false),
+    ("mu", "\u{003BC}", false),
+    ("multimap", "\u{022B8}", false),
+    ("mumap", "\u{022B8}", false),
+    ("nGg", "\u{022D9}\u{00338}", false),
+    ("nGt", "\u{0226B}\u{020D2}", false),
+    ("nGtv", "\u{0226B}\u{00338}", false),
+    ("nLeftarrow", "\u{021CD}", false),
+    ("nLeftrightarrow", "\u{021CE}", false),
+    ("nLl", "\u{022D8}\u{00338}", false),
+    ("nLt", "\u{0226A}\u{020D2}", false),
+    ("nLtv", "\u{0226A}\u{00338}", false),
+    ("nRightarrow", "\u{021CF}", false),
+    ("nVDash", "\u{022AF}", false),
+    ("nVdash", "\u{022AE}", false),
+    ("nabla", "\u{02207}", false),
+    ("nacute", "\u{00144}", false),
+    ("nang", "\u{02220}\u{020D2}", false),
+    ("nap", "\u{02249}", false),
+    ("napE", "\u{02A70}\u{00338}", false),
+    ("napid", "\u{0224B}\u{00338}", false),
+    ("napos", "\u{00149}", false),
+    ("napprox", "\u{02249}", false),
+    ("natur", "\u{0266E}", false),
+    ("natural", "\u{0266E}", false),
+    ("naturals", "\u{02115}", false),
+    ("nbsp", "\u{000A0}", true),
+    ("nbump", "\u{0224E}\u{00338}", false),
+    ("nbumpe", "\u{0224F}\u{00338}", false),
+    ("ncap", "\u{02A43}", false),
+    ("ncaron", "\u{00148}", false),
+    ("ncedil", "\u{00146}", false),
+    ("ncong", "\u{02247}", false),
+    ("ncongdot", "\u{02A6D}\u{00338}", false),
+    ("ncup", "\u{02A42}", false),
+    ("ncy", "\u{0043D}", false),
+    ("ndash", "\u{02013}", false),
+    ("ne", "\u{02260}", false),
+    ("neArr", "\u{021D7}", false),
+    ("nearhk", "\u{02924}", false),
+    ("nearr", "\u{02197}", false),
+    ("nearrow", "\u{02197}", false),
+    ("nedot", "\u{02250}\u{00338}", false),
+    ("nequiv", "\u{02262}", false),
+    ("nesear", "\u{02928}", false),
+    ("nesim", "\u{02242}\u{00338}", false),
+    ("nexist", "\u{02204}", false),
+    ("nexists", "\u{02204}", false),
+    ("nfr", "\u{1D52B}", false),
+    ("ngE", "\u{02267}\u{00338}", false),
+    ("nge", "\u{02271}", false),
+    ("ngeq", "\u{02271}", false),
+    ("ngeqq", "\u{02267}\u{00338}", false),
+    ("ngeqslant", "\u{02A7E}\u{00338}", false),
+    ("nges", "\u{02A7E}\u{00338}", false),
+    ("ngsim", "\u{02275}", false),
+    ("ngt", "\u{0226F}", false),
+    ("ngtr", "\u{0226F}", false),
+    ("nhArr", "\u{021CE}", false),
+    ("nharr", "\u{021AE}", false),
+    ("nhpar", "\u{02AF2}", false),
+    ("ni", "\u{0220B}", false),
+    ("nis", "\u{022FC}", false),
+    ("nisd", "\u{022FA}", false),
+    ("niv", "\u{0220B}", false),
+    ("njcy", "\u{0045A}", false),
+    ("nlArr", "\u{021CD}", false),
+    ("nlE", "\u{02266}\u{00338}", false),
+    ("nlarr", "\u{0219A}", false),
+    ("nldr", "\u{02025}", false),
+    ("nle", "\u{02270}", false),
+    ("nleftarrow", "\u{0219A}", false),
+    ("nleftrightarrow", "\u{021AE}", false),
+    ("nleq", "\u{02270}", false),
+    ("nleqq", "\u{02266}\u{00338}", false),
+    ("nleqslant", "\u{02A7D}\u{00338}", false),
+    ("nles", "\u{02A7D}\u{00338}", false),
+    ("nless", "\u{0226E}", false),
+    ("nlsim", "\u{02274}", false),
+    ("nlt", "\u{0226E}", false),
+    ("nltri", "\u{022EA}", false),
+    ("nltrie", "\u{022EC}", false),
+    ("nmid", "\u{02224}", false),
+    ("nopf", "\u{1D55F}", false),
+    ("not", "\u{000AC}", true),
+    ("notin", "\u{02209}", false),
+    ("notinE", "\u{022F9}\u{00338}", false),
+    ("notindot", "\u{022F5}\u{00338}", false),
+    ("notinva", "\u{02209}", false),
+    ("notinvb", "\u{022F7}", false),
+    ("notinvc", "\u{022F6}", false),
+    ("notni", "\u{0220C}", false),
+    ("notniva", "\u{0220C}", false),
+    ("notnivb", "\u{022FE}", false),
+    ("notnivc", "\u{022FD}", false),
+    ("npar", "\u{02226}", false),
+    ("nparallel", "\u{02226}", false),
+    ("nparsl", "\u{02AFD}\u{020E5}", false),
+    ("npart", "\u{02202}\u{00338}", false),
+    ("npolint", "\u{02A14}", false),
+    ("npr", "\u{02280}", false),
+    ("nprcue", "\u{022E0}", false),
+    ("npre", "\u{02AAF}\u{00338}", false),
+    ("nprec", "\u{02280}", false),
+    ("npreceq", "\u{02AAF}\u{00338}", false),
+    ("nrArr", "\u{021CF}", false),
+    ("nrarr", "\u{0219B}", false),
+    ("nrarrc", "\u{02933}\u{00338}", false),
+    ("nrarrw", "\u{0219D}\u{00338}", false),
+    ("nrightarrow", "\u{0219B}", false),
+    ("nrtri", "\u{022EB}", false),
+    ("nrtrie", "\u{022ED}", false),
+    ("nsc", "\u{02281}", false),
+    ("nsccue", "\u{022E1}", false),
+    ("nsce", "\u{02AB0}\u{00338}", false),
+    ("nscr", "\u{1D4C3}", false),
+    ("nshortmid", "\u{02224}", false),
+    ("nshortparallel", "\u{02226}", false),
+    ("nsim", "\u{02241}", false),
+    ("nsime", "\u{02244}", false),
+    ("nsimeq", "\u{02244}", false),
+    ("nsmid", "\u{02224}", false),
+    ("nspar", "\u{02226}", false),
+    ("nsqsube", "\u{022E2}", false),
+    ("nsqsupe", "\u{022E3}", false),
+    ("nsub", "\u{02284}", false),
+    ("nsubE", "\u{02AC5}\u{00338}", false),
+    ("nsube", "\u{02288}", false),
+    ("nsubset", "\u{02282}\u{020D2}", false),
+    ("nsubseteq", "\u{02288}", false),
+    ("nsubseteqq", "\u{02AC5}\u{00338}", false),
+    ("nsucc", "\u{02281}", false),
+    ("nsucceq", "\u{02AB0}\u{00338}", false),
+    ("nsup", "\u{02285}", false),
+    ("nsupE", "\u{02AC6}\u{00338}", false),
+    ("nsupe", "\u{02289}", false),
+    ("nsupset", "\u{02283}\u{020D2}", false),
+    ("nsupseteq", "\u{02289}", false),
+    ("nsupseteqq", "\u{02AC6}\u{00338}", false),
+    ("ntgl", "\u{02279}", false),
+    ("ntilde", "\u{000F1}", true),
+    ("ntlg", "\u{02278}", false),
+    ("ntriangleleft", "\u{022EA}", false),
+    ("ntrianglelefteq", "\u{022EC}", false),
+    ("ntriangleright", "\u{022EB}", false),
+    ("ntrianglerighteq", "\u{022ED}", false),
+    ("nu", "\u{003BD}", false),
+    ("num", "\u{00023}", false),
+    ("numero", "\u{02116}", false),
+    ("numsp", "\u{02007}", false),
+    ("nvDash", "\u{022AD}", false),
+    ("nvHarr", "\u{02904}", false),
+    ("nvap", "\u{0224D}\u{020D2}", false),
+    ("nvdash", "\u{022AC}", false),
+    ("nvge", "\u{02265}\u{020D2}", false),
+    ("nvgt", "\u{0003E}\u{020D2}", false),
+    ("nvinfin", "\u{029DE}", false),
+    ("nvlArr", "\u{02902}", false),
+    ("nvle", "\u{02264}\u{020D2}", false),
+    ("nvlt", "\u{0003C}\u{020D2}", false),
+    ("nvltrie", "\u{022B4}\u{020D2}", false),
+    ("nvrArr", "\u{02903}", false),
+    ("nvrtrie", "\u{022B5}\u{020D2}", false),
+    ("nvsim", "\u{0223C}\u{020D2}", false),
+    ("nwArr", "\u{021D6}", false),
+    ("nwarhk", "\u{02923}", false),
+    ("nwarr", "\u{02196}", false),
+    ("nwarrow", "\u{02196}", false),
+    ("nwnear", "\u{02927}", false),
+    ("oS", "\u{024C8}", false),
+    ("oacute", "\u{000F3}", true),
+    ("oast", "\u{0229B}", false),
+    ("ocir", "\u{0229A}", false),
+    ("ocirc", "\u{000F4}", true),
+    ("ocy", "\u{0043E}", false),
+    ("odash", "\u{0229D}", false),
+    ("odblac", "\u{00151}", false),
+    ("odiv", "\u{02A38}", false),
+    ("odot", "\u{02299}", false),
+    ("odsold", "\u{029BC}", false),
+    ("oelig", "\u{00153}", false),
+    ("ofcir", "\u{029BF}", false),
+    ("ofr", "\u{1D52C}", false),
+    ("ogon", "\u{002DB}", false),
+    ("ograve", "\u{000F2}", true),
+    ("ogt", "\u{029C1}", false),
+    ("ohbar", "\u{029B5}", false),
+    ("ohm", "\u{003A9}", false),
+    ("oint", "\u{0222E}", false),
+    ("olarr", "\u{021BA}", false),
+    ("olcir", "\u{029BE}", false),
+    ("olcross", "\u{029BB}", false),
+    ("oline", "\u{0203E}", false),
+    ("olt", "\u{029C0}", false),
+    ("omacr", "\u{0014D}", false),
+    ("omega", "\u{003C9}", false),
+    ("omicron", "\u{003BF}", false),
+    ("omid", "\u{029B6}", false),
+    ("ominus", "\u{02296}", false),
+    ("oopf", "\u{1D560}", false),
+    ("opar", "\u{029B7}", false),
+    ("operp", "\u{029B9}", false),
+    ("oplus", "\u{02295}", false),
+    ("or", "\u{02228}", false),
+    ("orarr", "\u{021BB}", false),
+    ("ord", "\u{02A5D}", false),
+    ("order", "\u{02134}", false),
+    ("orderof", "\u{02134}", false),
+    ("ordf", "\u{000AA}", true),
+    ("ordm", "\u{000BA}", true),
+    ("origof", "\u{022B6}", false),
+    ("oror", "\u{02A56}", false),
+    ("orslope", "\u{02A57}", false),
+    ("orv", "\u{02A5B}", false),
+    ("oscr", "\u{02134}", false),
+    ("oslash", "\u{000F8}", true),
+    ("osol", "\u{02298}", false),
+    ("otilde", "\u{000F5}", true),
+    ("otimes", "\u{02297}", false),
+    ("otimesas", "\u{02A36}", false),
+    ("ouml", "\u{000F6}", true),
+    ("ovbar", "\u{0233D}", false),
+    ("par", "\u{02225}", false),
+    ("para", "\u{000B6}", true),
+    ("parallel", "\u{02225}", false),
+    ("parsim", "\u{02AF3}", false),
+    ("parsl", "\u{02AFD}", false),
+    ("part", "\u{02202}", false),
+    ("pcy", "\u{0043F}", false),
+    ("percnt", "\u{00025}", false),
+    ("period", "\u{0002E}", false),
+    ("permil", "\u{02030}", false),
+    ("perp", "\u{022A5}", false),
+    ("pertenk", "\u{02031}", false),
+    ("pfr", "\u{1D52D}", false),
+    ("phi", "\u{003C6}", false),
+    ("phiv", "\u{003D5}", false),
+    ("phmmat", "\u{02133}", false),
+    ("phone", "\u{0260E}", false),
+    ("pi", "\u{003C0}", false),
+    ("pitchfork", "\u{022D4}", false),
+    ("piv", "\u{003D6}", false),
+    ("planck", "\u{0210F}", false),
+    ("planckh", "\u{0210E}", false),
+    ("plankv", "\u{0210F}", false),
+    ("plus", "\u{0002B}", false),
+    ("plusacir", "\u{02A23}", false),
+    ("plusb", "\u{0229E}", false),
+    ("pluscir", "\u{02A22}", false),
+    ("plusdo", "\u{02214}", false),
+    ("plusdu", "\u{02A25}", false),
+    ("pluse", "\u{02A72}", false),
+    ("plusmn", "\u{000B1}", true),
+    ("plussim", "\u{02A26}", false),
+    ("plustwo", "\u{02A27}", false),
+    ("pm", "\u{000B1}", false),
+    ("pointint", "\u{02A15}", false),
+    ("popf", "\u{1D561}", false),
+    ("pound", "\u{000A3}", true),
+    ("pr", "\u{0227A}", false),
+    ("prE", "\u{02AB3}", false),
+    ("prap", "\u{02AB7}", false),
+    ("prcue", "\u{0227C}", false),
+    ("pre", "\u{02AAF}", false),
+    ("prec", "\u{0227A}", false),
+    ("precapprox", "\u{02AB7}", false),
+    ("preccurlyeq", "\u{0227C}", false),
+    ("preceq", "\u{02AAF}", false),
+    ("precnapprox", "\u{02AB9}", false),
+    ("precneqq", "\u{02AB5}", false),
+    ("precnsim", "\u{022E8}", false),
+    ("precsim", "\u{0227E}", false),
+    ("prime", "\u{02032}", false),
+    ("primes", "\u{02119}", false),
+    ("prnE", "\u{02AB5}", false),
+    ("prnap", "\u{02AB9}", false),
+    ("prnsim", "\u{022E8}", false),
+    ("prod", "\u{0220F}", false),
+    ("profalar", "\u{0232E}", false),
+    ("profline", "\u{02312}", false),
+    ("profsurf", "\u{02313}", false),
+    ("prop", "\u{0221D}", false),
+    ("propto", "\u{0221D}", false),
+    ("prsim", "\u{0227E}", false),
+    ("prurel", "\u{022B0}", false),
+    ("pscr", "\u{1D4C5}", false),
+    ("psi", "\u{003C8}", false),
+    ("puncsp", "\u{02008}", false),
+    ("qfr", "\u{1D52E}", false),
+    ("qint", "\u{02A0C}", false),
+    ("qopf", "\u{1D562}", false),
+    ("qprime", "\u{02057}", false),
+    ("qscr", "\u{1D4C6}", false),
+    ("quaternions", "\u{0210D}", false),
+    ("quatint", "\u{02A16}", false),
+    ("quest", "\u{0003F}", false),
+    ("questeq", "\u{0225F}", false),
+    ("quot", "\u{00022}", true),
+    ("rAarr", "\u{021DB}", false),
+    ("rArr", "\u{021D2}", false),
+    ("rAtail", "\u{0291C}", false),
+    ("rBarr", "\u{0290F}", false),
+    ("rHar", "\u{02964}", false),
+    ("race", "\u{0223D}\u{00331}", false),
+    ("racute", "\u{00155}", false),
+    ("radic", "\u{0221A}", false),
+    ("raemptyv", "\u{029B3}", false),
+    ("rang", "\u{027E9}", false),
+    ("rangd", "\u{02992}", false),
+    ("range", "\u{029A5}", false),
+    ("rangle", "\u{027E9}", false),
+    ("raquo", "\u{000BB}", true),
+    ("rarr", "\u{02192}", false),
+    ("rarrap", "\u{02975}", false),
+    ("rarrb", "\u{021E5}", false),
+    ("rarrbfs", "\u{02920}", false),
+    ("rarrc", "\u{02933}", false),
+    ("rarrfs", "\u{0291E}", false),
+    ("rarrhk", "\u{021AA}", false),
+    ("rarrlp", "\u{021AC}", false),
+    ("rarrpl", "\u{02945}", false),
+    ("rarrsim", "\u{02974}", false),
+    ("rarrtl", "\u{021A3}", false),
+    ("rarrw", "\u{0219D}", false),
+    ("ratail", "\u{0291A}", false),
+    ("ratio", "\u{02236}", false),
+    ("rationals", "\u{0211A}", false),
+    ("rbarr", "\u{0290D}", false),
+    ("rbbrk", "\u{02773}", false),
+    ("rbrace", "\u{0007D}", false),
+    ("rbrack", "\u{0005D}", false),
+    ("rbrke", "\u{0298C}", false),
+    ("rbrksld", "\u{0298E}", false),
+    ("rbrkslu", "\u{02990}", false),
+    ("rcaron", "\u{00159}", false),
+    ("rcedil", "\u{00157}", false),
+    ("rceil", "\u{02309}", false),
+    ("rcub", "\u{0007D}", false),
+    ("rcy", "\u{00440}", false),
+    ("rdca", "\u{02937}", false),
+    ("rdldhar", "\u{02969}", false),
+    ("rdquo", "\u{0201D}", false),
+    ("rdquor", "\u{0201D}", false),
+    ("rdsh", "\u{021B3}", false),
+    ("real", "\u{0211C}", false),
+    ("realine", "\u{0211B}", false),
+    ("realpart", "\u{0211C}", false),
+    ("reals", "\u{0211D}", false),
+    ("rect", "\u{025AD}", false),
+    ("reg", "\u{000AE}", true),
+    ("rfisht", "\u{0297D}", false),
+    ("rfloor", "\u{0230B}", false),
+    ("rfr", "\u{1D52F}", false),
+    ("rhard", "\u{021C1}", false),
+    ("rharu", "\u{021C0}", false),
+    ("rharul", "\u{0296C}", false),
+    ("rho", "\u{003C1}", false),
+    ("rhov", "\u{003F1}", false),
+    ("rightarrow", "\u{02192}", false),
+    ("rightarrowtail", "\u{021A3}", false),
+    ("rightharpoondown", "\u{021C1}", false),
+    ("rightharpoonup", "\u{021C0}", false),
+    ("rightleftarrows", "\u{021C4}", false),
+    ("rightleftharpoons", "\u{021CC}", false),
+    ("rightrightarrows", "\u{021C9}", false),
+    ("rightsquigarrow", "\u{0219D}", false),
+    ("rightthreetimes", "\u{022CC}", false),
+    ("ring", "\u{002DA}", false),
+    ("risingdotseq", "\u{02253}", false),
+    ("rlarr", "\u{021C4}", false),
+    ("rlhar", "\u{021CC}", false),
+    ("rlm", "\u{0200F}", false),
+    ("rmoust", "\u{023B1}", false),
+    ("rmoustache", "\u{023B1}", false),
+    ("rnmid", "\u{02AEE}", false),
+    ("roang", "\u{027ED}", false),
+    ("roarr", "\u{021FE}", false),
+    ("robrk", "\u{027E7}", false),
+    ("ropar", "\u{02986}", false),
+    ("ropf", "\u{1D563}", false),
+    ("roplus", "\u{02A2E}", false),
+    ("rotimes", "\u{02A35}", false),
+    ("rpar", "\u{00029}", false),
+    ("rpargt", "\u{02994}", false),
+    ("rppolint", "\u{02A12}", false),
+    ("rrarr", "\u{021C9}", false),
+    ("rsaquo", "\u{0203A}", false),
+    ("rscr", "\u{1D4C7}", false),
+    ("rsh", "\u{021B1}", false),
+    ("rsqb", "\u{0005D}", false),
+    ("rsquo", "\u{02019}", false),
+    ("rsquor", "\u{02019}", false),
+    ("rthree", "\u{022CC}", false),
+    ("rtimes", "\u{022CA}", false),
+    ("rtri", "\u{025B9}", false),
+    ("rtrie", "\u{022B5}", false),
+    ("rtrif", "\u{025B8}", false),
+    ("rtriltri", "\u{029CE}", false),
+    ("ruluhar", "\u{02968}", false),
+    ("rx", "\u{0211E}", false),
+    ("sacute", "\u{0015B}", false),
+    ("sbquo", "\u{0201A}", false),
+    ("sc", "\u{0227B}", false),
+    ("scE", "\u{02AB4}", false),
+    ("scap", "\u{02AB8}", false),
+    ("scaron", "\u{00161}", false),
+    ("sccue", "\u{0227D}", false),
+    ("sce", "\u{02AB0}", false),
+    ("scedil", "\u{0015F}", false),
+    ("scirc", "\u{0015D}", false),
+    ("scnE", "\u{02AB6}", false),
+    ("scnap", "\u{02ABA}", false),
+    ("scnsim", "\u{022E9}", false),
+    ("scpolint", "\u{02A13}", false),
+    ("scsim", "\u{0227F}", false),
+    ("scy", "\u{00441}", false),
+    ("sdot", "\u{022C5}", false),
+    ("sdotb", "\u{022A1}", false),
+    ("sdote", "\u{02A66}", false),
+    ("seArr", "\u{021D8}", false),
+    ("searhk", "\u{02925}", false),
+    ("searr", "\u{02198}", false),
+    ("searrow", "\u{02198}", false),
+    ("sect", "\u{000A7}", true),
+    ("semi", "\u{0003B}", false),
+    ("seswar", "\u{02929}", false),
+    ("setminus", "\u{02216}", false),
+    ("setmn", "\u{02216}", false),
+    ("sext", "\u{02736}", false),
+    ("sfr", "\u{1D530}", false),
+    ("sfrown", "\u{02322}", false),
+    ("sharp", "\u{0266F}", false),
+    ("shchcy", "\u{00449}", false),
+    ("shcy", "\u{00448}", false),
+    ("shortmid", "\u{02223}", false),
+    ("shortparallel", "\u{02225}", false),
+    ("shy", "\u{000AD}", true),
+    ("sigma", "\u{003C3}", false),
+    ("sigmaf", "\u{003C2}", false),
+    ("sigmav", "\u{003C2}", false),
+    ("sim", "\u{0223C}", false),
+    ("simdot", "\u{02A6A}", false),
+    ("sime", "\u{02243}", false),
+    ("simeq", "\u{02243}", false),
+    ("simg", "\u{02A9E}", false),
+    ("simgE", "\u{02AA0}", false),
+    ("siml", "\u{02A9D}", false),
+    ("simlE", "\u{02A9F}", false),
+    ("simne", "\u{02246}", false),
+    ("simplus", "\u{02A24}", false),
+    ("simrarr", "\u{02972}", false),
+    ("slarr", "\u{02190}", false),
+    ("smallsetminus", "\u{02216}", false),
+    ("smashp", "\u{02A33}", false),
+    ("smeparsl", "\u{029E4}", false),
+    ("smid", "\u{02223}", false),
+    ("smile", "\u{02323}", false),
+    ("smt", "\u{02AAA}", false),
+    ("smte", "\u{02AAC}", false),
+    ("smtes", "\u{02AAC}\u{0FE00}", false),
+    ("softcy", "\u{0044C}", false),
+    ("sol", "\u{0002F}", false),
+    ("solb", "\u{029C4}", false),
+    ("solbar", "\u{0233F}", false),
+    ("sopf", "\u{1D564}", false),
+    ("spades", "\u{02660}", false),
+    ("spadesuit", "\u{02660}", false),
+    ("spar", "\u{02225}", false),
+    ("sqcap", "\u{02293}", false),
+    ("sqcaps", "\u{02293}\u{0FE00}", false),
+    ("sqcup", "\u{02294}", false),
+    ("sqcups", "\u{02294}\u{0FE00}", false),
+    ("sqsub", "\u{0228F}", false),
+    ("sqsube", "\u{02291}", false),
+    ("sqsubset", "\u{0228F}", false),
+    ("sqsubseteq", "\u{02291}", false),
+    ("sqsup", "\u{02290}", false),
+    ("sqsupe", "\u{02292}", false),
+    ("sqsupset", "\u{02290}", false),
+    ("sqsupseteq", "\u{02292}", false),
+    ("squ", "\u{025A1}", false),
+    ("square", "\u{025A1}", false),
+    ("squarf", "\u{025AA}", false),
+    ("squf", "\u{025AA}", false),
+    ("srarr", "\u{02192}", false),
+    ("sscr", "\u{1D4C8}", false),
+    ("ssetmn", "\u{02216}", false),
+    ("ssmile", "\u{02323}", false),
+    ("sstarf", "\u{022C6}", false),
+    ("star", "\u{02606}", false),
+    ("starf", "\u{02605}", false),
+    ("straightepsilon", "\u{003F5}", false),
+    ("straightphi", "\u{003D5}", false),
+    ("strns", "\u{000AF}", false),
+    ("sub", "\u{02282}", false),
+    ("subE", "\u{02AC5}", false),
+    ("subdot", "\u{02ABD}", false),
+    ("sube", "\u{02286}", false),
+    ("subedot", "\u{02AC3}", false),
+    ("submult", "\u{02AC1}", false),
+    ("subnE", "\u{02ACB}", false),
+    ("subne", "\u{0228A}", false),
+    ("subplus", "\u{02ABF}", false),
+    ("subrarr", "\u{02979}", false),
+    ("subset", "\u{02282}", false),
+    ("subseteq", "\u{02286}", false),
+    ("subseteqq", "\u{02AC5}", false),
+    ("subsetneq", "\u{0228A}", false),
+    ("subsetneqq", "\u{02ACB}", false),
+    ("subsim", "\u{02AC7}", false),
+    ("subsub", "\u{02AD5}", false),
+    ("subsup", "\u{02AD3}", false),
+    ("succ", "\u{0227B}", false),
+    ("succapprox", "\u{02AB8}", false),
+    ("succcurlyeq", "\u{0227D}", false),
+    ("succeq", "\u{02AB0}", false),
+    ("succnapprox", "\u{02ABA}", false),
+    ("succneqq", "\u{02AB6}", false),
+    ("succnsim", "\u{022E9}", false),
+    ("succsim", "\u{0227F}", false),
+    ("sum", "\u{02211}", false),
+    ("sung", "\u{0266A}", false),
+    ("sup", "\u{02283}", false),
+    ("sup1", "\u{000B9}", true),
+    ("sup2", "\u{000B2}", true),
+    ("sup3", "\u{000B3}", true),
+    ("supE", "\u{02AC6}", false),
+    ("supdot", "\u{02ABE}", false),
+    ("supdsub", "\u{02AD8}", false),
+    ("supe", "\u{02287}", false),
+    ("supedot", "\u{02AC4}", false),
+    ("suphsol", "\u{027C9}", false),
+    ("suphsub", "\u{02AD7}", false),
+    ("suplarr", "\u{0297B}", false),
+    ("supmult", "\u{02AC2}", false),
+    ("supnE", "\u{02ACC}", false),
+    ("supne", "\u{0228B}", false),
+    ("supplus", "\u{02AC0}", false),
+    ("supset", "\u{02283}", false),
+    ("supseteq", "\u{02287}", false),
+    ("supseteqq", "\u{02AC6}", false),
+    ("supsetneq", "\u{0228B}", false),
+    ("supsetneqq", "\u{02ACC}", false),
+    ("supsim", "\u{02AC8}", false),
+    ("supsub", "\u{02AD4}", false),
+    ("supsup", "\u{02AD6}", false),
+    ("swArr", "\u{021D9}", false),
+    ("swarhk", "\u{02926}", false),
+    ("swarr", "\u{02199}", false),
+    ("swarrow", "\u{02199}", false),
+    ("swnwar", "\u{0292A}", false),
+    ("szlig", "\u{000DF}", true),
+    ("target", "\u{02316}", false),
+    ("tau", "\u{003C4}", false),
+    ("tbrk", "\u{023B4}", false),
+    ("tcaron", "\u{00165}", false),
+    ("tcedil", "\u{00163}", false),
+    ("tcy", "\u{00442}", false),
+    ("tdot", "\u{020DB}", false),
+    ("telrec", "\u{02315}", false),
+    ("tfr", "\u{1D531}", false),
+    ("there4", "\u{02234}", false),
+    ("therefore", "\u{02234}", false),
+    ("theta", "\u{003B8}", false),
+    ("thetasym", "\u{003D1}", false),
+    ("thetav", "\u{003D1}", false),
+    ("thickapprox", "\u{02248}", false),
+    ("thicksim", "\u{0223C}", false),
+    ("thinsp", "\u{02009}", false),
+    ("thkap", "\u{02248}", false),
+    ("thksim", "\u{0223C}", false),
+    ("thorn", "\u{000FE}", true),
+    ("tilde", "\u{002DC}", false),
+    ("times", "\u{000D7}", true),
+    ("timesb", "\u{022A0}", false),
+    ("timesbar", "\u{02A31}", false),
+    ("timesd", "\u{02A30}", false),
+    ("tint", "\u{0222D}", false),
+    ("toea", "\u{02928}", false),
+    ("top", "\u{022A4}", false),
+    ("topbot", "\u{02336}", false),
+    ("topcir", "\u{02AF1}", false),
+    ("topf", "\u{1D565}", false),
+    ("topfork", "\u{02ADA}", false),
+    ("tosa", "\u{02929}", false),
+    ("tprime", "\u{02034}", false),
+    ("trade", "\u{02122}", false),
+    ("triangle", "\u{025B5}", false),
+    ("triangledown", "\u{025BF}", false),
+    ("triangleleft", "\u{025C3}", false),
+    ("trianglelefteq", "\u{022B4}", false),
+    ("triangleq", "\u{0225C}", false),
+    ("triangleright", "\u{025B9}", false),
+    ("trianglerighteq", "\u{022B5}", false),
+    ("tridot", "\u{025EC}", false),
+    ("trie", "\u{0225C}", false),
+    ("triminus", "\u{02A3A}", false),
+    ("triplus", "\u{02A39}", false),
+    ("trisb", "\u{029CD}", false),
+    ("tritime", "\u{02A3B}", false),
+    ("trpezium", "\u{023E2}", false),
+    ("tscr", "\u{1D4C9}", false),
+    ("tscy", "\u{00446}", false),
+    ("tshcy", "\u{0045B}", false),
+    ("tstrok", "\u{00167}", false),
+    ("twixt", "\u{0226C}", false),
+    ("twoheadleftarrow", "\u{0219E}", false),
+    ("twoheadrightarrow", "\u{021A0}", false),
+    ("uArr", "\u{021D1}", false),
+    ("uHar", "\u{02963}", false),
+    ("uacute", "\u{000FA}", true),
+    ("uarr", "\u{02191}", false),
+    ("ubrcy", "\u{0045E}", false),
+    ("ubreve", "\u{0016D}", false),
+    ("ucirc", "\u{000FB}", true),
+    ("ucy", "\u{00443}", false),
+    ("udarr", "\u{021C5}", false),
+    ("udblac", "\u{00171}", false),
+    ("udhar", "\u{0296E}", false),
+    ("ufisht", "\u{0297E}", false),
+    ("ufr", "\u{1D532}", false),
+    ("ugrave", "\u{000F9}", true),
+    ("uharl", "\u{021BF}", false),
+    ("uharr", "\u{021BE}", false),
+    ("uhblk", "\u{02580}", false),
+    ("ulcorn", "\u{0231C}", false),
+    ("ulcorner", "\u{0231C}", false),
+    ("ulcrop", "\u{0230F}", false),
+    ("ultri", "\u{025F8}", false),
+    ("umacr", "\u{0016B}", false),
+    ("uml", "\u{000A8}", true),
+    ("uogon", "\u{00173}", false),
+    ("uopf", "\u{1D566}", false),
+    ("uparrow", "\u{02191}", false),
+    ("updownarrow", "\u{02195}", false),
+    ("upharpoonleft", "\u{021BF}", false),
+    ("upharpoonright", "\u{021BE}", false),
+    ("uplus", "\u{0228E}", false),
+    ("upsi", "\u{003C5}", false),
+    ("upsih", "\u{003D2}", false),
+    ("upsilon", "\u{003C5}", false),
+    ("upuparrows", "\u{021C8}", false),
+    ("urcorn", "\u{0231D}", false),
+    ("urcorner", "\u{0231D}", false),
+    ("urcrop", "\u{0230E}", false),
+    ("uring", "\u{0016F}", false),
+    ("urtri", "\u{025F9}", false),
+    ("uscr", "\u{1D4CA}", false),
+    ("utdot", "\u{022F0}", false),
+    ("utilde", "\u{00169}", false),
+    ("utri", "\u{025B5}", false),
+    ("utrif", "\u{025B4}", false),
+    ("uuarr", "\u{021C8}", false),
+    ("uuml", "\u{000FC}", true),
+    ("uwangle", "\u{029A7}", false),
+    ("vArr", "\u{021D5}", false),
+    ("vBar", "\u{02AE8}", false),
+    ("vBarv", "\u{02AE9}", false),
+    ("vDash", "\u{022A8}", false),
+    ("vangrt", "\u{0299C}", false),
+    ("varepsilon", "\u{003F5}", false),
+    ("varkappa", "\u{003F0}", false),
+    ("varnothing", "\u{02205}", false),
+    ("varphi", "\u{003D5}", false),
+    ("varpi", "\u{003D6}", false),
+    ("varpropto", "\u{0221D}", false),
+    ("varr", "\u{02195}", false),
+    ("varrho", "\u{003F1}", false),
+    ("varsigma", "\u{003C2}", false),
+    ("varsubsetneq", "\u{0228A}\u{0FE00}", false),
+    ("varsubsetneqq", "\u{02ACB}\u{0FE00}", false),
+    ("varsupsetneq", "\u{0228B}\u{0FE00}", false),
+    ("varsupsetneqq", "\u{02ACC}\u{0FE00}", false),
+    ("vartheta", "\u{003D1}", false),
+    ("vartriangleleft", "\u{022B2}", false),
+    ("vartriangleright", "\u{022B3}", false),
+    ("vcy", "\u{00432}", false),
+    ("vdash", "\u{022A2}", false),
+    ("vee", "\u{02228}", false),
+    ("veebar", "\u{022BB}", false),
+    ("veeeq", "\u{0225A}", false),
+    ("vellip", "\u{022EE}", false),
+    ("verbar", "\u{0007C}", false),
+    ("vert", "\u{0007C}", false),
+    ("vfr", "\u{1D533}", false),
+    ("vltri", "\u{022B2}", false),
+    ("vnsub", "\u{02282}\u{020D2}", false),
+    ("vnsup", "\u{02283}\u{020D2}", false),
+    ("vopf", "\u{1D567}", false),
+    ("vprop", "\u{0221D}", false),
+    ("vrtri", "\u{022B3}", false),
+    ("vscr", "\u{1D4CB}", false),
+    ("vsubnE", "\u{02ACB}\u{0FE00}", false),
+    ("vsubne", "\u{0228A}\u{0FE00}", false),
+    ("vsupnE", "\u{02ACC}\u{0FE00}", false),
+    ("vsupne", "\u{0228B}\u{0FE00}", false),
+    ("vzigzag", "\u{0299A}", false),
+    ("wcirc", "\u{00175}", false),
+    ("wedbar", "\u{02A5F}", false),
+    ("wedge", "\u{02227}", false),
+    ("wedgeq", "\u{02259}", false),
+    ("weierp", "\u{02118}", false),
+    ("wfr", "\u{1D534}", false),
+    ("wopf", "\u{1D568}", false),
+    ("wp", "\u{02118}", false),
+    ("wr", "\u{02240}", false),
+    ("wreath", "\u{02240}", false),
+    ("wscr", "\u{1D4CC}", false),
+    ("xcap", "\u{022C2}", false),
+    ("xcirc", "\u{025EF}", false),
+    ("xcup", "\u{022C3}", false),
+    ("xdtri", "\u{025BD}", false),
+    ("xfr", "\u{1D535}", false),
+    ("xhArr", "\u{027FA}", false),
+    ("xharr", "\u{027F7}", false),
+    ("xi", "\u{003BE}", false),
+    ("xlArr", "\u{027F8}", false),
+    ("xlarr", "\u{027F5}", false),
+    ("xmap", "\u{027FC}", false),
+    ("xnis", "\u{022FB}", false),
+    ("xodot", "\u{02A00}", false),
+    ("xopf", "\u{1D569}", false),
+    ("xoplus", "\u{02A01}", false),
+    ("xotime", "\u{02A02}", false),
+    ("xrArr", "\u{027F9}", false),
+    ("xrarr", "\u{027F6}", false),
+    ("xscr", "\u{1D4CD}", false),
+    ("xsqcup", "\u{02A06}", false),
+    ("xuplus", "\u{02A04}", false),
+    ("xutri", "\u{025B3}", false),
+    ("xvee", "\u{022C1}", false),
+    ("xwedge", "\u{022C0}", false),
+    ("yacute", "\u{000FD}", true),
+    ("yacy", "\u{0044F}", false),
+    ("ycirc", "\u{00177}", false),
+    ("ycy", "\u{0044B}", false),
+    ("yen", "\u{000A5}", true),
+    ("yfr", "\u{1D536}", false),
+    ("yicy", "\u{00457}", false),
+    ("yopf", "\u{1D56A}", false),
+    ("yscr", "\u{1D4CE}", false),
+    ("yucy", "\u{0044E}", false),
+    ("yuml", "\u{000FF}", true),
+    ("zacute", "\u{0017A}", false),
+    ("zcaron", "\u{0017E}", false),
+    ("zcy", "\u{00437}", false),
+    ("zdot", "\u{0017C}", false),
+    ("zeetrf", "\u{02128}", false),
+    ("zeta", "\u{003B6}", false),
+    ("zfr", "\u{1D537}", false),
+    ("zhcy", "\u{00436}", false),
+    ("zigrarr", "\u{021DD}", false),
+    ("zopf", "\u{1D56B}", false),
+    ("zscr", "\u{1D4CF}", false),
+    ("zwj", "\u{0200D}", false),
+    ("zwnj", "\u{0200C}", false),
+];
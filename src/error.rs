@@ -0,0 +1,231 @@
+//! Errors found while parsing HTML
+
+use std::fmt;
+
+use crate::dom::Span;
+
+/// A recoverable error found while parsing
+///
+/// The parser never fails on bad input. Instead it fixes the markup and reports what it fixed.
+/// Every error has a stable code returned by [`ParseError::code`] and the span of the input
+/// that caused it.
+///
+/// [`ParseError::code`]: enum.ParseError.html#method.code
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// An element was not closed by a matching end tag
+    UnclosedElement { name: String, span: Span },
+    /// An end tag without a matching open element
+    UnexpectedEndTag { name: String, span: Span },
+    /// A start tag that isn't allowed at its position
+    ///
+    /// The tag is ignored. Attributes of a misplaced `html` or `body` start tag are added to
+    /// the existing element.
+    UnexpectedStartTag { name: String, span: Span },
+    /// Text or an element that isn't allowed in a table
+    ///
+    /// The content is moved before the table.
+    MisplacedTableContent { span: Span },
+    /// A formatting element like `<b>` that overlaps other elements
+    ///
+    /// The elements are split and reopened the same way browsers do it.
+    MisnestedElement { name: String, span: Span },
+    /// A character reference that doesn't refer to a known entity or valid code point
+    ///
+    /// The reference is kept as text.
+    InvalidCharacterReference { reference: String, span: Span },
+    /// A numeric character reference to U+0000
+    ///
+    /// The reference is replaced with U+FFFD.
+    NullCharacterReference { reference: String, span: Span },
+    /// A numeric character reference to a surrogate code point
+    ///
+    /// The reference is replaced with U+FFFD.
+    SurrogateCharacterReference { reference: String, span: Span },
+    /// A numeric character reference to a value above U+10FFFF
+    ///
+    /// The reference is replaced with U+FFFD.
+    CharacterReferenceOutsideUnicodeRange { reference: String, span: Span },
+    /// A numeric character reference to a control character
+    ///
+    /// References to `0x80` up to `0x9F` are mapped like windows-1252, i.e. `&#150;` is an en dash.
+    ControlCharacterReference { reference: String, span: Span },
+    /// Markup starting with `<!` that is neither a comment, a CDATA section nor a doctype
+    ///
+    /// The markup is kept as text.
+    InvalidMarkupDeclaration { span: Span },
+    /// Markup like `<!foo>`, `<?php ?>` or `</1>` read as a comment
+    ///
+    /// This is only reported if `ParseOptions::bogus_comments` is set.
+    BogusComment { span: Span },
+    /// An end tag without a name like `</>`
+    ///
+    /// The tag is ignored.
+    MissingEndTagName { span: Span },
+    /// A `<` that isn't followed by a letter like in `a < b`
+    ///
+    /// The `<` is kept as text.
+    InvalidFirstCharacterOfTagName { span: Span },
+    /// An empty comment closed by `<!-->` or `<!--->`
+    AbruptClosingOfEmptyComment { span: Span },
+    /// A comment closed by `--!>`
+    IncorrectlyClosedComment { span: Span },
+    /// An attribute with the same name as a previous attribute of the tag
+    ///
+    /// Only the first attribute is kept.
+    DuplicateAttribute { name: String, span: Span },
+    /// An attribute name containing `"`, `'` or `<` or starting with `=`
+    ///
+    /// The chars are kept in the name.
+    UnexpectedCharacterInAttributeName { span: Span },
+    /// An unquoted attribute value containing `"`, `'`, `<`, `=` or a backtick
+    ///
+    /// The chars are kept in the value.
+    UnexpectedCharacterInUnquotedAttributeValue { span: Span },
+    /// End of input after a `<`
+    EofBeforeTagName { span: Span },
+    /// End of input inside of a start or end tag
+    EofInTag { name: String, span: Span },
+    /// End of input inside of a comment
+    EofInComment { span: Span },
+    /// End of input inside of a CDATA section
+    EofInCData { span: Span },
+    /// End of input inside of a doctype definition
+    EofInDoctype { span: Span },
+    /// End of input inside of a processing instruction
+    EofInProcessingInstruction { span: Span },
+    /// An element nested deeper than the depth limit
+    ///
+    /// The element is added after the current element.
+    NestingTooDeep { name: String, span: Span },
+    /// A start tag with more attributes than allowed
+    ///
+    /// The attributes after the limit are dropped.
+    TooManyAttributes { name: String, span: Span },
+    /// Content longer than the token length limit
+    ///
+    /// The content is cut off.
+    TokenTooLong { span: Span },
+    /// The node limit was reached
+    ///
+    /// Parsing stops at this point.
+    TooManyNodes { span: Span },
+}
+
+impl ParseError {
+    /// Returns the stable code of the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::UnclosedElement { .. } => "unclosed-element",
+            ParseError::UnexpectedEndTag { .. } => "unexpected-end-tag",
+            ParseError::UnexpectedStartTag { .. } => "unexpected-start-tag",
+            ParseError::MisplacedTableContent { .. } => "misplaced-table-content",
+            ParseError::MisnestedElement { .. } => "misnested-element",
+            ParseError::InvalidCharacterReference { .. } => "invalid-character-reference",
+            ParseError::NullCharacterReference { .. } => "null-character-reference",
+            ParseError::SurrogateCharacterReference { .. } => "surrogate-character-reference",
+            ParseError::CharacterReferenceOutsideUnicodeRange { .. } => "character-reference-outside-unicode-range",
+            ParseError::ControlCharacterReference { .. } => "control-character-reference",
+            ParseError::InvalidMarkupDeclaration { .. } => "invalid-markup-declaration",
+            ParseError::BogusComment { .. } => "bogus-comment",
+            ParseError::MissingEndTagName { .. } => "missing-end-tag-name",
+            ParseError::InvalidFirstCharacterOfTagName { .. } => "invalid-first-character-of-tag-name",
+            ParseError::AbruptClosingOfEmptyComment { .. } => "abrupt-closing-of-empty-comment",
+            ParseError::IncorrectlyClosedComment { .. } => "incorrectly-closed-comment",
+            ParseError::DuplicateAttribute { .. } => "duplicate-attribute",
+            ParseError::UnexpectedCharacterInAttributeName { .. } => "unexpected-character-in-attribute-name",
+            ParseError::UnexpectedCharacterInUnquotedAttributeValue { .. } => "unexpected-character-in-unquoted-attribute-value",
+            ParseError::EofBeforeTagName { .. } => "eof-before-tag-name",
+            ParseError::EofInTag { .. } => "eof-in-tag",
+            ParseError::EofInComment { .. } => "eof-in-comment",
+            ParseError::EofInCData { .. } => "eof-in-cdata",
+            ParseError::EofInDoctype { .. } => "eof-in-doctype",
+            ParseError::EofInProcessingInstruction { .. } => "eof-in-processing-instruction",
+            ParseError::NestingTooDeep { .. } => "nesting-too-deep",
+            ParseError::TooManyAttributes { .. } => "too-many-attributes",
+            ParseError::TokenTooLong { .. } => "token-too-long",
+            ParseError::TooManyNodes { .. } => "too-many-nodes",
+        }
+    }
+
+    /// Returns the span of the input that caused the error
+    pub fn span(&self) -> Span {
+        match *self {
+            ParseError::UnclosedElement { span, .. }
+            | ParseError::UnexpectedEndTag { span, .. }
+            | ParseError::UnexpectedStartTag { span, .. }
+            | ParseError::MisplacedTableContent { span }
+            | ParseError::MisnestedElement { span, .. }
+            | ParseError::InvalidCharacterReference { span, .. }
+            | ParseError::NullCharacterReference { span, .. }
+            | ParseError::SurrogateCharacterReference { span, .. }
+            | ParseError::CharacterReferenceOutsideUnicodeRange { span, .. }
+            | ParseError::ControlCharacterReference { span, .. }
+            | ParseError::InvalidMarkupDeclaration { span }
+            | ParseError::BogusComment { span }
+            | ParseError::MissingEndTagName { span }
+            | ParseError::InvalidFirstCharacterOfTagName { span }
+            | ParseError::AbruptClosingOfEmptyComment { span }
+            | ParseError::IncorrectlyClosedComment { span }
+            | ParseError::DuplicateAttribute { span, .. }
+            | ParseError::UnexpectedCharacterInAttributeName { span }
+            | ParseError::UnexpectedCharacterInUnquotedAttributeValue { span }
+            | ParseError::EofBeforeTagName { span }
+            | ParseError::EofInTag { span, .. }
+            | ParseError::EofInComment { span }
+            | ParseError::EofInCData { span }
+            | ParseError::EofInDoctype { span }
+            | ParseError::EofInProcessingInstruction { span }
+            | ParseError::NestingTooDeep { span, .. }
+            | ParseError::TooManyAttributes { span, .. }
+            | ParseError::TokenTooLong { span }
+            | ParseError::TooManyNodes { span } => span,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnclosedElement { name, .. } => write!(f, "Unclosed element <{}>", name)?,
+            ParseError::UnexpectedEndTag { name, .. } => write!(f, "Unexpected end tag </{}>", name)?,
+            ParseError::UnexpectedStartTag { name, .. } => write!(f, "Unexpected start tag <{}>", name)?,
+            ParseError::MisplacedTableContent { .. } => write!(f, "Content not allowed in table")?,
+            ParseError::MisnestedElement { name, .. } => write!(f, "Misnested element <{}>", name)?,
+            ParseError::InvalidCharacterReference { reference, .. } => write!(f, "Invalid character reference {}", reference)?,
+            ParseError::NullCharacterReference { reference, .. } => write!(f, "Null character reference {}", reference)?,
+            ParseError::SurrogateCharacterReference { reference, .. } => write!(f, "Surrogate character reference {}", reference)?,
+            ParseError::CharacterReferenceOutsideUnicodeRange { reference, .. } => {
+                write!(f, "Character reference outside of the unicode range {}", reference)?
+            }
+            ParseError::ControlCharacterReference { reference, .. } => write!(f, "Control character reference {}", reference)?,
+            ParseError::InvalidMarkupDeclaration { .. } => write!(f, "Invalid markup declaration")?,
+            ParseError::BogusComment { .. } => write!(f, "Invalid markup read as comment")?,
+            ParseError::MissingEndTagName { .. } => write!(f, "End tag without name")?,
+            ParseError::InvalidFirstCharacterOfTagName { .. } => write!(f, "Invalid first character of tag name")?,
+            ParseError::AbruptClosingOfEmptyComment { .. } => write!(f, "Empty comment closed abruptly")?,
+            ParseError::IncorrectlyClosedComment { .. } => write!(f, "Comment closed by --!>")?,
+            ParseError::DuplicateAttribute { name, .. } => write!(f, "Duplicate attribute {}", name)?,
+            ParseError::UnexpectedCharacterInAttributeName { .. } => write!(f, "Unexpected character in attribute name")?,
+            ParseError::UnexpectedCharacterInUnquotedAttributeValue { .. } => {
+                write!(f, "Unexpected character in unquoted attribute value")?
+            }
+            ParseError::EofBeforeTagName { .. } => write!(f, "End of input before tag name")?,
+            ParseError::EofInTag { name, .. } => write!(f, "End of input in tag <{}>", name)?,
+            ParseError::EofInComment { .. } => write!(f, "End of input in comment")?,
+            ParseError::EofInCData { .. } => write!(f, "End of input in CDATA section")?,
+            ParseError::EofInDoctype { .. } => write!(f, "End of input in doctype")?,
+            ParseError::EofInProcessingInstruction { .. } => write!(f, "End of input in processing instruction")?,
+            ParseError::NestingTooDeep { name, .. } => write!(f, "Element <{}> nested too deep", name)?,
+            ParseError::TooManyAttributes { name, .. } => write!(f, "Too many attributes in <{}>", name)?,
+            ParseError::TokenTooLong { .. } => write!(f, "Content too long")?,
+            ParseError::TooManyNodes { .. } => write!(f, "Too many nodes")?,
+        }
+
+        let start = self.span().start;
+        write!(f, " at line {}, column {} [{}]", start.line, start.column, self.code())
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -39,13 +39,13 @@
 //!
 //! ```
 
-#[macro_use]
-extern crate lazy_static;
-
 mod reader;
+mod dom_builder;
 
 pub mod utils;
 pub mod dom;
+pub mod encoding;
+pub mod error;
 pub mod parser;
 pub mod writer;
 
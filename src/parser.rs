@@ -1,28 +1,488 @@
 //! Read HTML files from strings
 
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use std::io::{self, Read};
+
 use crate::dom::*;
+use crate::dom_builder::{closes_paragraph, is_head_element, DomParseHandler};
+use crate::error::ParseError;
 use crate::utils::*;
 use crate::reader::*;
 
+/// Attribute of a start tag
+///
+/// Name and value borrow from the input unless the value contains character references.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagAttribute<'a> {
+    pub name: Cow<'a, str>,
+    pub value: Option<Cow<'a, str>>,
+}
+
+/// List of attributes of a start tag
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagAttributes<'a> {
+    list: Vec<TagAttribute<'a>>,
+}
+
+impl<'a> TagAttributes<'a> {
+    pub fn new() -> TagAttributes<'a> {
+        TagAttributes { list: Vec::new() }
+    }
+
+    /// Adds an attribute or replaces the value of an attribute with the same name
+    ///
+    /// A `None` value sets a boolean attribute.
+    pub fn set(&mut self, name: Cow<'a, str>, value: Option<Cow<'a, str>>) {
+        match self.list.iter_mut().find(|attr| attr.name == name) {
+            Some(attr) => attr.value = value,
+            None => self.list.push(TagAttribute { name, value }),
+        }
+    }
+
+    pub fn get_value(&self, name: &str) -> Option<&str> {
+        self.list.iter()
+            .find(|attr| attr.name == name)
+            .and_then(|attr| attr.value.as_deref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.list.iter().any(|attr| attr.name == name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, TagAttribute<'a>> {
+        self.list.iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b TagAttributes<'a> {
+    type Item = &'b TagAttribute<'a>;
+    type IntoIter = std::slice::Iter<'b, TagAttribute<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+impl From<TagAttributes<'_>> for Attributes {
+    fn from(attributes: TagAttributes<'_>) -> Attributes {
+        // The names are unique already, so they don't need to be searched like in `Attributes::set`
+        Attributes::from_unique(attributes.list.into_iter()
+            .map(|attr| Attribute {
+                name: attr.name.into_owned(),
+                value: attr.value.map(Cow::into_owned),
+            })
+            .collect())
+    }
+}
+
+/// Returns the lowercase version of a name without copying names that are lowercase already
+fn lowercase(name: &str) -> Cow<'_, str> {
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(name.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Reads the name and identifiers of a doctype from the content after `<!DOCTYPE`
+///
+/// Doctypes without a name, with unclosed identifiers or with unknown keywords are marked to force
+/// the quirks mode like in browsers.
+fn read_doctype(content: &str, closed: bool) -> Doctype {
+    let mut rest = content.trim_start_matches(is_html_whitespace);
+    let name_end = rest.find(is_html_whitespace).unwrap_or(rest.len());
+
+    let mut doctype = Doctype::new(rest[..name_end].to_ascii_lowercase());
+    doctype.force_quirks = !closed || doctype.name.is_empty();
+    rest = rest[name_end..].trim_start_matches(is_html_whitespace);
+
+    let keyword = rest.get(..6).unwrap_or(rest);
+    if keyword.eq_ignore_ascii_case("public") {
+        rest = &rest[6..];
+        match read_doctype_id(&mut rest) {
+            Some((id, closed)) => {
+                doctype.public_id = Some(id.to_owned());
+                doctype.force_quirks |= !closed;
+            }
+            None => doctype.force_quirks = true,
+        }
+        if !rest.trim_start_matches(is_html_whitespace).is_empty() {
+            if let Some((id, closed)) = read_doctype_id(&mut rest) {
+                doctype.system_id = Some(id.to_owned());
+                doctype.force_quirks |= !closed;
+            }
+        }
+    } else if keyword.eq_ignore_ascii_case("system") {
+        rest = &rest[6..];
+        match read_doctype_id(&mut rest) {
+            Some((id, closed)) => {
+                doctype.system_id = Some(id.to_owned());
+                doctype.force_quirks |= !closed;
+            }
+            None => doctype.force_quirks = true,
+        }
+    } else if !rest.is_empty() {
+        doctype.force_quirks = true;
+    }
+
+    doctype
+}
+
+/// Reads a quoted identifier of a doctype and returns it together with a flag if it was closed
+fn read_doctype_id<'a>(rest: &mut &'a str) -> Option<(&'a str, bool)> {
+    let trimmed = rest.trim_start_matches(is_html_whitespace);
+    let quote = trimmed.chars().next().filter(|&ch| ch == '"' || ch == '\'')?;
+    let id = &trimmed[1..];
+
+    match id.find(quote) {
+        Some(end) => {
+            *rest = &id[end + 1..];
+            Some((&id[..end], true))
+        }
+        None => {
+            *rest = "";
+            Some((id, false))
+        }
+    }
+}
+
+/// A token of the HTML input
+///
+/// Text and names borrow from the input like the arguments of the [`ParseHandler`] callbacks.
+///
+/// [`ParseHandler`]: trait.ParseHandler.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    Text(Cow<'a, str>, Span),
+    Comment(Cow<'a, str>, Span),
+    CData(Cow<'a, str>, Span),
+    Doctype(Doctype, Span),
+    ProcessingInstruction(Cow<'a, str>, Span),
+    /// A start tag
+    ///
+    /// See [`ParseHandler::element_start`] for the meaning of `autoclose` and `raw_content`.
+    ///
+    /// [`ParseHandler::element_start`]: trait.ParseHandler.html#tymethod.element_start
+    StartTag {
+        name: Cow<'a, str>,
+        attributes: TagAttributes<'a>,
+        autoclose: bool,
+        raw_content: Option<Cow<'a, str>>,
+        span: Span,
+    },
+    EndTag(Cow<'a, str>, Span),
+}
+
+impl Token<'_> {
+    /// The part of the input the token was created from
+    pub fn span(&self) -> Span {
+        match *self {
+            Token::Text(_, span)
+            | Token::Comment(_, span)
+            | Token::CData(_, span)
+            | Token::Doctype(_, span)
+            | Token::ProcessingInstruction(_, span)
+            | Token::StartTag { span, .. }
+            | Token::EndTag(_, span) => span,
+        }
+    }
+
+    fn dispatch<H: ParseHandler>(self, handler: &mut H) -> Control {
+        match self {
+            Token::Text(text, span) => handler.text(text, span),
+            Token::Comment(content, span) => handler.comment(content, span),
+            Token::CData(content, span) => handler.cdata(content, span),
+            Token::Doctype(doctype, span) => handler.doctype(doctype, span),
+            Token::ProcessingInstruction(content, span) => handler.processing_instruction(content, span),
+            Token::StartTag { name, attributes, autoclose, raw_content, span } => {
+                handler.element_start(name, attributes, autoclose, raw_content, span)
+            }
+            Token::EndTag(name, span) => handler.element_end(&name, span),
+        }
+    }
+}
+
+/// Tells the parser how to go on after a [`ParseHandler`] callback
+///
+/// [`ParseHandler`]: trait.ParseHandler.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Go on with the next event
+    Continue,
+    /// Skip all content of the element up to its end tag
+    ///
+    /// This is only used for the return value of `element_start`. The end tag of the element is still
+    /// passed to the handler, nested elements with the same name are taken into account. Elements with
+    /// an optional end tag like `li`, `p` or `head` also end at a tag that closes them implicitly, like
+    /// the next `li` or `<body>`, which is passed to the handler then.
+    SkipChildren,
+    /// Stop parsing and call `finish`
+    Stop,
+}
+
+/// Tests if an element opened in the skipped content keeps start tags from closing the skipped element
+fn shields_skipped(skipped: &str, open: &str) -> bool {
+    match skipped {
+        "li" => matches!(open, "ul" | "ol" | "menu"),
+        "dd" | "dt" => open == "dl",
+        "tr" | "td" | "th" => open == "table",
+        "p" => matches!(open, "button" | "applet" | "marquee" | "object" | "template" | "table" | "caption" | "td" | "th"),
+        _ => false,
+    }
+}
+
+/// Tests if a start tag closes the skipped element implicitly like the tree builder does
+fn closes_skipped(skipped: &str, start: &str) -> bool {
+    match skipped {
+        "p" => closes_paragraph(start),
+        "li" => start == "li",
+        "dd" | "dt" => matches!(start, "dd" | "dt"),
+        "option" => matches!(start, "option" | "optgroup"),
+        "tr" => matches!(start, "tr" | "tbody" | "thead" | "tfoot"),
+        "td" | "th" => matches!(start, "td" | "th" | "tr" | "tbody" | "thead" | "tfoot"),
+        "head" => !is_head_element(start) && !matches!(start, "html" | "head"),
+        _ => false,
+    }
+}
+
+/// An element whose content is skipped
+struct Skipped {
+    name: String,
+    /// Elements opened in the skipped content that weren't closed yet
+    open: Vec<String>,
+}
+
+/// Applies the control values returned by a handler to the following events
+#[derive(Default)]
+struct ControlState {
+    skipped: Option<Skipped>,
+    stopped: bool,
+}
+
+impl ControlState {
+    fn dispatch<H: ParseHandler>(&mut self, event: Event<'_>, handler: &mut H) {
+        if let Some(skipped) = &mut self.skipped {
+            let ended = match event {
+                Event::Token(Token::StartTag { name: ref start, autoclose: false, raw_content: None, .. }) => {
+                    let closed = closes_skipped(&skipped.name, start)
+                        && !skipped.open.iter().any(|open| shields_skipped(&skipped.name, open));
+                    if !closed {
+                        skipped.open.push(start.clone().into_owned());
+                    }
+                    closed
+                }
+                Event::Token(Token::EndTag(ref end, _)) => match skipped.open.iter().rposition(|open| open == end) {
+                    Some(index) => {
+                        skipped.open.truncate(index);
+                        false
+                    }
+                    // The end tag of a parent closes elements with an optional end tag too
+                    None => end == skipped.name.as_str() || has_optional_end_tag(&skipped.name),
+                },
+                _ => false,
+            };
+
+            if ended {
+                self.skipped = None;
+                self.dispatch(event, handler);
+            }
+            return;
+        }
+
+        // Only elements with children can be skipped
+        let skippable = match event {
+            Event::Token(Token::StartTag { ref name, autoclose: false, raw_content: None, .. }) => Some(name.clone()),
+            _ => None,
+        };
+
+        match event.dispatch(handler) {
+            Control::Continue => {}
+            Control::SkipChildren => self.skipped = skippable.map(|name| Skipped { name: name.into_owned(), open: Vec::new() }),
+            Control::Stop => self.stopped = true,
+        }
+    }
+}
+
+/// An event created by the parser that is passed on to a `ParseHandler`
+enum Event<'a> {
+    Token(Token<'a>),
+    Error(ParseError),
+}
+
+impl Event<'_> {
+    fn dispatch<H: ParseHandler>(self, handler: &mut H) -> Control {
+        match self {
+            Event::Token(token) => token.dispatch(handler),
+            Event::Error(error) => {
+                handler.error(error);
+                Control::Continue
+            }
+        }
+    }
+}
+
 /**
   A Parser to parse HTML alike tag soup
 */
-struct Parser<'a, H: 'a + ParseHandler> {
-    pub handler: &'a mut H,
-    pub reader: &'a mut Reader<'a>,
+struct Parser<'a> {
+    pub reader: Reader<'a>,
+    /// Start position of the currently processed token
+    pub start: Position,
+    /// Events created by the current token
+    pub events: VecDeque<Event<'a>>,
+    limits: ParserLimits,
+    /// Read invalid markup as comments like browsers do
+    bogus_comments: bool,
+    /// Parse XML instead of HTML
+    xml: bool,
+    /// Names of the attributes of the current start tag to find duplicates
+    attribute_names: HashSet<Cow<'a, str>>,
 }
 
-impl<'a, H: ParseHandler> Parser<'a, H> {
-    pub fn parse(mut self) {
-        while let Some(ch) = self.reader.next_char() {
-            self.process(ch);
+impl<'a> Parser<'a> {
+    pub fn new(reader: Reader<'a>) -> Parser<'a> {
+        Parser::with_options(reader, &ParseOptions::default())
+    }
+
+    pub fn with_options(mut reader: Reader<'a>, options: &ParseOptions) -> Parser<'a> {
+        let xml = options.mode == ParseMode::Xml;
+        reader.xml_entities = xml;
+
+        Parser {
+            reader,
+            start: Position::default(),
+            events: VecDeque::new(),
+            limits: options.limits.clone(),
+            bogus_comments: options.bogus_comments,
+            xml,
+            attribute_names: HashSet::new(),
+        }
+    }
+
+    /// Returns the name of a tag or an attribute, which is lowercase unless XML is parsed
+    fn name(&self, name: &'a str) -> Cow<'a, str> {
+        if self.xml {
+            Cow::Borrowed(name)
+        } else {
+            lowercase(name)
         }
+    }
 
-        self.handler.finish();
+    /// Parses the next token and stores the resulting events
+    ///
+    /// Returns false if the end of input is reached
+    pub fn next_token(&mut self) -> bool {
+        self.start = self.reader.position();
+        match self.reader.next_char() {
+            Some(ch) => self.process(ch),
+            None => return false,
+        }
+
+        let errors = self.reader.errors.drain(..).map(Event::Error);
+        self.events.extend(errors);
+
+        true
+    }
+
+    /// Passes all events of the current token to the handler
+    pub fn dispatch<H: ParseHandler>(&mut self, handler: &mut H, control: &mut ControlState) {
+        for event in self.events.drain(..) {
+            if control.stopped {
+                break;
+            }
+            control.dispatch(event, handler);
+        }
+    }
+
+    fn push(&mut self, event: Event<'a>) {
+        // Errors found by the reader belong to the token causing the event
+        let errors = self.reader.errors.drain(..).map(Event::Error);
+        self.events.extend(errors);
+        self.events.push_back(event);
+    }
+
+    fn emit(&mut self, mut token: Token<'a>) {
+        match &mut token {
+            Token::Text(content, span)
+            | Token::Comment(content, span)
+            | Token::CData(content, span)
+            | Token::ProcessingInstruction(content, span) => self.limit_length(content, *span),
+            Token::StartTag { attributes, raw_content, span, .. } => {
+                for attr in &mut attributes.list {
+                    if let Some(value) = &mut attr.value {
+                        self.limit_length(value, *span);
+                    }
+                }
+                if let Some(content) = raw_content {
+                    self.limit_length(content, *span);
+                }
+            }
+            // Tag names are limited when they are read and the content of doctypes before their identifiers are read
+            Token::Doctype(..) | Token::EndTag(..) => {}
+        }
+
+        self.push(Event::Token(token));
+    }
+
+    /// Truncates content longer than the maximum token length
+    fn limit_length(&mut self, content: &mut Cow<'a, str>, span: Span) {
+        let max = self.limits.max_token_length;
+        if content.len() <= max {
+            return;
+        }
+
+        let end = (0..=max).rev().find(|&end| content.is_char_boundary(end)).unwrap_or(0);
+        match content {
+            Cow::Borrowed(content) => *content = &content[..end],
+            Cow::Owned(content) => content.truncate(end),
+        }
+
+        self.error(ParseError::TokenTooLong { span });
+    }
+
+    /// Adds an attribute of a start tag
+    ///
+    /// Like in browsers only the first of attributes with the same name is kept. Returns false if
+    /// the attribute is dropped because the tag has the maximum number of attributes already.
+    /// Long names are truncated before they are compared.
+    fn add_attribute(&mut self, attributes: &mut TagAttributes<'a>, mut name: Cow<'a, str>, value: Option<Cow<'a, str>>, span: Span) -> bool {
+        self.limit_length(&mut name, span);
+
+        if self.attribute_names.contains(&name) {
+            self.error(ParseError::DuplicateAttribute { name: name.into_owned(), span });
+            return true;
+        }
+
+        if attributes.len() >= self.limits.max_attributes {
+            return false;
+        }
+
+        self.attribute_names.insert(name.clone());
+        attributes.list.push(TagAttribute { name, value });
+        true
+    }
+
+    fn error(&mut self, error: ParseError) {
+        self.push(Event::Error(error));
+    }
+
+    /// Span from the start of the current token up to the current position
+    fn span(&self) -> Span {
+        Span::new(self.start, self.reader.position())
     }
 
     fn process(&mut self, ch: char) {
         match ch {
+            '<' if self.bogus_comments => self.parse_markup(),
             '<' => {
                 match self.reader.next_char() {
                     Some('/') => self.parse_end_element(),
@@ -30,7 +490,7 @@ impl<'a, H: ParseHandler> Parser<'a, H> {
                         match (self.reader.next_char(), self.reader.next_char()) {
                             (Some('-'), Some('-')) => self.parse_comment(),
                             (Some('['), Some('C')) => self.parse_cdata(),
-                            (Some('D'), Some('O')) => self.parse_doctype(),
+                            (Some(d @ ('D' | 'd')), Some(o @ ('O' | 'o'))) => self.parse_doctype(d, o),
                             (Some('>'), ch2) => self.parse_unknown_special_tag(ch2),
                             (Some(ch), Some('>')) => self.parse_unknown_special_tag2(ch),
                             (ch, ch2) => self.parse_unknown_special_tag3(ch, ch2),
@@ -41,64 +501,175 @@ impl<'a, H: ParseHandler> Parser<'a, H> {
                         self.reader.push_back(ch);
                         self.parse_start_element();
                     }
-                    None => self.handler.text("<".into()),
+                    None => {
+                        let span = self.span();
+                        self.error(ParseError::EofBeforeTagName { span });
+                        self.emit(Token::Text(Cow::Borrowed("<"), span));
+                    }
                 }
             }
             _ => {
                 self.reader.push_back(ch);
-                let text = self.reader.read_text(&['<']);
-                self.handler.text(text);
+                let text = self.reader.read_text(&['<'], false);
+                let span = self.span();
+                self.emit(Token::Text(text, span));
+            }
+        }
+    }
+
+    /// Parses the markup after a `<` the way browsers do
+    ///
+    /// Markup that is neither a tag, a comment, a CDATA section nor a doctype is read as a comment.
+    fn parse_markup(&mut self) {
+        match self.reader.next_char() {
+            Some('!') => {
+                if self.reader.read_prefix("--", false) {
+                    self.parse_comment_like_browsers();
+                } else if self.reader.read_prefix("DOCTYPE", true) {
+                    self.parse_doctype_content();
+                } else if self.reader.read_prefix("[CDATA[", false) {
+                    self.parse_cdata_content();
+                } else {
+                    self.parse_bogus_comment();
+                }
+            }
+            Some('?') => {
+                // The question mark is part of the comment
+                self.reader.push_back('?');
+                self.parse_bogus_comment();
+            }
+            Some('/') => match self.reader.next_char() {
+                Some(ch) if ch.is_ascii_alphabetic() => {
+                    self.reader.push_back(ch);
+                    self.parse_end_element();
+                }
+                Some('>') => {
+                    let span = self.span();
+                    self.error(ParseError::MissingEndTagName { span });
+                }
+                Some(ch) => {
+                    self.reader.push_back(ch);
+                    self.parse_bogus_comment();
+                }
+                None => {
+                    let span = self.span();
+                    self.error(ParseError::EofBeforeTagName { span });
+                    self.emit(Token::Text(Cow::Borrowed("</"), span));
+                }
+            },
+            Some(ch) if ch.is_ascii_alphabetic() => {
+                self.reader.push_back(ch);
+                self.parse_start_element();
             }
+            Some(ch) => {
+                // Only a letter starts a tag, so the `<` is text like in `a < b`
+                self.reader.push_back(ch);
+                let span = self.span();
+                self.error(ParseError::InvalidFirstCharacterOfTagName { span });
+                self.emit(Token::Text(Cow::Borrowed("<"), span));
+            }
+            None => {
+                let span = self.span();
+                self.error(ParseError::EofBeforeTagName { span });
+                self.emit(Token::Text(Cow::Borrowed("<"), span));
+            }
+        }
+    }
+
+    /// Reads everything up to the next `>` as a comment
+    fn parse_bogus_comment(&mut self) {
+        let (content, _) = self.reader.read_raw(">");
+        let span = self.span();
+        self.error(ParseError::BogusComment { span });
+        self.emit(Token::Comment(Cow::Borrowed(content), span));
+    }
+
+    /// Parses a comment that is closed by `-->`, `--!>` or directly by `>` if it is empty
+    fn parse_comment_like_browsers(&mut self) {
+        if self.reader.read_prefix(">", false) || self.reader.read_prefix("->", false) {
+            let span = self.span();
+            self.error(ParseError::AbruptClosingOfEmptyComment { span });
+            self.emit(Token::Comment(Cow::Borrowed(""), span));
+            return;
         }
+
+        let (content, end) = self.reader.read_comment();
+        let span = self.span();
+        match end {
+            Some("--!>") => self.error(ParseError::IncorrectlyClosedComment { span }),
+            Some(_) => {}
+            None => self.error(ParseError::EofInComment { span }),
+        }
+        self.emit(Token::Comment(Cow::Borrowed(content), span));
     }
 
-    fn parse_doctype(&mut self) {
+    fn parse_doctype(&mut self, d: char, o: char) {
         let marker = self.reader.read_raw_count(5);
-        if &marker == "CTYPE" {
-            self.handler.doctype(
-                self.reader.read_raw(">").trim().to_owned(),
-            );
+        if marker.eq_ignore_ascii_case("CTYPE") {
+            self.parse_doctype_content();
         } else {
-            let mut text = self.reader.read_text(&['<', '>']);
-            text.insert_str(0, "<!DO");
-            text.insert_str(4, &marker);
+            let mut text = format!("<!{}{}{}", d, o, marker);
+            text.push_str(&self.reader.read_text(&['<', '>'], false));
             if self.reader.ignore_if_next('>') {
                 text.push('>');
             }
-            self.handler.text(text);
+            self.invalid_markup_declaration(text);
         }
     }
 
+    fn parse_doctype_content(&mut self) {
+        let (content, closed) = self.reader.read_raw(">");
+        let span = self.span();
+        if !closed {
+            self.error(ParseError::EofInDoctype { span });
+        }
+        let mut content = Cow::Borrowed(content);
+        self.limit_length(&mut content, span);
+        self.emit(Token::Doctype(read_doctype(&content, closed), span));
+    }
+
     fn parse_cdata(&mut self) {
         let marker = self.reader.read_raw_count(5);
-        if &marker == "DATA[" {
-            let content = self.reader.read_raw("]]>");
-            self.handler.cdata(content);
+        if marker == "DATA[" {
+            self.parse_cdata_content();
         } else {
-            let mut text = self.reader.read_text(&['<', '>']);
+            let mut text = self.reader.read_text(&['<', '>'], false).into_owned();
             text.insert_str(0, "<![C");
-            text.insert_str(4, &marker);
+            text.insert_str(4, marker);
             if self.reader.ignore_if_next('>') {
                 text.push('>');
             }
-            self.handler.text(text);
+            self.invalid_markup_declaration(text);
+        }
+    }
+
+    fn parse_cdata_content(&mut self) {
+        let (content, closed) = self.reader.read_raw("]]>");
+        let span = self.span();
+        if !closed {
+            self.error(ParseError::EofInCData { span });
         }
+        self.emit(Token::CData(Cow::Borrowed(content), span));
     }
 
     fn parse_comment(&mut self) {
-        let content = self.reader.read_raw("-->");
-        self.handler.comment(content);
+        let (content, closed) = self.reader.read_raw("-->");
+        let span = self.span();
+        if !closed {
+            self.error(ParseError::EofInComment { span });
+        }
+        self.emit(Token::Comment(Cow::Borrowed(content), span));
     }
 
     fn parse_unknown_special_tag(&mut self, ch2: Option<char>) {
-        self.handler.text("<!>".to_owned());
         if let Some(ch2) = ch2 {
             self.reader.push_back(ch2);
         }
+        self.invalid_markup_declaration("<!>".to_owned());
     }
 
     fn parse_unknown_special_tag2(&mut self, ch: char) {
-        self.handler.text(format!("<!{}>", ch))
+        self.invalid_markup_declaration(format!("<!{}>", ch));
     }
 
     fn parse_unknown_special_tag3(&mut self, ch: Option<char>, ch2: Option<char>) {
@@ -109,120 +680,218 @@ impl<'a, H: ParseHandler> Parser<'a, H> {
         }
         if let Some(ch2) = ch2 {
             text.push(ch2);
-            text.push_str(&self.reader.read_text(&['<', '>']));
+            text.push_str(&self.reader.read_text(&['<', '>'], false));
         }
 
         if self.reader.ignore_if_next('>') {
             text.push('>');
         }
-        self.handler.text(text);
+        self.invalid_markup_declaration(text);
     }
 
+    /// Reports a bad `<!...>` tag and keeps it as text
+    fn invalid_markup_declaration(&mut self, text: String) {
+        let span = self.span();
+        self.error(ParseError::InvalidMarkupDeclaration { span });
+        self.emit(Token::Text(Cow::Owned(text), span));
+    }
 
     fn parse_processing_instruction(&mut self) {
-        let text = self.reader.read_raw("?>");
-        self.handler.processing_instruction(text);
+        let (text, closed) = self.reader.read_raw("?>");
+        let span = self.span();
+        if !closed {
+            self.error(ParseError::EofInProcessingInstruction { span });
+        }
+        self.emit(Token::ProcessingInstruction(Cow::Borrowed(text), span));
     }
 
     fn parse_start_element(&mut self) {
         self.reader.skip_whitespace();
 
-        let raw_name = self.reader.get_until(true, &['>', '/']);
-        let name = raw_name.to_ascii_lowercase();
-        let autoclose = is_autoclose_element(&name);
-        let mut attributes = Attributes::new();
+        let name = self.reader.get_until(true, &['>', '/']);
+        let mut name = self.name(name);
+        self.limit_length(&mut name, self.span());
+        let autoclose = !self.xml && is_autoclose_element(&name);
+        let mut attributes = TagAttributes::new();
+        self.attribute_names.clear();
+        let mut dropped = false;
+        let mut closed = false;
+        let mut self_closing = false;
 
-        self.reader.skip_whitespace();
-
-        while let Some(ch) = self.reader.next_char() {
-            if ch == '/' || ch == '>' {
-                self.reader.push_back(ch);
-                break;
-            }
-
-            if is_html_whitespace(ch) {
-                self.reader.skip_whitespace();
-                continue;
-            }
-
-            self.reader.push_back(ch);
-
-            let name = self.reader.get_until(true, &['=', '/', '>']);
+        loop {
             self.reader.skip_whitespace();
 
             match self.reader.next_char() {
-                Some('=') => {
-                    self.reader.skip_whitespace();
-                    let value = match self.reader.next_char() {
-                        Some('"') => self.reader.read_text(&['"']),
-                        Some('\'') => self.reader.read_text(&['\'']),
-                        Some(ch) => {
-                            self.reader.push_back(ch);
-                            self.reader.read_text(&[' ', '/', '>'])
-                        }
-                        None => {
-                            //bad attribute at end of input
-                            attributes.set_bool(name);
-                            break;
-                        }
-                    };
-                    self.reader.next_char();
-                    attributes.set(name, value);
-                }
-                Some(ch) => {
-                    //boolean attribute
-                    attributes.set_bool(name);
-                    self.reader.push_back(ch);
-                }
-                None => {
-                    //Onclosed element at end of input
+                Some('>') => {
+                    closed = true;
                     break;
                 }
+                Some('/') => {
+                    // A slash not followed by `>` is ignored
+                    if self.reader.ignore_if_next('>') {
+                        closed = true;
+                        self_closing = true;
+                        break;
+                    }
+                    continue;
+                }
+                Some(ch) => self.reader.push_back(ch),
+                None => break,
             }
+
+            let start = self.reader.position();
+            let (name, value) = self.parse_attribute();
+            let span = Span::new(start, self.reader.position());
+            dropped |= !self.add_attribute(&mut attributes, name, value, span);
         }
 
-        let autoclose = match self.reader.next_char() {
-            Some('/') => {
-                self.reader.next_char();
-                true
+        let autoclose = autoclose || self_closing;
+
+        if !closed {
+            let span = self.span();
+            self.error(ParseError::EofInTag { name: name.clone().into_owned(), span });
+        }
+
+        if dropped {
+            let span = self.span();
+            self.error(ParseError::TooManyAttributes { name: name.clone().into_owned(), span });
+        }
+
+        let raw_content = if autoclose || self.xml {
+            None
+        } else if name == "plaintext" {
+            Some(Cow::Borrowed(self.reader.read_to_end()))
+        } else if is_raw_element(&name) || is_rcdata_element(&name) {
+            let start = self.reader.position();
+            let (content, found) = self.reader.read_raw_text(&name, name == "script");
+            if !found {
+                let span = self.span();
+                self.error(ParseError::UnclosedElement { name: name.clone().into_owned(), span });
+            } else if !self.reader.read_raw(">").1 {
+                // Like browsers attributes of the end tag are ignored
+                let span = self.span();
+                self.error(ParseError::EofInTag { name: name.clone().into_owned(), span });
             }
-            _ => autoclose,
-        };
 
-        let raw_content = if !autoclose && is_raw_element(&name) {
-            Some(self.reader.read_raw(&format!("</{}>", raw_name)))
+            if is_rcdata_element(&name) {
+                Some(self.decode_rcdata(&name, content, start))
+            } else {
+                Some(Cow::Borrowed(content))
+            }
         } else {
             None
         };
 
-        self.handler.element_start(
+        let span = self.span();
+        self.emit(Token::StartTag {
             name,
             attributes,
             autoclose,
             raw_content,
-        );
+            span,
+        });
+    }
+
+    /// Parses an attribute of a start tag and returns its name and its value
+    ///
+    /// A `=` at the end of input is ignored.
+    fn parse_attribute(&mut self) -> (Cow<'a, str>, Option<Cow<'a, str>>) {
+        let start = self.reader.position();
+
+        // A leading `=` is part of the name
+        self.reader.ignore_if_next('=');
+        self.reader.get_until(true, &['=', '/', '>']);
+        let name = self.reader.slice(start, self.reader.position());
+
+        if name.contains(['"', '\'', '<']) || name.starts_with('=') {
+            let span = self.span();
+            self.error(ParseError::UnexpectedCharacterInAttributeName { span });
+        }
+
+        let name = self.name(name);
+        self.reader.skip_whitespace();
+
+        if !self.reader.ignore_if_next('=') {
+            return (name, None);
+        }
+
+        self.reader.skip_whitespace();
+
+        let value = match self.reader.next_char() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                let value = self.reader.read_text(&[quote], true);
+                self.reader.next_char();
+                value
+            }
+            Some(ch) => {
+                self.reader.push_back(ch);
+                let start = self.reader.position();
+                let value = self.reader.read_text(&[' ', '\n', '\t', '\r', '\x0C', '>'], true);
+
+                if self.reader.slice(start, self.reader.position()).contains(['"', '\'', '<', '=', '`']) {
+                    let span = self.span();
+                    self.error(ParseError::UnexpectedCharacterInUnquotedAttributeValue { span });
+                }
+                value
+            }
+            None => return (name, None),
+        };
+
+        (name, Some(value))
+    }
+
+    /// Decodes the character references in the content of `title` and `textarea` elements
+    fn decode_rcdata(&mut self, name: &str, content: &'a str, start: Position) -> Cow<'a, str> {
+        let mut reader = Reader::new_at(content, start, false);
+
+        // A newline directly after the start tag of a textarea is ignored
+        if name == "textarea" {
+            reader.ignore_if_next('\n');
+        }
+
+        let text = reader.read_text(&[], false);
+        self.reader.errors.append(&mut reader.errors);
+        text
     }
 
     fn parse_end_element(&mut self) {
         self.reader.skip_whitespace();
-        let name = self.reader.get_until(true, &['>']).to_ascii_lowercase();
+        let name = self.reader.get_until(true, &['>']);
+        let mut name = self.name(name);
+        self.limit_length(&mut name, self.span());
         self.reader.skip_whitespace();
-        self.reader.next_char();
+        let closed = if self.bogus_comments {
+            // Like browsers attributes of end tags are ignored
+            self.reader.read_raw(">").1
+        } else {
+            self.reader.next_char().is_some()
+        };
 
-        self.handler.element_end(&name);
+        let span = self.span();
+        if !closed {
+            self.error(ParseError::EofInTag { name: name.clone().into_owned(), span });
+        }
+        self.emit(Token::EndTag(name, span));
     }
 }
 
 /// Handles parser events
 ///
+/// Every event comes with the [`Span`] of the input it was created from. Text, names and attribute
+/// values borrow from the input and are only copied if character references were decoded or a
+/// name was lowercased. Use `into_owned()` to keep them after the call.
+///
+/// The callbacks return a [`Control`] value to stop parsing or to skip the content of an element.
+///
 /// # Example
 ///
 /// A handler that prints events to stdout during parsing:
 ///
 /// ```rust
 ///
-/// use qd_html::parser::{ParseHandler, parse};
-/// use qd_html::dom::Attributes;
+/// use std::borrow::Cow;
+/// use qd_html::parser::{Control, ParseHandler, TagAttributes, parse};
+/// use qd_html::dom::{Doctype, Span};
 ///
 /// struct ExampleHandler {
 /// }
@@ -232,36 +901,44 @@ impl<'a, H: ParseHandler> Parser<'a, H> {
 ///         println!("FINISHED");
 ///     }
 ///
-///     fn text(&mut self, text: String) {
-///         println!("TEXT: {}", text);
+///     fn text(&mut self, text: Cow<str>, span: Span) -> Control {
+///         println!("TEXT: {} at line {}", text, span.start.line);
+///         Control::Continue
 ///     }
 ///
-///     fn comment(&mut self, content: String) {
+///     fn comment(&mut self, content: Cow<str>, span: Span) -> Control {
 ///         println!("COMMENT: {}", content);
+///         Control::Continue
 ///     }
 ///
-///     fn cdata(&mut self, content: String) {
+///     fn cdata(&mut self, content: Cow<str>, span: Span) -> Control {
 ///         println!("CDATA: {}", content);
+///         Control::Continue
 ///     }
 ///
-///     fn doctype(&mut self, content: String) {
-///         println!("DOCTYPE: {}", content);
+///     fn doctype(&mut self, doctype: Doctype, span: Span) -> Control {
+///         println!("DOCTYPE: {}", doctype.name);
+///         Control::Continue
 ///     }
 ///
-///     fn processing_instruction(&mut self, content: String) {
+///     fn processing_instruction(&mut self, content: Cow<str>, span: Span) -> Control {
 ///         println!("PI: {}", content);
+///         Control::Continue
 ///     }
 ///
 ///     fn element_start(&mut self,
-///                      name: String,
-///                      attributes: Attributes,
+///                      name: Cow<str>,
+///                      attributes: TagAttributes,
 ///                      autoclose: bool,
-///                      raw_content: Option<String>) {
+///                      raw_content: Option<Cow<str>>,
+///                      span: Span) -> Control {
 ///         println!("ELEMENT START: {}", name);
+///         Control::Continue
 ///     }
 ///
-///     fn element_end(&mut self,name: &str) {
+///     fn element_end(&mut self, name: &str, span: Span) -> Control {
 ///         println!("ELEMENT END: {}", name);
+///         Control::Continue
 ///     }
 ///
 /// }
@@ -273,7 +950,8 @@ impl<'a, H: ParseHandler> Parser<'a, H> {
 ///
 /// ```
 ///
-///
+/// [`Span`]: ../dom/struct.Span.html
+/// [`Control`]: enum.Control.html
 pub trait ParseHandler {
     /// Called after the parser finished processing the HTML
     fn finish(&mut self) {}
@@ -281,111 +959,196 @@ pub trait ParseHandler {
     /// Called on text content
     ///
     /// All HTML entities have already been replaced with their corresponding character
-    fn text(&mut self, text: String);
+    fn text(&mut self, text: Cow<str>, span: Span) -> Control;
 
     /// Called on a comment
-    fn comment(&mut self, content: String);
+    fn comment(&mut self, content: Cow<str>, span: Span) -> Control;
 
     /// Called on a Data element
-    fn cdata(&mut self, content: String);
+    fn cdata(&mut self, content: Cow<str>, span: Span) -> Control;
 
     /// Called on a doctype definition
     ///
     /// Warning: If a bad HTML file contains multiple doctype definitions this is called multiple time
-    fn doctype(&mut self, content: String);
+    fn doctype(&mut self, doctype: Doctype, span: Span) -> Control;
 
     /// Called on a xml processing instruction
     ///
     /// This will be called for XML declarations (i.e. `<?xml version="1"?`>) too.
-    fn processing_instruction(&mut self, content: String);
+    fn processing_instruction(&mut self, content: Cow<str>, span: Span) -> Control;
 
     /// Called on an elements start tag
     ///
-    /// In case of tags containing raw content (`script` and `style`) the string is passed with the `raw_content` parameter. In this case the element_end event is not fired.
+    /// In case of tags containing raw content (`script`, `style`, `title`, `textarea`, ...) the string is passed with the `raw_content` parameter. In this case the element_end event is not fired
+    /// and the span covers the whole element including its end tag. Character references in the content of `title` and `textarea` are decoded.
     fn element_start(
         &mut self,
-        name: String,
-        attributes: Attributes,
+        name: Cow<str>,
+        attributes: TagAttributes,
         autoclose: bool,
-        raw_content: Option<String>,
-    );
+        raw_content: Option<Cow<str>>,
+        span: Span,
+    ) -> Control;
 
     /// Called on an elements end tag
     ///
     /// This is not called for autoclosing and raw content elements
-    fn element_end(&mut self, name: &str);
-}
+    fn element_end(&mut self, name: &str, span: Span) -> Control;
 
-/// A ParseHandler implementation that construct simplified DOM tree
-struct DomParseHandler {
-    pub stack: Vec<Element>,
-    pub current: Element,
-    pub doctype: Option<String>,
-    pub is_xml: bool,
+    /// Called on recoverable errors in the HTML
+    ///
+    /// The parser always fixes the error and goes on. The default implementation ignores all errors.
+    fn error(&mut self, _error: ParseError) {}
 }
 
-impl ParseHandler for DomParseHandler {
+impl<H: ParseHandler + ?Sized> ParseHandler for &mut H {
     fn finish(&mut self) {
-        while let Some(mut e) = self.stack.pop() {
-            ::std::mem::swap(&mut self.current, &mut e);
-            self.current.nodes.push(Node::Element(e));
-        }
+        (**self).finish()
     }
 
-    fn text(&mut self, content: String) {
-        self.current.add_text(content);
+    fn text(&mut self, text: Cow<str>, span: Span) -> Control {
+        (**self).text(text, span)
     }
 
-    fn comment(&mut self, content: String) {
-        self.current.add_comment(content);
+    fn comment(&mut self, content: Cow<str>, span: Span) -> Control {
+        (**self).comment(content, span)
     }
 
-    fn cdata(&mut self, content: String) {
-        self.current.add_cdata(content);
+    fn cdata(&mut self, content: Cow<str>, span: Span) -> Control {
+        (**self).cdata(content, span)
     }
 
-    fn doctype(&mut self, content: String) {
-        self.doctype = Some(content);
+    fn doctype(&mut self, doctype: Doctype, span: Span) -> Control {
+        (**self).doctype(doctype, span)
     }
 
-    fn processing_instruction(&mut self, content: String) {
-        if content.starts_with("xml") {
-            self.is_xml = true;
-        } else {
-            self.current.add_processing_instruction(content);
-        }
+    fn processing_instruction(&mut self, content: Cow<str>, span: Span) -> Control {
+        (**self).processing_instruction(content, span)
     }
 
     fn element_start(
         &mut self,
-        name: String,
-        attributes: Attributes,
+        name: Cow<str>,
+        attributes: TagAttributes,
         autoclose: bool,
-        raw_content: Option<String>,
-    ) {
-        let mut e = Element::new_with_attributes(name, attributes);
-        e.autoclosed = autoclose;
-
-        if autoclose {
-            self.current.add_element(e);
-        } else if let Some(raw_content) = raw_content {
-            e.nodes.push(Node::Raw(Raw::new(raw_content)));
-            self.current.nodes.push(Node::Element(e));
-        } else {
-            ::std::mem::swap(&mut self.current, &mut e);
-            self.stack.push(e);
-        }
+        raw_content: Option<Cow<str>>,
+        span: Span,
+    ) -> Control {
+        (**self).element_start(name, attributes, autoclose, raw_content, span)
     }
 
-    fn element_end(&mut self, name: &str) {
-        while let Some(mut e) = self.stack.pop() {
-            ::std::mem::swap(&mut self.current, &mut e);
+    fn element_end(&mut self, name: &str, span: Span) -> Control {
+        (**self).element_end(name, span)
+    }
 
-            let same = e.name == name;
-            self.current.add_element(e);
-            if same {
-                return;
-            }
+    fn error(&mut self, error: ParseError) {
+        (**self).error(error)
+    }
+}
+
+/// Options to control how HTML is parsed
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    /// Store the source spans on elements, text and comment nodes
+    pub spans: bool,
+    /// Encoding label used for byte input without a byte order mark
+    ///
+    /// This is meant for encodings declared outside of the HTML, i.e. by a HTTP `Content-Type` header.
+    /// It takes precedence over a `<meta charset>` element.
+    pub encoding_hint: Option<String>,
+    /// Insert the implied `html`, `head` and `body` elements when building a DOM
+    ///
+    /// Elements only allowed in the head are put into `head` until the first other content.
+    pub implied_elements: bool,
+    /// Limits for untrusted input
+    pub limits: ParserLimits,
+    /// Read invalid markup as comments like browsers do
+    ///
+    /// Markup like `<!foo>`, `<?php ?>` or `</1>` becomes a comment, `<!-->` is an empty comment and
+    /// `--!>` closes a comment. `</>` is dropped and a `<` that isn't followed by a letter is text. If
+    /// not set the invalid markup is kept as text and processing instructions are read up to `?>`.
+    pub bogus_comments: bool,
+    /// Syntax of the input
+    pub mode: ParseMode,
+}
+
+/// Syntax the input is parsed with
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{parse_to_dom_with_options, ParseMode, ParseOptions};
+///
+/// let options = ParseOptions { mode: ParseMode::Xml, ..ParseOptions::default() };
+/// let document = parse_to_dom_with_options("<Feed><script/><Item>&amp;&nbsp;</Item></Feed>", &options);
+///
+/// assert!(document.is_xml);
+/// assert_eq!(qd_html::writer::write(&document), "<Feed><script/><Item>&amp;&amp;nbsp;</Item></Feed>");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// HTML with its rules for void elements, raw text elements and optional end tags
+    #[default]
+    Html,
+    /// XML or XHTML
+    ///
+    /// Names keep their case and every element can be closed with `/>`. Only the five XML entities
+    /// and numeric character references are decoded and end tags have to match the open elements.
+    Xml,
+}
+
+/// Limits to guard against hostile input
+///
+/// Content exceeding a limit is truncated and an error is reported. The defaults are far above what
+/// documents written by hand need, but keep hostile input from nesting elements deep enough to
+/// overflow the stack when the tree is dropped or written.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{parse_to_dom_with_errors, ParseOptions, ParserLimits};
+///
+/// let options = ParseOptions {
+///     limits: ParserLimits {
+///         max_depth: 2,
+///         ..ParserLimits::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let (document, errors) = parse_to_dom_with_errors("<div><div><div></div></div></div>", &options);
+/// assert_eq!(qd_html::writer::write(&document), "<div><div></div><div></div></div>");
+/// assert_eq!(errors[0].code(), "nesting-too-deep");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// Maximum number of nested elements when building a DOM
+    ///
+    /// Elements that would be nested deeper are added after the current element instead. This also
+    /// applies to elements opened implicitly, like reopened formatting elements, and limits the number
+    /// of formatting elements that are kept to be reopened. Defaults to 512.
+    pub max_depth: usize,
+    /// Maximum number of attributes of a start tag
+    ///
+    /// Further attributes are dropped. Defaults to 256.
+    pub max_attributes: usize,
+    /// Maximum length in bytes of text, comments, raw element content, tag names and attribute names and values
+    ///
+    /// Longer content is cut off. Defaults to 64 MiB.
+    pub max_token_length: usize,
+    /// Maximum number of nodes when building a DOM
+    ///
+    /// Parsing stops when the limit is reached. Defaults to 10 million.
+    pub max_nodes: usize,
+}
+
+impl Default for ParserLimits {
+    fn default() -> ParserLimits {
+        ParserLimits {
+            max_depth: 512,
+            max_attributes: 256,
+            max_token_length: 64 * 1024 * 1024,
+            max_nodes: 10_000_000,
         }
     }
 }
@@ -396,10 +1159,306 @@ impl ParseHandler for DomParseHandler {
 ///
 /// [`ParseHandler`]: trait.ParseHandler.html
 pub fn parse<H: ParseHandler>(handler: &mut H, raw: &str) {
-    Parser {
-        handler,
-        reader: &mut Reader::new(raw),
-    }.parse();
+    parse_with_options(handler, raw, &ParseOptions::default());
+}
+
+/// Parse HTML with the supplied handler and options
+///
+/// Only the options for reading tokens apply. The depth and number of nodes are only limited when building a DOM.
+pub fn parse_with_options<H: ParseHandler>(handler: &mut H, raw: &str, options: &ParseOptions) {
+    let mut parser = Parser::with_options(Reader::new(raw), options);
+    let mut control = ControlState::default();
+
+    while !control.stopped && parser.next_token() {
+        parser.dispatch(handler, &mut control);
+    }
+
+    handler.finish();
+}
+
+/// Splits HTML into tokens
+///
+/// The tokens are created with the same rules as the events of [`parse`], but without building
+/// any tree structure. Errors are collected in the iterator and can be read with [`errors`].
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{tokenize, Token};
+///
+/// let links: Vec<String> = tokenize("<p><a href=\"/1\">1</a> <a href=\"/2\">2</a></p>")
+///     .filter_map(|token| match token {
+///         Token::StartTag { name, attributes, .. } if name == "a" => {
+///             attributes.get_value("href").map(str::to_owned)
+///         }
+///         _ => None,
+///     })
+///     .collect();
+///
+/// assert_eq!(links, vec!["/1", "/2"]);
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`errors`]: struct.Tokenizer.html#method.errors
+pub fn tokenize(html: &str) -> Tokenizer<'_> {
+    Tokenizer {
+        parser: Parser::new(Reader::new(html)),
+        errors: Vec::new(),
+    }
+}
+
+/// Iterator over the tokens of HTML created by [`tokenize`]
+///
+/// [`tokenize`]: fn.tokenize.html
+pub struct Tokenizer<'a> {
+    parser: Parser<'a>,
+    errors: Vec<ParseError>,
+}
+
+impl Tokenizer<'_> {
+    /// Errors found in the input up to the last returned token
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        loop {
+            match self.parser.events.pop_front() {
+                Some(Event::Token(token)) => return Some(token),
+                Some(Event::Error(error)) => self.errors.push(error),
+                None if self.parser.next_token() => {}
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A parser that is fed with chunks of HTML
+///
+/// Tokens crossing the border between two chunks are buffered until they are complete,
+/// so the handler receives the same events as when parsing the whole input at once.
+/// An incomplete token is only read again after the buffered part of it doubled, which keeps
+/// large tokens like long scripts from being read over and over again.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use qd_html::parser::{Control, ParseHandler, StreamingParser, TagAttributes};
+/// use qd_html::dom::{Doctype, Span};
+///
+/// #[derive(Default)]
+/// struct LinkCounter {
+///     links: usize,
+/// }
+///
+/// impl ParseHandler for LinkCounter {
+///     fn text(&mut self, _text: Cow<str>, _span: Span) -> Control { Control::Continue }
+///     fn comment(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+///     fn cdata(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+///     fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+///     fn processing_instruction(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+///     fn element_end(&mut self, _name: &str, _span: Span) -> Control { Control::Continue }
+///
+///     fn element_start(&mut self, name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, _span: Span) -> Control {
+///         if name == "a" {
+///             self.links += 1;
+///         }
+///         Control::Continue
+///     }
+/// }
+///
+/// let mut parser = StreamingParser::new(LinkCounter::default());
+///
+/// parser.feed("<p><a href=\"/1\">1</a><");
+/// parser.feed("a href=\"/2\">2</a></p>");
+///
+/// let handler = parser.finish();
+/// assert_eq!(handler.links, 2);
+/// ```
+pub struct StreamingParser<H: ParseHandler> {
+    handler: H,
+    /// Input not processed yet
+    buffer: String,
+    /// Position of the start of the buffer in the whole input
+    position: Position,
+    /// Length the buffer needs to have before an incomplete token is read again
+    retry_length: usize,
+    control: ControlState,
+    options: ParseOptions,
+}
+
+impl<H: ParseHandler> StreamingParser<H> {
+    pub fn new(handler: H) -> StreamingParser<H> {
+        StreamingParser::with_options(handler, &ParseOptions::default())
+    }
+
+    /// Creates a streaming parser with the supplied options
+    ///
+    /// Only the options for reading tokens apply like for [`parse_with_options`].
+    ///
+    /// [`parse_with_options`]: fn.parse_with_options.html
+    pub fn with_options(handler: H, options: &ParseOptions) -> StreamingParser<H> {
+        StreamingParser {
+            handler,
+            buffer: String::new(),
+            position: Position::default(),
+            retry_length: 0,
+            control: ControlState::default(),
+            options: options.clone(),
+        }
+    }
+
+    /// Parses the next chunk of the input
+    ///
+    /// The chunk is ignored if the handler stopped parsing.
+    pub fn feed(&mut self, chunk: &str) {
+        if self.control.stopped {
+            return;
+        }
+
+        self.buffer.push_str(chunk);
+        if self.buffer.len() >= self.retry_length {
+            self.process(false);
+        }
+    }
+
+    /// Tests if the handler stopped parsing by returning `Control::Stop`
+    pub fn is_stopped(&self) -> bool {
+        self.control.stopped
+    }
+
+    /// Parses the rest of the buffered input and returns the handler
+    pub fn finish(mut self) -> H {
+        self.process(true);
+        self.handler.finish();
+        self.handler
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    fn process(&mut self, last: bool) {
+        let mut parser = Parser::with_options(Reader::new_at(&self.buffer, self.position, !last), &self.options);
+
+        while !self.control.stopped {
+            let state = parser.reader.clone();
+
+            if !parser.next_token() {
+                break;
+            }
+
+            if parser.reader.is_incomplete() {
+                // Wait for more input to finish the token
+                parser.reader = state;
+                parser.events.clear();
+                break;
+            }
+
+            parser.dispatch(&mut self.handler, &mut self.control);
+        }
+
+        let position = parser.reader.position();
+        if self.control.stopped {
+            self.buffer.clear();
+        } else {
+            self.buffer.drain(..position.offset - self.position.offset);
+        }
+        self.position = position;
+
+        // The rest of the buffer is an incomplete token
+        self.retry_length = 2 * self.buffer.len();
+    }
+}
+
+/// Parse UTF-8 encoded HTML from a reader with the supplied handler
+///
+/// The input is read and parsed in chunks without loading it into memory at once.
+/// Reading stops early if the handler returns `Control::Stop`.
+/// Invalid UTF-8 results in an error of kind `InvalidData`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use qd_html::parser::{ParseHandler, parse_reader};
+/// # use std::borrow::Cow;
+/// # use qd_html::parser::{Control, TagAttributes};
+/// # use qd_html::dom::{Doctype, Span};
+/// # struct ExampleHandler;
+/// # impl ParseHandler for ExampleHandler {
+/// #     fn text(&mut self, _text: Cow<str>, _span: Span) -> Control { Control::Continue }
+/// #     fn comment(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+/// #     fn cdata(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+/// #     fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+/// #     fn processing_instruction(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+/// #     fn element_start(&mut self, _name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, _span: Span) -> Control { Control::Continue }
+/// #     fn element_end(&mut self, _name: &str, _span: Span) -> Control { Control::Continue }
+/// # }
+///
+/// fn parse_file(path: &str) -> std::io::Result<()> {
+///     let mut handler = ExampleHandler;
+///     parse_reader(&mut handler, BufReader::new(File::open(path)?))
+/// }
+/// ```
+pub fn parse_reader<H: ParseHandler, R: Read>(handler: &mut H, input: R) -> io::Result<()> {
+    parse_reader_with_options(handler, input, &ParseOptions::default())
+}
+
+/// Parse UTF-8 encoded HTML from a reader with the supplied handler and options
+///
+/// Only the options for reading tokens apply like for [`parse_with_options`].
+///
+/// [`parse_with_options`]: fn.parse_with_options.html
+pub fn parse_reader_with_options<H: ParseHandler, R: Read>(handler: &mut H, mut input: R, options: &ParseOptions) -> io::Result<()> {
+    let mut parser = StreamingParser::with_options(handler, options);
+    let mut chunk = [0u8; 8192];
+    let mut pending: Vec<u8> = Vec::new();
+
+    while !parser.is_stopped() {
+        let len = match input.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+
+        pending.extend_from_slice(&chunk[..len]);
+
+        // A chunk might end in the middle of a multi byte char that is completed by the next one
+        let valid = match std::str::from_utf8(&pending) {
+            Ok(text) => {
+                parser.feed(text);
+                pending.len()
+            }
+            Err(err) if err.error_len().is_none() => {
+                let valid = err.valid_up_to();
+                parser.feed(std::str::from_utf8(&pending[..valid]).unwrap());
+                valid
+            }
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+
+        pending.drain(..valid);
+    }
+
+    if !pending.is_empty() && !parser.is_stopped() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Incomplete UTF-8 sequence at end of input"));
+    }
+
+    parser.finish();
+
+    Ok(())
 }
 
 /// Parse HTML and build a simplified DOM tree
@@ -415,18 +1474,138 @@ pub fn parse<H: ParseHandler>(handler: &mut H, raw: &str) {
 ///
 /// ```
 pub fn parse_to_dom(raw: &str) -> Document {
-    let mut handler = DomParseHandler {
-        stack: Vec::new(),
-        current: Element::new("#document"),
-        doctype: None,
-        is_xml: false,
-    };
+    parse_to_dom_with_options(raw, &ParseOptions::default())
+}
+
+/// Parse HTML and build a simplified DOM tree using the supplied options
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{parse_to_dom_with_options, ParseOptions};
+/// use qd_html::dom::Node;
+///
+/// let options = ParseOptions {
+///     spans: true,
+///     ..ParseOptions::default()
+/// };
+///
+/// let document = parse_to_dom_with_options("<p>\n<b>bold</b></p>", &options);
+///
+/// if let Node::Element(ref p) = document.element.nodes[0] {
+///     if let Node::Element(ref b) = p.nodes[1] {
+///         let span = b.span.unwrap();
+///         assert_eq!(span.start.line, 2);
+///         assert_eq!(span.start.offset, 4);
+///         assert_eq!(span.end.offset, 15);
+///     }
+/// }
+/// ```
+pub fn parse_to_dom_with_options(raw: &str, options: &ParseOptions) -> Document {
+    parse_to_dom_with_errors(raw, options).0
+}
+
+/// Parse HTML and build a simplified DOM tree and return all errors found in the HTML
+///
+/// The errors are sorted by their position in the input.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{parse_to_dom_with_errors, ParseOptions};
+///
+/// let (document, errors) = parse_to_dom_with_errors("<div>&ZZZ;</span>", &ParseOptions::default());
+///
+/// let codes: Vec<&str> = errors.iter().map(|e| e.code()).collect();
+/// assert_eq!(codes, vec!["unclosed-element", "invalid-character-reference", "unexpected-end-tag"]);
+/// ```
+pub fn parse_to_dom_with_errors(raw: &str, options: &ParseOptions) -> (Document, Vec<ParseError>) {
+    let mut handler = DomParseHandler::new(options);
+
+    parse_with_options(&mut handler, raw, options);
+
+    handler.into_document()
+}
 
-    parse(&mut handler, raw);
+/// Parse a HTML fragment as the content of the `context` element
+///
+/// This works like setting `innerHTML`: The content of `title` and `textarea` is read as text
+/// with character references and the content of raw text elements like `script` is kept as it is.
+///
+/// The table rules only apply if the context is a table element like `table`, `tbody`, `tr` or `td`:
+/// Missing sections and rows are inserted and table elements that can't be added to the context,
+/// like a `tr` in a `tr`, are ignored. In other contexts table elements are kept like any other
+/// element, while browsers would drop a stray `<td>` in a `div`.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::parse_fragment;
+/// use qd_html::dom::Node;
+///
+/// let nodes = parse_fragment("<td>a</td><td>b</td>", "tr");
+/// assert_eq!(nodes.len(), 2);
+///
+/// let nodes = parse_fragment("<b>&amp;</b>", "title");
+/// assert!(matches!(&nodes[0], Node::Text(text) if text.content == "<b>&</b>"));
+/// ```
+pub fn parse_fragment(html: &str, context: &str) -> Vec<Node> {
+    let context = context.to_ascii_lowercase();
 
-    Document {
-        doctype: handler.doctype,
-        is_xml: handler.is_xml,
-        element: handler.current,
+    if html.is_empty() {
+        Vec::new()
+    } else if is_rcdata_element(&context) {
+        let text = Reader::new(html).read_text(&[], false);
+        vec![Node::Text(Text::new(text))]
+    } else if is_raw_element(&context) {
+        vec![Node::Raw(Raw::new(html))]
+    } else {
+        let mut handler = DomParseHandler::new_fragment(&context);
+        parse(&mut handler, html);
+        handler.into_fragment()
     }
 }
+
+/// Parse HTML bytes in an unknown encoding and build a simplified DOM tree
+///
+/// See the [`encoding`] module for how the encoding is detected.
+/// The name of the detected encoding is stored in `Document::encoding`.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::parse_bytes_to_dom;
+///
+/// let document = parse_bytes_to_dom(b"<meta charset=\"iso-8859-1\"><p>Gr\xfc\xdfe</p>");
+///
+/// assert_eq!(document.encoding, Some("windows-1252"));
+/// ```
+///
+/// [`encoding`]: ../encoding/index.html
+pub fn parse_bytes_to_dom(raw: &[u8]) -> Document {
+    parse_bytes_to_dom_with_options(raw, &ParseOptions::default())
+}
+
+/// Parse HTML bytes in an unknown encoding and build a simplified DOM tree using the supplied options
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::parser::{parse_bytes_to_dom_with_options, ParseOptions};
+///
+/// let options = ParseOptions {
+///     encoding_hint: Some("shift_jis".to_owned()),
+///     ..ParseOptions::default()
+/// };
+///
+/// let document = parse_bytes_to_dom_with_options(b"<p>\x93\xfa\x96\x7b</p>", &options);
+///
+/// assert_eq!(document.encoding, Some("Shift_JIS"));
+/// ```
+pub fn parse_bytes_to_dom_with_options(raw: &[u8], options: &ParseOptions) -> Document {
+    let (html, encoding) = crate::encoding::decode(raw, options.encoding_hint.as_deref());
+
+    let mut document = parse_to_dom_with_options(&html, options);
+    document.encoding = Some(encoding);
+    document
+}
@@ -1,22 +1,154 @@
 
-use std::str::Chars;
+use std::borrow::Cow;
+
+use memchr::{memchr, memchr2, memchr3, memchr_iter, memrchr};
+use memchr::memmem;
+
+use crate::dom::{Position, Span};
+use crate::error::ParseError;
 use crate::utils::*;
 
+/// Finds the first of up to 3 ASCII bytes with memchr and falls back to a simple search for more
+fn find_any(bytes: &[u8], needles: &[u8]) -> Option<usize> {
+    match *needles {
+        [] => None,
+        [a] => memchr(a, bytes),
+        [a, b] => memchr2(a, b, bytes),
+        [a, b, c] => memchr3(a, b, c, bytes),
+        _ => bytes.iter().position(|b| needles.contains(b)),
+    }
+}
+
+/// Tests if the input starts with a tag with the given name like `</script>`
+///
+/// The name is compared case-insensitively and has to be followed by whitespace, `/` or `>`.
+fn is_tag(input: &[u8], prefix: &[u8], name: &str) -> bool {
+    let end = prefix.len() + name.len();
+
+    input.starts_with(prefix)
+        && input.len() > end
+        && input[prefix.len()..end].eq_ignore_ascii_case(name.as_bytes())
+        && matches!(input[end], b'\t' | b'\n' | b'\x0C' | b'\r' | b' ' | b'/' | b'>')
+}
+
+/// Escape states of script content started by `<!--`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ScriptEscape {
+    None,
+    /// After `<!--`
+    Escaped,
+    /// After `<!--<script>`, where `</script>` doesn't end the script
+    Double,
+}
+
+/// Reads the input by byte offsets and returns slices of it where possible
+#[derive(Clone)]
 pub(crate) struct Reader<'a> {
-    iter: Chars<'a>,
-    pushback: Option<char>,
+    input: &'a str,
+    /// Offset of the start of `input` in the whole input
+    base: usize,
+    position: Position,
+    prev_position: Position,
+    /// Errors found while reading
+    pub errors: Vec<ParseError>,
+    /// Set if more input might follow after the end of `input`
+    partial: bool,
+    /// Set if the reader tried to read behind the end of `input`
+    end_reached: bool,
+    /// Only decode the XML entities besides numeric character references
+    pub xml_entities: bool,
 }
 
 impl<'a> Reader<'a> {
-    pub fn new(chars: &'a str) -> Reader {
+    pub fn new(chars: &'a str) -> Reader<'a> {
+        Reader::new_at(chars, Position::default(), false)
+    }
+
+    /// Creates a reader for a part of the input starting at `position`
+    ///
+    /// If `partial` is set more input might follow after the end of `chars`.
+    pub fn new_at(chars: &'a str, position: Position, partial: bool) -> Reader<'a> {
         Reader {
-            iter: chars.chars(),
-            pushback: None,
+            input: chars,
+            base: position.offset,
+            position,
+            prev_position: position,
+            errors: Vec::new(),
+            partial,
+            end_reached: false,
+            xml_entities: false,
+        }
+    }
+
+    /// Tests if the reader reached the end of a partial input
+    ///
+    /// In this case the result of the last read might change if more input is available.
+    pub fn is_incomplete(&self) -> bool {
+        self.partial && self.end_reached
+    }
+
+    /// Position of the next char to be read
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the input between two positions of this reader
+    pub fn slice(&self, start: Position, end: Position) -> &'a str {
+        &self.input[start.offset - self.base..end.offset - self.base]
+    }
+
+    /// Returns the input that wasn't read yet
+    fn rest(&self) -> &'a str {
+        &self.input[self.position.offset - self.base..]
+    }
+
+    /// Moves the position `length` bytes forward
+    fn advance(&mut self, length: usize) {
+        let skipped = &self.rest()[..length];
+        let bytes = skipped.as_bytes();
+
+        // The setup of memchr doesn't pay off for the short skips within tags
+        let last_newline = if length < 64 {
+            bytes.iter().rposition(|&b| b == b'\n')
+        } else {
+            memrchr(b'\n', bytes)
+        };
+
+        self.position.offset += length;
+        match last_newline {
+            Some(last) => {
+                self.position.line += memchr_iter(b'\n', &bytes[..=last]).count();
+                self.position.column = skipped[last + 1..].chars().count() + 1;
+            }
+            None => self.position.column += skipped.chars().count(),
         }
     }
 
+    /// Moves the position to the end of the input
+    fn advance_to_end(&mut self) {
+        self.advance(self.rest().len());
+        self.end_reached = true;
+    }
+
     pub fn next_char(&mut self) -> Option<char> {
-        self.pushback.take().or_else(|| self.iter.next())
+        let ch = match self.rest().chars().next() {
+            Some(ch) => ch,
+            None => {
+                self.end_reached = true;
+                return None;
+            }
+        };
+
+        self.prev_position = self.position;
+        self.position.offset += ch.len_utf8();
+        if ch == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+
+        Some(ch)
     }
 
     pub fn ignore_if_next(&mut self, ignored_ch: char) -> bool {
@@ -29,100 +161,298 @@ impl<'a> Reader<'a> {
         false
     }
 
+    /// Push back the last char returned by `next_char`
     pub fn push_back(&mut self, ch: char) {
-        self.pushback = Some(ch);
+        self.position = self.prev_position;
+        debug_assert_eq!(self.rest().chars().next(), Some(ch));
     }
 
+    /// Reads up to whitespace or one of the ASCII `end_chars`
+    pub fn get_until(&mut self, stop_on_ws: bool, end_chars: &[char]) -> &'a str {
+        let rest = self.rest();
+        let length = rest.bytes()
+            .position(|b| (stop_on_ws && is_html_whitespace(b as char)) || end_chars.contains(&(b as char)));
 
-    pub fn get_until(&mut self, stop_on_ws: bool, end_chars: &[char]) -> String {
-        let mut result = "".to_owned();
-        while let Some(ch) = self.next_char() {
-            if (stop_on_ws && is_html_whitespace(ch)) || end_chars.contains(&ch) {
-                self.push_back(ch);
-                break;
-            } else {
-                result.push(ch);
-            }
+        match length {
+            Some(length) => self.advance(length),
+            None => self.advance_to_end(),
         }
 
-        result
+        &rest[..length.unwrap_or(rest.len())]
     }
 
     pub fn skip_whitespace(&mut self) {
-        while let Some(ch) = self.next_char() {
-            if !is_html_whitespace(ch) {
-                self.push_back(ch);
-                break;
-            }
+        match self.rest().bytes().position(|b| !is_html_whitespace(b as char)) {
+            Some(length) => self.advance(length),
+            None => self.advance_to_end(),
         }
     }
 
-    pub fn read_text(&mut self, end: &[char]) -> String {
-        let mut content = String::new();
+    /// Reads text up to one of the `end` chars and decodes character references
+    ///
+    /// In attribute values legacy entities without a semicolon are kept if they are followed by an
+    /// alphanumeric char or `=`.
+    ///
+    /// The text borrows from the input unless a character reference was decoded.
+    pub fn read_text(&mut self, end: &[char], attribute: bool) -> Cow<'a, str> {
+        let start = self.position;
+        let mut decoded: Option<String> = None;
+
+        let mut needles = [b'&'; 8];
+        for (needle, ch) in needles.iter_mut().zip(end) {
+            *needle = *ch as u8;
+        }
+        let needles = &needles[..end.len().min(7) + 1];
+        debug_assert!(end.len() <= 7 && end.iter().all(char::is_ascii));
+
+        loop {
+            let rest = self.rest();
+            let length = find_any(rest.as_bytes(), needles);
 
-        while let Some(ch) = self.next_char() {
-            if end.contains(&ch) {
-                self.push_back(ch);
-                break;
-            } else if ch == '&' {
-                let start = content.len();
-                let mut finished = false;
+            if let Some(decoded) = decoded.as_mut() {
+                decoded.push_str(&rest[..length.unwrap_or(rest.len())]);
+            }
 
-                content.push(ch);
+            match length {
+                Some(length) if rest.as_bytes()[length] == b'&' => {
+                    self.advance(length);
+                    let reference_start = self.position;
+                    self.advance(1);
 
-                while let Some(ch) = self.next_char() {
-                    if end.contains(&ch) || ch == ' ' {
-                        self.push_back(ch);
-                        break;
-                    } else if ch == ';' {
-                        finished = true;
-                        break;
-                    } else {
-                        content.push(ch);
+                    let mut text = String::new();
+                    self.read_char_reference(&mut text, reference_start, attribute);
+
+                    let original = self.slice(reference_start, self.position);
+                    if decoded.is_some() || text != original {
+                        decoded.get_or_insert_with(|| self.slice(start, reference_start).to_owned()).push_str(&text);
                     }
                 }
+                Some(length) => {
+                    self.advance(length);
+                    break;
+                }
+                None => {
+                    self.advance_to_end();
+                    break;
+                }
+            }
+        }
+
+        match decoded {
+            Some(decoded) => Cow::Owned(decoded),
+            None => Cow::Borrowed(self.slice(start, self.position)),
+        }
+    }
+
+    /// Reads the character reference following a `&` and adds its text to `content`
+    ///
+    /// If there is no valid reference only the `&` is added.
+    fn read_char_reference(&mut self, content: &mut String, start: Position, attribute: bool) {
+        let rest = self.rest();
+
+        // The reference might continue in the next part of the input
+        let name_length = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '#').unwrap_or(rest.len());
+        if name_length == rest.len() {
+            self.end_reached = true;
+        }
+
+        let decoded = if let Some(number) = rest.strip_prefix('#') {
+            let hex = number.starts_with(['x', 'X']);
+            let prefix = if hex { 1 } else { 0 };
+            let digits = number[prefix..]
+                .find(|c: char| !c.is_digit(if hex { 16 } else { 10 }))
+                .unwrap_or(number.len() - prefix);
+
+            let mut length = 1 + prefix + digits;
+            if rest[length..].starts_with(';') {
+                length += 1;
+            }
+
+            let reference = format!("&{}", &rest[..length]);
+            // Numeric references never contain a newline
+            let mut end = self.position;
+            end.offset += length;
+            end.column += length;
+
+            match decode_numeric_entity_checked(rest[..length].trim_end_matches(';'), &reference, Span::new(start, end)) {
+                Some((ch, error)) if digits > 0 => {
+                    self.errors.extend(error);
+                    Some((length, ch.to_string()))
+                }
+                _ => None,
+            }
+        } else if self.xml_entities {
+            match_xml_entity(rest).map(|(length, value)| (length, value.to_owned()))
+        } else {
+            match match_entity(rest) {
+                Some((length, value)) => {
+                    let legacy = !rest[..length].ends_with(';');
+                    let next = rest[length..].chars().next();
 
-                if finished {
-                    if let Some(ch) = decode_entity(&content[start + 1..]) {
-                        content.truncate(start);
-                        content.push(ch);
-                    } else {
-                        content.push(';');
+                    if attribute && legacy && next.is_some_and(|c| c.is_ascii_alphanumeric() || c == '=') {
+                        content.push('&');
+                        return;
                     }
+
+                    Some((length, value.to_owned()))
                 }
-            } else {
-                content.push(ch);
+                None => None,
+            }
+        };
+
+        match decoded {
+            Some((length, value)) => {
+                self.advance(length);
+                content.push_str(&value);
             }
+            None if name_length > 0 && rest[name_length..].starts_with(';') => {
+                let reference = format!("&{};", &rest[..name_length]);
+                self.advance(name_length + 1);
+                content.push_str(&reference);
+                self.errors.push(ParseError::InvalidCharacterReference {
+                    reference,
+                    span: Span::new(start, self.position),
+                });
+            }
+            None => content.push('&'),
         }
+    }
+
+    /// Reads everything up to the `end` marker
+    ///
+    /// The returned flag is false if the end of input was reached without finding the marker.
+    pub fn read_raw(&mut self, end: &str) -> (&'a str, bool) {
+        let rest = self.rest();
 
-        content
+        match memmem::find(rest.as_bytes(), end.as_bytes()) {
+            Some(length) => {
+                self.advance(length + end.len());
+                (&rest[..length], true)
+            }
+            None => {
+                self.advance_to_end();
+                (rest, false)
+            }
+        }
     }
 
-    pub fn read_raw(&mut self, end: &str) -> String {
-        let mut content = String::new();
+    /// Reads the content of a raw text or RCDATA element up to the start of its end tag
+    ///
+    /// The end tag name is matched case-insensitively and must be followed by whitespace, `/` or `>`.
+    /// After the content the position is behind the end tag name. In scripts an end tag inside of
+    /// `<!--<script>` and `-->` doesn't end the element like in browsers.
+    ///
+    /// The returned flag is false if the end of input was reached without finding the end tag.
+    pub fn read_raw_text(&mut self, name: &str, script: bool) -> (&'a str, bool) {
+        let rest = self.rest();
+        let bytes = rest.as_bytes();
+        let mut escape = ScriptEscape::None;
+        let mut offset = 0;
+
+        loop {
+            let found = if script {
+                memchr2(b'<', b'-', &bytes[offset..])
+            } else {
+                memchr(b'<', &bytes[offset..])
+            };
+
+            let start = match found {
+                Some(found) => offset + found,
+                None => {
+                    self.advance_to_end();
+                    return (rest, false);
+                }
+            };
+
+            let tail = &bytes[start..];
+            offset = start + 1;
 
-        while let Some(ch) = self.next_char() {
-            content.push(ch);
-            if content.ends_with(end) {
-                let len = content.len();
-                content.truncate(len - end.len());
-                break;
+            if tail[0] == b'-' {
+                if escape != ScriptEscape::None && tail.starts_with(b"-->") {
+                    escape = ScriptEscape::None;
+                    offset = start + 3;
+                }
+            } else if is_tag(tail, b"</", name) {
+                if escape != ScriptEscape::Double {
+                    self.advance(start + 2 + name.len());
+                    return (&rest[..start], true);
+                }
+                escape = ScriptEscape::Escaped;
+            } else if script && escape == ScriptEscape::None && tail.starts_with(b"<!--") {
+                // The dashes might be part of `-->` already
+                escape = ScriptEscape::Escaped;
+                offset = start + 2;
+            } else if escape == ScriptEscape::Escaped && is_tag(tail, b"<", "script") {
+                escape = ScriptEscape::Double;
             }
         }
+    }
+
+    /// Reads `prefix` if the input continues with it
+    ///
+    /// If `ignore_case` is set ASCII letters are compared case-insensitively.
+    pub fn read_prefix(&mut self, prefix: &str, ignore_case: bool) -> bool {
+        let rest = self.rest().as_bytes();
+        let length = prefix.len().min(rest.len());
+        let (start, prefix_start) = (&rest[..length], &prefix.as_bytes()[..length]);
+
+        if !(start == prefix_start || (ignore_case && start.eq_ignore_ascii_case(prefix_start))) {
+            return false;
+        }
+
+        // The prefix might continue in the next part of the input
+        if length < prefix.len() {
+            self.end_reached = true;
+            return false;
+        }
 
-        content
+        self.advance(length);
+        true
     }
 
-    pub fn read_raw_count(&mut self, count: usize) -> String {
-        let mut content = String::new();
+    /// Reads a comment up to `-->` or `--!>`
+    ///
+    /// Returns the content and the end marker, which is `None` if the end of input was reached first.
+    pub fn read_comment(&mut self) -> (&'a str, Option<&'static str>) {
+        let rest = self.rest();
+        let mut offset = 0;
 
-        for _ in 0..count {
-            match self.next_char() {
-                Some(ch) => content.push(ch),
-                None => break,
+        while let Some(found) = memmem::find(&rest.as_bytes()[offset..], b"--") {
+            let start = offset + found;
+            for end in ["-->", "--!>"] {
+                if rest[start..].starts_with(end) {
+                    self.advance(start + end.len());
+                    return (&rest[..start], Some(end));
+                }
             }
+            offset = start + 1;
         }
 
-        content
+        self.advance_to_end();
+        (rest, None)
+    }
+
+    /// Reads everything up to the end of input
+    pub fn read_to_end(&mut self) -> &'a str {
+        let rest = self.rest();
+        self.advance_to_end();
+        rest
+    }
+
+    /// Reads up to `count` chars
+    pub fn read_raw_count(&mut self, count: usize) -> &'a str {
+        let rest = self.rest();
+        let length = match rest.char_indices().nth(count) {
+            Some((length, _)) => length,
+            None => {
+                if rest.chars().count() < count {
+                    self.end_reached = true;
+                }
+                rest.len()
+            }
+        };
+
+        self.advance(length);
+        &rest[..length]
     }
 }
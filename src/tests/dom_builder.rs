@@ -0,0 +1,603 @@
+use crate::dom::*;
+use crate::parser::*;
+use crate::writer::write_element;
+use crate::utils::cleanup;
+
+fn error_codes(html: &str) -> Vec<&'static str> {
+    let (_, errors) = parse_to_dom_with_errors(html, &ParseOptions::default());
+    errors.iter().map(|e| e.code()).collect()
+}
+
+#[test]
+fn implied_end_li() {
+    assert_eq!(cleanup("<ul><li>a<li>b</ul>"), "<ul><li>a</li><li>b</li></ul>");
+}
+
+#[test]
+fn implied_end_nested_li() {
+    assert_eq!(
+        cleanup("<ul><li>a<ul><li>b</ul><li>c</ul>"),
+        "<ul><li>a<ul><li>b</li></ul></li><li>c</li></ul>"
+    );
+}
+
+#[test]
+fn implied_end_li_with_inline_content() {
+    assert_eq!(cleanup("<ul><li><span>a<li>b</ul>"), "<ul><li><span>a</span></li><li>b</li></ul>");
+    assert_eq!(error_codes("<ul><li><span>a<li>b</ul>"), vec!["unclosed-element"]);
+}
+
+#[test]
+fn implied_end_li_in_div() {
+    assert_eq!(cleanup("<ul><li><div>a<li>b</ul>"), "<ul><li><div>a</div></li><li>b</li></ul>");
+}
+
+#[test]
+fn implied_end_p() {
+    assert_eq!(cleanup("<p>one<p>two"), "<p>one</p><p>two</p>");
+    assert!(error_codes("<p>one<p>two").is_empty());
+}
+
+#[test]
+fn implied_end_p_by_block() {
+    assert_eq!(cleanup("<p>one<div>two</div>"), "<p>one</p><div>two</div>");
+    assert_eq!(cleanup("<p>one<ul><li>two</ul>"), "<p>one</p><ul><li>two</li></ul>");
+    assert_eq!(cleanup("<p>one<h1>two</h1>"), "<p>one</p><h1>two</h1>");
+    assert_eq!(cleanup("<p>one<hr>two"), "<p>one</p><hr>two");
+}
+
+#[test]
+fn implied_end_p_with_inline_content() {
+    assert_eq!(cleanup("<p><span>one<p>two"), "<p><span>one</span></p><p>two</p>");
+}
+
+#[test]
+fn no_implied_end_p_by_inline() {
+    assert_eq!(cleanup("<p>one<span>two</span></p>"), "<p>one<span>two</span></p>");
+}
+
+#[test]
+fn no_implied_end_p_in_button() {
+    assert_eq!(cleanup("<p><button><div>a</div></button></p>"), "<p><button><div>a</div></button></p>");
+}
+
+#[test]
+fn implied_end_dt_dd() {
+    assert_eq!(
+        cleanup("<dl><dt>a<dd>b<dt>c<dd>d</dl>"),
+        "<dl><dt>a</dt><dd>b</dd><dt>c</dt><dd>d</dd></dl>"
+    );
+}
+
+#[test]
+fn implied_end_option() {
+    assert_eq!(
+        cleanup("<select><option>a<option>b<optgroup><option>c<optgroup><option>d</select>"),
+        "<select><option>a</option><option>b</option><optgroup><option>c</option></optgroup><optgroup><option>d</option></optgroup></select>"
+    );
+}
+
+#[test]
+fn implied_end_tr_td() {
+    assert_eq!(
+        cleanup("<table><tr><td>a<td>b<tr><th>c<td>d</table>"),
+        "<table><tbody><tr><td>a</td><td>b</td></tr><tr><th>c</th><td>d</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn implied_end_td_nested_table() {
+    assert_eq!(
+        cleanup("<table><tr><td><table><tr><td>a</table><td>b</table>"),
+        "<table><tbody><tr><td><table><tbody><tr><td>a</td></tr></tbody></table></td><td>b</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn implied_end_heading() {
+    assert_eq!(cleanup("<h1>a<h2>b"), "<h1>a</h1><h2>b</h2>");
+}
+
+#[test]
+fn implied_end_button() {
+    assert_eq!(cleanup("<button>a<button>b"), "<button>a</button><button>b</button>");
+}
+
+#[test]
+fn implied_end_ruby() {
+    assert_eq!(
+        cleanup("<ruby>a<rp>(<rt>b<rp>)</ruby>"),
+        "<ruby>a<rp>(</rp><rt>b</rt><rp>)</rp></ruby>"
+    );
+}
+
+#[test]
+fn stray_end_tag_is_ignored() {
+    assert_eq!(
+        cleanup("<div><p>one</span>two</p><p>three</p></div>"),
+        "<div><p>onetwo</p><p>three</p></div>"
+    );
+    assert_eq!(error_codes("<div><p>one</span>two</p></div>"), vec!["unexpected-end-tag"]);
+}
+
+#[test]
+fn stray_end_tag_does_not_cross_special_element() {
+    assert_eq!(cleanup("<span><div>one</span>two</div>"), "<span><div>onetwo</div></span>");
+    assert_eq!(error_codes("<span><div>one</span>two</div></span>"), vec!["unexpected-end-tag"]);
+}
+
+#[test]
+fn stray_block_end_tag_is_ignored() {
+    assert_eq!(
+        cleanup("<section><ul><li>one</div></li><li>two</li></ul></section>"),
+        "<section><ul><li>one</li><li>two</li></ul></section>"
+    );
+}
+
+#[test]
+fn end_tag_closes_inline_elements() {
+    assert_eq!(cleanup("<div><span><q>one</div>two"), "<div><span><q>one</q></span></div>two");
+    assert_eq!(error_codes("<div><span><q>one</div>two"), vec!["unclosed-element", "unclosed-element"]);
+}
+
+#[test]
+fn end_li_out_of_scope() {
+    assert_eq!(cleanup("<li>one<ul><li>two</li></li></ul>"), "<li>one<ul><li>two</li></ul></li>");
+    assert_eq!(error_codes("<ul><li>one</ul></li>"), vec!["unexpected-end-tag"]);
+}
+
+#[test]
+fn stray_end_p_creates_paragraph() {
+    assert_eq!(cleanup("one</p>two"), "one<p></p>two");
+    assert_eq!(error_codes("one</p>two"), vec!["unexpected-end-tag"]);
+}
+
+#[test]
+fn stray_end_br_creates_line_break() {
+    assert_eq!(cleanup("one</br>two"), "one<br>two");
+}
+
+#[test]
+fn end_heading_closes_other_heading() {
+    assert_eq!(cleanup("<h1>one</h2>two"), "<h1>one</h1>two");
+    assert_eq!(error_codes("<h1>one</h2>two"), vec!["unclosed-element"]);
+    assert_eq!(error_codes("one</h2>"), vec!["unexpected-end-tag"]);
+}
+
+#[test]
+fn misnested_formatting_elements() {
+    assert_eq!(cleanup("<b><i>text</b> more</i>"), "<b><i>text</i></b><i> more</i>");
+    assert_eq!(error_codes("<b><i>text</b> more</i>"), vec!["misnested-element"]);
+}
+
+#[test]
+fn formatting_element_around_block() {
+    assert_eq!(cleanup("<b>one<p>two</b>three</p>"), "<b>one</b><p><b>two</b>three</p>");
+    assert_eq!(error_codes("<b>one<p>two</b>three</p>"), vec!["misnested-element"]);
+}
+
+#[test]
+fn formatting_elements_reopened_in_block() {
+    assert_eq!(
+        cleanup("<b>1<i>2<span>3<div>4</b>5</div>"),
+        "<b>1<i>2<span>3</span></i></b><i><div><b>4</b>5</div></i>"
+    );
+}
+
+#[test]
+fn link_around_block() {
+    assert_eq!(
+        cleanup("<a href=\"x\">one<div>two</a>three</div>"),
+        "<a href=\"x\">one</a><div><a href=\"x\">two</a>three</div>"
+    );
+}
+
+#[test]
+fn formatting_element_reopened_after_paragraph() {
+    assert_eq!(cleanup("<p><b>one</p>two"), "<p><b>one</b></p><b>two</b>");
+    assert_eq!(cleanup("<p><b>one</b></p>two"), "<p><b>one</b></p>two");
+}
+
+#[test]
+fn formatting_elements_reopened_at_most_three_times() {
+    assert_eq!(
+        cleanup("<p><b><b><b><b>one</p>two"),
+        "<p><b><b><b><b>one</b></b></b></b></p><b><b><b>two</b></b></b>"
+    );
+}
+
+#[test]
+fn formatting_elements_with_other_attributes_reopened() {
+    assert_eq!(
+        cleanup("<p><b x=1><b x=2><b x=1><b x=1><b x=1>one</p>two"),
+        "<p><b x=\"1\"><b x=\"2\"><b x=\"1\"><b x=\"1\"><b x=\"1\">one</b></b></b></b></b></p><b x=\"2\"><b x=\"1\"><b x=\"1\"><b x=\"1\">two</b></b></b></b>"
+    );
+}
+
+#[test]
+fn formatting_element_not_reopened_outside_cell() {
+    assert_eq!(
+        cleanup("<table><tr><td><b>one</td><td>two</td></tr></table>"),
+        "<table><tbody><tr><td><b>one</b></td><td>two</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn nested_link_closes_link() {
+    assert_eq!(cleanup("<a>one<a>two</a>"), "<a>one</a><a>two</a>");
+    assert_eq!(error_codes("<a>one<a>two</a>"), vec!["unclosed-element"]);
+}
+
+#[test]
+fn formatting_end_tag_without_open_element() {
+    assert_eq!(cleanup("one</b>two"), "onetwo");
+    assert_eq!(error_codes("one</b>two"), vec!["unexpected-end-tag"]);
+}
+
+fn parse_implied(html: &str) -> String {
+    let options = ParseOptions {
+        implied_elements: true,
+        ..ParseOptions::default()
+    };
+    crate::writer::write(&parse_to_dom_with_options(html, &options))
+}
+
+#[test]
+fn implied_elements() {
+    assert_eq!(
+        parse_implied("<title>x</title><p>hi"),
+        "<html><head><title>x</title></head><body><p>hi</p></body></html>"
+    );
+}
+
+#[test]
+fn implied_elements_empty_document() {
+    assert_eq!(parse_implied(""), "<html><head></head><body></body></html>");
+}
+
+#[test]
+fn implied_elements_not_inserted_by_default() {
+    assert_eq!(cleanup("<title>x</title><p>hi"), "<title>x</title><p>hi</p>");
+}
+
+#[test]
+fn implied_elements_existing_structure() {
+    let html = "<!DOCTYPE html><html><head><meta charset=\"utf-8\"></head><body><p>x</p></body></html>";
+    assert_eq!(parse_implied(html), html);
+}
+
+#[test]
+fn implied_elements_head_content() {
+    assert_eq!(
+        parse_implied("<meta charset=\"utf-8\"><link rel=\"x\"><style>p {}</style><script>x()</script><p>a<script>y()</script>"),
+        "<html><head><meta charset=\"utf-8\"><link rel=\"x\"><style>p {}</style><script>x()</script></head>\
+         <body><p>a<script>y()</script></p></body></html>"
+    );
+}
+
+#[test]
+fn implied_elements_whitespace() {
+    assert_eq!(
+        parse_implied("\n<!--c-->\n<title>t</title>\n<p>x"),
+        "<!--c--><html><head><title>t</title>\n</head><body><p>x</p></body></html>"
+    );
+}
+
+#[test]
+fn implied_elements_text_starts_body() {
+    assert_eq!(
+        parse_implied("<title>t</title>text<meta charset=\"utf-8\">"),
+        "<html><head><title>t</title></head><body>text<meta charset=\"utf-8\"></body></html>"
+    );
+}
+
+#[test]
+fn implied_elements_head_element_after_head() {
+    assert_eq!(
+        parse_implied("<head></head><link rel=\"x\"><p>y"),
+        "<html><head><link rel=\"x\"></head><body><p>y</p></body></html>"
+    );
+}
+
+#[test]
+fn implied_elements_merge_attributes() {
+    let options = ParseOptions {
+        implied_elements: true,
+        ..ParseOptions::default()
+    };
+    let (_, errors) = parse_to_dom_with_errors("<body class=\"a\"><body id=\"b\">x", &options);
+
+    assert_eq!(
+        parse_implied("<body class=\"a\"><body class=\"c\" id=\"b\">x"),
+        "<html><head></head><body class=\"a\" id=\"b\">x</body></html>"
+    );
+    assert_eq!(errors.iter().map(|e| e.code()).collect::<Vec<_>>(), vec!["unexpected-start-tag"]);
+}
+
+#[test]
+fn implied_elements_content_after_body() {
+    assert_eq!(
+        parse_implied("<p>x</body><!--a--></html><!--b-->"),
+        "<html><head></head><body><p>x</p></body><!--a--></html><!--b-->"
+    );
+    assert_eq!(
+        parse_implied("<p>x</body></html>y"),
+        "<html><head></head><body><p>xy</p></body></html>"
+    );
+}
+
+#[test]
+fn implied_tbody_and_tr() {
+    assert_eq!(cleanup("<table><tr><td>x</table>"), "<table><tbody><tr><td>x</td></tr></tbody></table>");
+    assert_eq!(cleanup("<table><td>x</table>"), "<table><tbody><tr><td>x</td></tr></tbody></table>");
+    assert_eq!(cleanup("<table><tbody><td>x</table>"), "<table><tbody><tr><td>x</td></tr></tbody></table>");
+    assert!(error_codes("<table><tr><td>x</td></tr></table>").is_empty());
+}
+
+#[test]
+fn implied_colgroup() {
+    assert_eq!(
+        cleanup("<table><col><tr><td>x</table>"),
+        "<table><colgroup><col></colgroup><tbody><tr><td>x</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn table_sections() {
+    assert_eq!(
+        cleanup("<table><caption>c<thead><tr><th>h<tbody><tr><td>a<tfoot><tr><td>f</table>"),
+        "<table><caption>c</caption><thead><tr><th>h</th></tr></thead><tbody><tr><td>a</td></tr></tbody>\
+         <tfoot><tr><td>f</td></tr></tfoot></table>"
+    );
+}
+
+#[test]
+fn foster_parent_text() {
+    assert_eq!(
+        cleanup("<table>a<tr><td>b</td></tr>c</table>"),
+        "ac<table><tbody><tr><td>b</td></tr></tbody></table>"
+    );
+    assert_eq!(
+        error_codes("<table>a<tr><td>b</td></tr>c</table>"),
+        vec!["misplaced-table-content", "misplaced-table-content"]
+    );
+}
+
+#[test]
+fn table_whitespace_is_kept() {
+    assert_eq!(
+        cleanup("<table>\n<tr>\n<td>a</td>\n</tr>\n</table>"),
+        "<table>\n<tbody><tr>\n<td>a</td>\n</tr>\n</tbody></table>"
+    );
+}
+
+#[test]
+fn foster_parent_elements() {
+    assert_eq!(
+        cleanup("<div><table><p>x</p><tr><td>y</table></div>"),
+        "<div><p>x</p><table><tbody><tr><td>y</td></tr></tbody></table></div>"
+    );
+    assert_eq!(
+        cleanup("<table><tr><td>a</td><img src=\"x\"></tr></table>"),
+        "<img src=\"x\"><table><tbody><tr><td>a</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn foster_parent_formatting_elements() {
+    assert_eq!(
+        cleanup("<p><b>x</p><table>y<tr><td>z</table>"),
+        "<p><b>x</b></p><b>y</b><table><tbody><tr><td>z</td></tr></tbody></table>"
+    );
+}
+
+#[test]
+fn table_in_table_closes_table() {
+    assert_eq!(cleanup("<table><tr><td>a</td><table>b"), "<table><tbody><tr><td>a</td></tr></tbody></table>b<table></table>");
+}
+
+#[test]
+fn stray_table_end_tags_are_ignored() {
+    assert_eq!(
+        cleanup("<table><tr><td>a</caption></col></td></tr></table>"),
+        "<table><tbody><tr><td>a</td></tr></tbody></table>"
+    );
+    assert_eq!(
+        error_codes("<table><tr><td>a</caption></col></td></tr></table>"),
+        vec!["unexpected-end-tag", "unexpected-end-tag"]
+    );
+}
+
+#[test]
+fn table_end_tag_closes_cell() {
+    assert_eq!(cleanup("<table><tr><td><div>a</table>b"), "<table><tbody><tr><td><div>a</div></td></tr></tbody></table>b");
+}
+
+#[test]
+fn table_elements_outside_of_table() {
+    assert_eq!(cleanup("<tr><td>a<td>b</tr>"), "<tr><td>a</td><td>b</td></tr>");
+}
+
+fn fragment(html: &str, context: &str) -> String {
+    let mut element = Element::new("#document");
+    element.nodes = parse_fragment(html, context);
+    write_element(&element)
+}
+
+#[test]
+fn fragment_in_div() {
+    assert_eq!(fragment("<p>a<p>b</div>c", "div"), "<p>a</p><p>bc</p>");
+    assert_eq!(fragment("<b>a</html>b", "DIV"), "<b>ab</b>");
+    assert_eq!(fragment("", "div"), "");
+}
+
+#[test]
+fn fragment_in_list_item() {
+    assert_eq!(fragment("a<li>b", "ul"), "a<li>b</li>");
+    assert_eq!(fragment("<b>a<li>b", "li"), "<b>a<li>b</li></b>");
+}
+
+#[test]
+fn fragment_in_table_row() {
+    assert_eq!(fragment("<td>a</td><td>b</td>", "tr"), "<td>a</td><td>b</td>");
+    assert_eq!(fragment("<td>a<td>b</tr>c", "tr"), "<td>a</td><td>bc</td>");
+}
+
+#[test]
+fn fragment_in_table_body() {
+    assert_eq!(fragment("<td>a", "tbody"), "<tr><td>a</td></tr>");
+}
+
+#[test]
+fn fragment_in_table() {
+    assert_eq!(fragment("<tr><td>a", "table"), "<tbody><tr><td>a</td></tr></tbody>");
+}
+
+#[test]
+fn fragment_in_table_cell() {
+    assert_eq!(fragment("<b>a</td><tr>b", "td"), "<b>ab</b>");
+}
+
+#[test]
+fn fragment_ignores_table_structure_without_open_element() {
+    assert_eq!(fragment("<col>a", "tr"), "a");
+    assert_eq!(fragment("<tr>a", "tr"), "a");
+    assert_eq!(fragment("<td>a</td><tbody><td>b", "tr"), "<td>a</td><td>b</td>");
+    assert_eq!(fragment("<tbody>a", "tbody"), "a");
+    assert_eq!(fragment("<caption>a", "thead"), "a");
+    assert_eq!(fragment("<tr><td>a<tfoot>b", "tfoot"), "<tr><td>a</td></tr>b");
+}
+
+#[test]
+fn fragment_in_every_table_context() {
+    let contexts = ["table", "caption", "colgroup", "tbody", "thead", "tfoot", "tr", "td", "th"];
+    let tags = ["table", "caption", "colgroup", "col", "tbody", "thead", "tfoot", "tr", "td", "th"];
+
+    for context in contexts.iter() {
+        for tag in tags.iter() {
+            // Every combination has to finish
+            parse_fragment(&format!("<{}>a", tag), context);
+            parse_fragment(&format!("</{}>a", tag), context);
+            parse_fragment(&format!("<{0}><{0}>a</{0}></{0}>", tag), context);
+        }
+    }
+}
+
+#[test]
+fn fragment_table_elements_outside_of_table() {
+    assert_eq!(fragment("<td>a</td>", "div"), "<td>a</td>");
+}
+
+#[test]
+fn fragment_in_rcdata_element() {
+    let nodes = parse_fragment("<b>&amp;</b>", "title");
+    assert!(matches!(&nodes[..], [Node::Text(text)] if text.content == "<b>&</b>"));
+
+    let nodes = parse_fragment("</textarea>&lt;", "textarea");
+    assert!(matches!(&nodes[..], [Node::Text(text)] if text.content == "</textarea><"));
+}
+
+#[test]
+fn fragment_in_raw_element() {
+    let nodes = parse_fragment("if (a < b) {}</script>", "script");
+    assert!(matches!(&nodes[..], [Node::Raw(raw)] if raw.content == "if (a < b) {}</script>"));
+
+    let nodes = parse_fragment("<p>&amp;", "plaintext");
+    assert!(matches!(&nodes[..], [Node::Raw(raw)] if raw.content == "<p>&amp;"));
+}
+
+fn limited(html: &str, limits: ParserLimits) -> (String, Vec<&'static str>) {
+    let options = ParseOptions { limits, ..ParseOptions::default() };
+    let (document, errors) = parse_to_dom_with_errors(html, &options);
+    (crate::writer::write(&document), errors.iter().map(|e| e.code()).collect())
+}
+
+#[test]
+fn limit_depth_of_deeply_nested_document() {
+    let limits = ParserLimits { max_depth: 100, ..ParserLimits::default() };
+    let (html, _) = limited(&"<div>".repeat(10_000), limits);
+    assert_eq!(html, format!("{}{}{}", "<div>".repeat(100), "</div><div>".repeat(9_900), "</div>".repeat(100)));
+}
+
+#[test]
+fn default_limits_stop_deep_nesting() {
+    fn depth(element: &Element) -> usize {
+        1 + element.nodes.iter().filter_map(|node| match node {
+            Node::Element(e) => Some(depth(e)),
+            _ => None,
+        }).max().unwrap_or(0)
+    }
+
+    let (document, errors) = parse_to_dom_with_errors(&"<div>".repeat(10_000), &ParseOptions::default());
+    assert_eq!(depth(&document.element), 513);
+    assert!(errors.iter().any(|e| e.code() == "nesting-too-deep"));
+}
+
+#[test]
+fn limit_depth() {
+    let limits = ParserLimits { max_depth: 2, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<div><p><b>a</b>b</p>c</div>", limits),
+        ("<div><p></p><b>a</b>b<p></p>c</div>".to_owned(), vec!["nesting-too-deep", "unexpected-end-tag"])
+    );
+}
+
+#[test]
+fn limit_depth_of_reopened_formatting_elements() {
+    let limits = ParserLimits { max_depth: 3, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<p><b><i>a</p><div><div>b", limits),
+        (
+            "<p><b><i>a</i></b></p><div><div><b></b><i>b</i></div></div>".to_owned(),
+            vec!["unclosed-element", "unclosed-element", "unclosed-element", "unclosed-element", "nesting-too-deep"]
+        )
+    );
+}
+
+#[test]
+fn limit_formatting_elements_to_reopen() {
+    let limits = ParserLimits { max_depth: 2, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<p><b x=1></p><p><b x=2></p><p><b x=3></p>x", limits),
+        (
+            "<p><b x=\"1\"></b></p><p><b x=\"1\"></b><b x=\"2\"></b></p><p><b x=\"2\"></b><b x=\"3\"></b></p><b x=\"3\">x</b>".to_owned(),
+            vec!["unclosed-element", "nesting-too-deep", "unclosed-element", "nesting-too-deep", "unclosed-element"]
+        )
+    );
+}
+
+#[test]
+fn limit_nodes() {
+    let limits = ParserLimits { max_nodes: 3, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<p>a<b>b</b>c</p>", limits),
+        ("<p>a<b></b></p>".to_owned(), vec!["unclosed-element", "too-many-nodes"])
+    );
+}
+
+#[test]
+fn limit_attributes() {
+    let limits = ParserLimits { max_attributes: 2, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<p a=1 b=2 a=3 c=4 d>x</p>", limits),
+        ("<p a=\"1\" b=\"2\">x</p>".to_owned(), vec!["too-many-attributes", "duplicate-attribute"])
+    );
+}
+
+#[test]
+fn limit_token_length() {
+    let limits = ParserLimits { max_token_length: 6, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<p title=\"abcdefgh\">ab&auml;cdefg<!--comment--><script>var a = 1;</script>", limits),
+        ("<p title=\"abcdef\">abäcd<!--commen--><script>var a </script></p>".to_owned(), vec!["token-too-long"; 4])
+    );
+}
+
+#[test]
+fn limit_length_of_names() {
+    let limits = ParserLimits { max_token_length: 4, ..ParserLimits::default() };
+    assert_eq!(
+        limited("<section datax=1 datay=2>a</section>", limits),
+        ("<sect data=\"1\">a</sect>".to_owned(), vec!["token-too-long", "token-too-long", "token-too-long", "duplicate-attribute", "token-too-long"])
+    );
+}
@@ -0,0 +1,74 @@
+use crate::encoding::*;
+
+#[test]
+fn bom() {
+    assert_eq!(detect_encoding(b"\xef\xbb\xbf<meta charset=latin1>", None), "UTF-8");
+    assert_eq!(detect_encoding(b"\xfe\xff\0<", Some("latin1")), "UTF-16BE");
+    assert_eq!(detect_encoding(b"\xff\xfe<\0", None), "UTF-16LE");
+}
+
+#[test]
+fn hint() {
+    assert_eq!(detect_encoding(b"<meta charset=utf-8>", Some("latin1")), "windows-1252");
+    assert_eq!(detect_encoding(b"<p>", Some(" Shift_JIS ")), "Shift_JIS");
+}
+
+#[test]
+fn unknown_hint() {
+    assert_eq!(detect_encoding(b"<meta charset=koi8-r>", Some("not an encoding")), "KOI8-R");
+}
+
+#[test]
+fn meta_charset() {
+    assert_eq!(detect_encoding(b"<html><head><meta charset=\"iso-8859-2\">", None), "ISO-8859-2");
+    assert_eq!(detect_encoding(b"<META CHARSET='EUC-JP'/>", None), "EUC-JP");
+    assert_eq!(detect_encoding(b"<meta charset=gbk>", None), "GBK");
+}
+
+#[test]
+fn meta_http_equiv() {
+    let html = b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=iso-8859-15\">";
+    assert_eq!(detect_encoding(html, None), "ISO-8859-15");
+
+    let html = b"<meta content='text/html;charset=\"big5\"' http-equiv=content-type>";
+    assert_eq!(detect_encoding(html, None), "Big5");
+}
+
+#[test]
+fn meta_content_without_http_equiv() {
+    assert_eq!(detect_encoding(b"<meta content=\"text/html; charset=koi8-r\">", None), "UTF-8");
+}
+
+#[test]
+fn meta_utf16_is_utf8() {
+    assert_eq!(detect_encoding(b"<meta charset=utf-16>\xe4", None), "UTF-8");
+}
+
+#[test]
+fn meta_in_comment() {
+    assert_eq!(detect_encoding(b"<!-- <meta charset=koi8-r> --><p>", None), "UTF-8");
+}
+
+#[test]
+fn meta_in_attribute() {
+    assert_eq!(detect_encoding(b"<div title=\"<meta charset=koi8-r>\"><p>", None), "UTF-8");
+}
+
+#[test]
+fn meta_after_prescan_length() {
+    let mut html = vec![b' '; 1024];
+    html.extend_from_slice(b"<meta charset=koi8-r>");
+    assert_eq!(detect_encoding(&html, None), "UTF-8");
+}
+
+#[test]
+fn fallback() {
+    assert_eq!(detect_encoding("<p>äöü</p>".as_bytes(), None), "UTF-8");
+    assert_eq!(detect_encoding(b"<p>\xe4\xf6\xfc</p>", None), "windows-1252");
+}
+
+#[test]
+fn decode_bytes() {
+    assert_eq!(decode(b"\xef\xbb\xbf<p>", None), ("<p>".into(), "UTF-8"));
+    assert_eq!(decode(b"<p>\x80", None), ("<p>€".into(), "windows-1252"));
+}
@@ -1,5 +1,8 @@
+#![allow(clippy::needless_borrowed_reference)]
 
 mod dom;
+mod dom_builder;
+mod encoding;
 mod reader;
 mod writer;
 mod parser;
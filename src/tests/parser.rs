@@ -1,6 +1,16 @@
+use std::borrow::Cow;
 
-use crate::parser::parse_to_dom;
+use crate::parser::*;
 use crate::dom::*;
+use crate::error::ParseError;
+use crate::utils::cleanup;
+
+fn parse_with_spans(html: &str) -> Document {
+    parse_to_dom_with_options(html, &ParseOptions {
+        spans: true,
+        ..ParseOptions::default()
+    })
+}
 
 #[test]
 fn empty() {
@@ -16,7 +26,7 @@ fn empty() {
 #[test]
 fn doctype() {
     let doc = parse_to_dom("<!DOCTYPE html>");
-    assert_eq!(doc.doctype, Some("html".to_owned()));
+    assert_eq!(doc.doctype, Some(Doctype::new("html")));
     let e = doc.element;
 
     assert_eq!(e.name, "#document");
@@ -26,7 +36,7 @@ fn doctype() {
 
 #[test]
 fn bad_doctype() {
-    let doc = parse_to_dom("<!DOCTYPe html>Some");
+    let doc = parse_to_dom("<!DOCTYP html>Some");
     assert_eq!(doc.doctype, None);
     let e = doc.element;
 
@@ -35,7 +45,7 @@ fn bad_doctype() {
     assert_eq!(e.nodes.len(), 2);
 
     if let &Node::Text(ref text) = &e.nodes[0] {
-        assert_eq!(text.content, "<!DOCTYPe html>");
+        assert_eq!(text.content, "<!DOCTYP html>");
     } else {
         panic!("Node of wrong type");
     }
@@ -47,6 +57,61 @@ fn bad_doctype() {
     }
 }
 
+fn doctype_of(html: &str) -> Doctype {
+    parse_to_dom(html).doctype.expect("Doctype")
+}
+
+#[test]
+fn doctype_identifiers() {
+    let doctype = doctype_of("<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\"\n  'http://www.w3.org/TR/html4/strict.dtd'>");
+    assert_eq!(doctype.name, "html");
+    assert_eq!(doctype.public_id.as_deref(), Some("-//W3C//DTD HTML 4.01//EN"));
+    assert_eq!(doctype.system_id.as_deref(), Some("http://www.w3.org/TR/html4/strict.dtd"));
+    assert!(!doctype.force_quirks);
+
+    let doctype = doctype_of("<!DOCTYPE html system \"about:legacy-compat\">");
+    assert_eq!(doctype.public_id, None);
+    assert_eq!(doctype.system_id.as_deref(), Some("about:legacy-compat"));
+
+    let doctype = doctype_of("<!DOCTYPE html PUBLIC \"\">");
+    assert_eq!(doctype.public_id.as_deref(), Some(""));
+    assert_eq!(doctype.system_id, None);
+}
+
+#[test]
+fn malformed_doctypes_force_quirks() {
+    assert!(doctype_of("<!DOCTYPE>").force_quirks);
+    assert!(doctype_of("<!DOCTYPE html").force_quirks);
+    assert!(doctype_of("<!DOCTYPE html PUBLIC>").force_quirks);
+    assert!(doctype_of("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN>").force_quirks);
+    assert!(doctype_of("<!DOCTYPE html SYSTEM foo>").force_quirks);
+    assert!(doctype_of("<!DOCTYPE html whatever>").force_quirks);
+    assert!(!doctype_of("<!DOCTYPE html >").force_quirks);
+}
+
+#[test]
+fn quirks_mode() {
+    let mode = |html: &str| parse_to_dom(html).quirks_mode;
+
+    assert_eq!(mode("<!DOCTYPE html>"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!doctype html>"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!Doctype html>"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!DOCTYPE html SYSTEM \"about:legacy-compat\">"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">"), QuirksMode::NoQuirks);
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">"), QuirksMode::NoQuirks);
+
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">"), QuirksMode::LimitedQuirks);
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Transitional//EN\" \"http://www.w3.org/TR/html4/loose.dtd\">"), QuirksMode::LimitedQuirks);
+
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Transitional//EN\">"), QuirksMode::Quirks);
+    assert_eq!(mode("<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 3.2 Final//EN\">"), QuirksMode::Quirks);
+    assert_eq!(mode("<!DOCTYPE html PUBLIC \"html\">"), QuirksMode::Quirks);
+    assert_eq!(mode("<!DOCTYPE html SYSTEM \"http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd\">"), QuirksMode::Quirks);
+    assert_eq!(mode("<!DOCTYPE svg>"), QuirksMode::Quirks);
+    assert_eq!(mode("<!DOCTYPE>"), QuirksMode::Quirks);
+    assert_eq!(mode("<p>No doctype</p>"), QuirksMode::Quirks);
+}
+
 #[test]
 fn text_only() {
     let e = parse_to_dom("HELLO World!").element;
@@ -73,6 +138,39 @@ fn text_only_with_entities() {
     }
 }
 
+#[test]
+fn text_only_with_multi_code_point_entities() {
+    let e = parse_to_dom("&NotEqualTilde;&bne;").element;
+
+    if let Node::Text(text) = &e.nodes[0] {
+        assert_eq!(text.content, "\u{2242}\u{338}=\u{20e5}");
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn text_only_with_longest_legacy_entity() {
+    let e = parse_to_dom("&notin; &notit; &ampamp;").element;
+
+    if let Node::Text(text) = &e.nodes[0] {
+        assert_eq!(text.content, "\u{2209} \u{ac}it; &amp;");
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn attribute_with_legacy_entities() {
+    let e = parse_to_dom("<a href=\"?a=1&copy=2&amp;b=3&copy;&copy\"></a>").element;
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.get_attribute_value("href"), Some("?a=1&copy=2&b=3©©"));
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
 #[test]
 fn text_only_with_bad_entities() {
     let e = parse_to_dom("HELL&ZZZ; W&#xZZZ;!").element;
@@ -86,10 +184,10 @@ fn text_only_with_bad_entities() {
 
 #[test]
 fn text_only_with_unclosed_entities() {
-    let e = parse_to_dom("HELL&Ouml W&oumlrld!").element;
+    let e = parse_to_dom("HELL&Ouml W&oumlrld &euro &#65!").element;
 
     if let &Node::Text(ref text) = &e.nodes[0] {
-        assert_eq!(text.content, "HELL&Ouml W&oumlrld!");
+        assert_eq!(text.content, "HELLÖ Wörld &euro A!");
     } else {
         panic!("Node of wrong type");
     }
@@ -142,7 +240,7 @@ fn single_element_with_dublicate_attribute() {
     if let &Node::Element(ref e) = &e.nodes[0] {
         assert_eq!(e.name, "test");
         assert_eq!(e.attributes.len(), 1, "Bad attributes:{:?}", e);
-        assert_eq!(e.attributes.get_value("attr1"), Some("a2"));
+        assert_eq!(e.attributes.get_value("attr1"), Some("a1"));
     } else {
         panic!("Node of wrong type");
     }
@@ -159,8 +257,7 @@ fn single_element_with_dublicate_bool_attribute() {
     if let &Node::Element(ref e) = &e.nodes[0] {
         assert_eq!(e.name, "test");
         assert_eq!(e.attributes.len(), 1, "Bad attributes:{:?}", e);
-        assert!(e.attributes.contains("attr1"));
-        assert_eq!(e.attributes.get_value("attr1"), None);
+        assert_eq!(e.attributes.get_value("attr1"), Some("a1"));
     } else {
         panic!("Node of wrong type");
     }
@@ -333,6 +430,84 @@ fn single_style_mixedcase() {
     }
 }
 
+#[test]
+fn single_textarea() {
+    let e = parse_to_dom("<textarea>\n<b>x</b> &amp; &lt;</textarea>").element;
+
+    assert_eq!(e.nodes.len(), 1);
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.name, "textarea");
+        assert_eq!(e.nodes.len(), 1);
+        if let Node::Text(text) = &e.nodes[0] {
+            assert_eq!(text.content, "<b>x</b> & <");
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn single_title() {
+    let e = parse_to_dom("<title>a < b &amp;&amp; <i>c</i></title>").element;
+
+    assert_eq!(e.nodes.len(), 1);
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.name, "title");
+        assert_eq!(e.nodes.len(), 1);
+        if let Node::Text(text) = &e.nodes[0] {
+            assert_eq!(text.content, "a < b && <i>c</i>");
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn raw_text_elements() {
+    for name in &["xmp", "iframe", "noembed", "noframes"] {
+        let e = parse_to_dom(&format!("<{0}><b>&amp;</b></{0}>", name)).element;
+
+        assert_eq!(e.nodes.len(), 1);
+
+        if let Node::Element(e) = &e.nodes[0] {
+            assert_eq!(&e.name, name);
+            assert_eq!(e.nodes.len(), 1);
+            if let Node::Raw(raw) = &e.nodes[0] {
+                assert_eq!(raw.content, "<b>&amp;</b>");
+            } else {
+                panic!("Node of wrong type");
+            }
+        } else {
+            panic!("Node of wrong type");
+        }
+    }
+}
+
+#[test]
+fn plaintext() {
+    let e = parse_to_dom("<plaintext><b>x</b></plaintext>").element;
+
+    assert_eq!(e.nodes.len(), 1);
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.name, "plaintext");
+        assert_eq!(e.nodes.len(), 1);
+        if let Node::Raw(raw) = &e.nodes[0] {
+            assert_eq!(raw.content, "<b>x</b></plaintext>");
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
 #[test]
 fn single_element_mixing_case() {
     let e = parse_to_dom("<foo><teST></TEst><bar></bar></foo>").element;
@@ -550,17 +725,1145 @@ fn processing_instruction_with_multicode_point_grapheme() {
 #[test]
 fn is_xml() {
     let doc = parse_to_dom("<?xml version=\"1.0\"?>");
+    assert!(!doc.is_xml);
+
+    let doc = parse_to_dom_with_options("<?xml version=\"1.0\"?>", &ParseOptions { mode: ParseMode::Xml, ..ParseOptions::default() });
     assert!(doc.is_xml);
 }
 
+#[test]
+fn xml_declaration() {
+    let doc = parse_to_dom("<?xml version='1.1' encoding=\"ISO-8859-1\" standalone = \"yes\" ?>");
+    let declaration = doc.xml_declaration.expect("Declaration");
+
+    assert_eq!(declaration.version, "1.1");
+    assert_eq!(declaration.encoding.as_deref(), Some("ISO-8859-1"));
+    assert_eq!(declaration.standalone, Some(true));
+}
+
+#[test]
+fn xml_processing_instructions_are_not_declarations() {
+    let doc = parse_to_dom("<?xml-stylesheet href=\"a.xsl\"?><?xml encoding=\"UTF-8\"?>");
+
+    assert_eq!(doc.xml_declaration, None);
+    assert!(!doc.is_xml);
+    assert!(matches!(&doc.element.nodes[..], [
+        Node::ProcessingInstruction(a),
+        Node::ProcessingInstruction(b),
+    ] if a.content == "xml-stylesheet href=\"a.xsl\"" && b.content == "xml encoding=\"UTF-8\""));
+}
+
+#[test]
+fn write_xml_declaration() {
+    let xml = "<?xml version=\"1.0\" encoding=\"ISO-8859-1\" standalone=\"yes\"?>\n<?xml-stylesheet href=\"a.xsl\"?><feed></feed>";
+    assert_eq!(crate::writer::write(&parse_to_dom(xml)), xml);
+}
+
+#[test]
+fn write_xml_declaration_with_single_quotes() {
+    let xml = "<?xml version='1.0' encoding='ISO-8859-1'?>\n<!DOCTYPE html>\n<html></html>";
+    assert_eq!(crate::writer::write(&parse_to_dom(xml)), xml);
+
+    let options = ParseOptions { mode: ParseMode::Xml, ..ParseOptions::default() };
+    let xml = "<?xml version='1.0'?>\r\n\n<feed>\n</feed>";
+    assert_eq!(crate::writer::write(&parse_to_dom_with_options(xml, &options)), xml);
+}
+
+#[test]
+fn write_xml_declaration_as_in_source() {
+    for xml in &["<?xml version=\"1.0\" encoding=\"UTF-8\" ?>", "<?xml  version = \"1.0\"?>", "<?xml version='1.0' encoding=\"UTF-8\"?>"] {
+        assert_eq!(crate::writer::write(&parse_to_dom(xml)), *xml);
+    }
+
+    let mut doc = parse_to_dom("<?xml version='1.0'  encoding=\"UTF-8\"?>");
+    doc.xml_declaration.as_mut().unwrap().encoding = Some("ISO-8859-1".to_owned());
+    assert_eq!(crate::writer::write(&doc), "<?xml version='1.0' encoding='ISO-8859-1'?>");
+}
+
+#[test]
+fn xml_declaration_only_at_start() {
+    let doc = parse_to_dom("<p>a<?xml version=\"1.0\"?></p>");
+    assert_eq!(doc.xml_declaration, None);
+    assert_eq!(crate::writer::write(&doc), "<p>a<?xml version=\"1.0\"?></p>");
+
+    let doc = parse_to_dom(" <?xml version=\"1.0\"?>");
+    assert_eq!(doc.xml_declaration, None);
+    assert!(matches!(&doc.element.nodes[..], [Node::Text(_), Node::ProcessingInstruction(pi)] if pi.content == "xml version=\"1.0\""));
+}
+
 #[test]
 fn xml_and_doctype() {
     let doc = parse_to_dom("<?xml version=\"1.0\"?><!DOCTYPE html>");
-    assert_eq!(doc.doctype, Some("html".to_owned()));
+    assert_eq!(doc.doctype, Some(Doctype::new("html")));
+    assert_eq!(doc.xml_declaration.map(|declaration| declaration.version).as_deref(), Some("1.0"));
     let e = doc.element;
-    assert!(doc.is_xml);
 
     assert_eq!(e.name, "#document");
     assert_eq!(e.attributes.len(), 0);
     assert_eq!(e.nodes.len(), 0);
-}
\ No newline at end of file
+}
+#[test]
+fn no_spans_by_default() {
+    let e = parse_to_dom("<test>text<!--c--></test>").element;
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.span, None);
+        if let Node::Text(t) = &e.nodes[0] {
+            assert_eq!(t.span, None);
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn spans() {
+    let e = parse_with_spans("<test a=\"1\">\ntext<!--c-->\n</test>").element;
+
+    if let Node::Element(e) = &e.nodes[0] {
+        assert_eq!(e.span, Some(Span::new(Position::new(0, 1, 1), Position::new(33, 3, 8))));
+
+        if let Node::Text(t) = &e.nodes[0] {
+            assert_eq!(t.span, Some(Span::new(Position::new(12, 1, 13), Position::new(17, 2, 5))));
+        } else {
+            panic!("Node of wrong type");
+        }
+
+        if let Node::Comment(c) = &e.nodes[1] {
+            assert_eq!(c.span, Some(Span::new(Position::new(17, 2, 5), Position::new(25, 2, 13))));
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn spans_of_implicitly_closed_elements() {
+    let e = parse_with_spans("<foo><bar></foo>").element;
+
+    if let Node::Element(foo) = &e.nodes[0] {
+        assert_eq!(foo.span, Some(Span::new(Position::new(0, 1, 1), Position::new(16, 1, 17))));
+
+        if let Node::Element(bar) = &foo.nodes[0] {
+            assert_eq!(bar.span, Some(Span::new(Position::new(5, 1, 6), Position::new(10, 1, 11))));
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn spans_of_unclosed_elements() {
+    let e = parse_with_spans("<foo>text").element;
+
+    if let Node::Element(foo) = &e.nodes[0] {
+        assert_eq!(foo.span, Some(Span::new(Position::new(0, 1, 1), Position::new(9, 1, 10))));
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[derive(Default)]
+struct SpanHandler {
+    spans: Vec<(String, Span)>,
+}
+
+impl ParseHandler for SpanHandler {
+    fn text(&mut self, text: Cow<str>, span: Span) -> Control {
+        self.spans.push((text.into_owned(), span));
+        Control::Continue
+    }
+
+    fn comment(&mut self, _content: Cow<str>, span: Span) -> Control {
+        self.spans.push(("comment".to_owned(), span));
+        Control::Continue
+    }
+
+    fn cdata(&mut self, _content: Cow<str>, span: Span) -> Control {
+        self.spans.push(("cdata".to_owned(), span));
+        Control::Continue
+    }
+
+    fn doctype(&mut self, _doctype: Doctype, span: Span) -> Control {
+        self.spans.push(("doctype".to_owned(), span));
+        Control::Continue
+    }
+
+    fn processing_instruction(&mut self, _content: Cow<str>, span: Span) -> Control {
+        self.spans.push(("pi".to_owned(), span));
+        Control::Continue
+    }
+
+    fn element_start(&mut self, name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, span: Span) -> Control {
+        self.spans.push((name.into_owned(), span));
+        Control::Continue
+    }
+
+    fn element_end(&mut self, name: &str, span: Span) -> Control {
+        self.spans.push((format!("/{}", name), span));
+        Control::Continue
+    }
+}
+
+#[test]
+fn event_spans() {
+    let mut handler = SpanHandler::default();
+    parse(&mut handler, "<!DOCTYPE html><?pi?><![CDATA[x]]><script>1<2</script><br/>&auml;</br ><");
+
+    let offsets: Vec<(&str, usize, usize)> = handler.spans.iter()
+        .map(|(name, span)| (name.as_str(), span.start.offset, span.end.offset))
+        .collect();
+
+    assert_eq!(offsets, vec![
+        ("doctype", 0, 15),
+        ("pi", 15, 21),
+        ("cdata", 21, 34),
+        ("script", 34, 54),
+        ("br", 54, 59),
+        ("ä", 59, 65),
+        ("/br", 65, 71),
+        ("<", 71, 72),
+    ]);
+}
+
+fn error_codes(html: &str) -> Vec<&'static str> {
+    let (_, errors) = parse_to_dom_with_errors(html, &ParseOptions::default());
+    errors.iter().map(|e| e.code()).collect()
+}
+
+#[test]
+fn no_errors() {
+    assert!(error_codes("<!DOCTYPE html><html><body><p>Hello &amp; <br> World<p>!</body></html>").is_empty());
+}
+
+#[test]
+fn error_unclosed_element() {
+    let (_, errors) = parse_to_dom_with_errors("<div>\n<span>text</div>", &ParseOptions::default());
+
+    assert_eq!(errors, vec![ParseError::UnclosedElement {
+        name: "span".to_owned(),
+        span: Span::new(Position::new(6, 2, 1), Position::new(12, 2, 7)),
+    }]);
+}
+
+#[test]
+fn error_unclosed_element_at_end_of_input() {
+    assert_eq!(error_codes("<div>"), vec!["unclosed-element"]);
+}
+
+#[test]
+fn error_unexpected_end_tag() {
+    let (_, errors) = parse_to_dom_with_errors("text</div>", &ParseOptions::default());
+
+    assert_eq!(errors, vec![ParseError::UnexpectedEndTag {
+        name: "div".to_owned(),
+        span: Span::new(Position::new(4, 1, 5), Position::new(10, 1, 11)),
+    }]);
+}
+
+#[test]
+fn error_invalid_character_reference() {
+    let (_, errors) = parse_to_dom_with_errors("HELL&ZZZ; W&#xZZZ;!", &ParseOptions::default());
+
+    assert_eq!(errors, vec![
+        ParseError::InvalidCharacterReference {
+            reference: "&ZZZ;".to_owned(),
+            span: Span::new(Position::new(4, 1, 5), Position::new(9, 1, 10)),
+        },
+        ParseError::InvalidCharacterReference {
+            reference: "&#xZZZ;".to_owned(),
+            span: Span::new(Position::new(11, 1, 12), Position::new(18, 1, 19)),
+        },
+    ]);
+}
+
+#[test]
+fn error_numeric_character_references() {
+    let (document, errors) = parse_to_dom_with_errors("&#146;&#0;&#xD800&#x110000;&#65;", &ParseOptions::default());
+
+    if let Node::Text(text) = &document.element.nodes[0] {
+        assert_eq!(text.content, "\u{2019}\u{FFFD}\u{FFFD}\u{FFFD}A");
+    } else {
+        panic!("Node of wrong type");
+    }
+
+    assert_eq!(errors, vec![
+        ParseError::ControlCharacterReference {
+            reference: "&#146;".to_owned(),
+            span: Span::new(Position::new(0, 1, 1), Position::new(6, 1, 7)),
+        },
+        ParseError::NullCharacterReference {
+            reference: "&#0;".to_owned(),
+            span: Span::new(Position::new(6, 1, 7), Position::new(10, 1, 11)),
+        },
+        ParseError::SurrogateCharacterReference {
+            reference: "&#xD800".to_owned(),
+            span: Span::new(Position::new(10, 1, 11), Position::new(17, 1, 18)),
+        },
+        ParseError::CharacterReferenceOutsideUnicodeRange {
+            reference: "&#x110000;".to_owned(),
+            span: Span::new(Position::new(17, 1, 18), Position::new(27, 1, 28)),
+        },
+    ]);
+}
+
+#[test]
+fn error_invalid_character_reference_in_attribute() {
+    assert_eq!(error_codes("<a title=\"&ZZZ;\"></a>"), vec!["invalid-character-reference"]);
+}
+
+#[test]
+fn error_invalid_character_reference_in_title() {
+    let (_, errors) = parse_to_dom_with_errors("<title>\n&bogus;</title>", &ParseOptions::default());
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code(), "invalid-character-reference");
+    assert_eq!(errors[0].span().start, Position::new(8, 2, 1));
+}
+
+#[test]
+fn error_bad_doctype() {
+    assert_eq!(error_codes("<!DOCTYP html>Some"), vec!["invalid-markup-declaration"]);
+}
+
+#[test]
+fn error_unknown_bang_stuff() {
+    assert_eq!(error_codes("<!><!a><!-foo >"), vec!["invalid-markup-declaration"; 3]);
+}
+
+#[test]
+fn error_unclosed_attribute() {
+    let (_, errors) = parse_to_dom_with_errors("<test attr1=", &ParseOptions::default());
+
+    let span = Span::new(Position::new(0, 1, 1), Position::new(12, 1, 13));
+
+    assert_eq!(errors, vec![
+        ParseError::EofInTag { name: "test".to_owned(), span },
+        ParseError::UnclosedElement { name: "test".to_owned(), span },
+    ]);
+}
+
+#[test]
+fn error_unclosed_start_element() {
+    assert_eq!(error_codes("<test attr1=\"a1\" "), vec!["eof-in-tag", "unclosed-element"]);
+    assert_eq!(error_codes("<test attr1=\"a1"), vec!["eof-in-tag", "unclosed-element"]);
+}
+
+#[test]
+fn error_unclosed_end_element() {
+    assert_eq!(error_codes("<test></test"), vec!["eof-in-tag"]);
+}
+
+#[test]
+fn error_eof() {
+    assert_eq!(error_codes("<"), vec!["eof-before-tag-name"]);
+    assert_eq!(error_codes("<!-- comment"), vec!["eof-in-comment"]);
+    assert_eq!(error_codes("<![CDATA[ data"), vec!["eof-in-cdata"]);
+    assert_eq!(error_codes("<!DOCTYPE html"), vec!["eof-in-doctype"]);
+    assert_eq!(error_codes("<?pi"), vec!["eof-in-processing-instruction"]);
+    assert_eq!(error_codes("<script>code"), vec!["unclosed-element"]);
+}
+
+#[derive(Default)]
+struct ErrorHandler {
+    errors: Vec<ParseError>,
+}
+
+impl ParseHandler for ErrorHandler {
+    fn text(&mut self, _text: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn comment(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn cdata(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+    fn processing_instruction(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn element_start(&mut self, _name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, _span: Span) -> Control { Control::Continue }
+    fn element_end(&mut self, _name: &str, _span: Span) -> Control { Control::Continue }
+
+    fn error(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+}
+
+#[test]
+fn handler_errors() {
+    let mut handler = ErrorHandler::default();
+    parse(&mut handler, "<!a>&ZZZ;<b");
+
+    let codes: Vec<&str> = handler.errors.iter().map(|e| e.code()).collect();
+    assert_eq!(codes, vec!["invalid-markup-declaration", "invalid-character-reference", "eof-in-tag"]);
+}
+
+#[test]
+fn error_display() {
+    let error = ParseError::UnexpectedEndTag {
+        name: "div".to_owned(),
+        span: Span::new(Position::new(10, 2, 3), Position::new(16, 2, 9)),
+    };
+
+    assert_eq!(error.to_string(), "Unexpected end tag </div> at line 2, column 3 [unexpected-end-tag]");
+}
+
+/// Records all events as strings
+#[derive(Default)]
+struct RecordingHandler {
+    events: Vec<String>,
+}
+
+impl ParseHandler for RecordingHandler {
+    fn finish(&mut self) {
+        self.events.push("finish".to_owned());
+    }
+
+    fn text(&mut self, text: Cow<str>, span: Span) -> Control {
+        self.events.push(format!("text {:?} {:?}", text, span));
+        Control::Continue
+    }
+
+    fn comment(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.events.push(format!("comment {:?} {:?}", content, span));
+        Control::Continue
+    }
+
+    fn cdata(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.events.push(format!("cdata {:?} {:?}", content, span));
+        Control::Continue
+    }
+
+    fn doctype(&mut self, doctype: Doctype, span: Span) -> Control {
+        self.events.push(format!("doctype {:?} {:?}", doctype.name, span));
+        Control::Continue
+    }
+
+    fn processing_instruction(&mut self, content: Cow<str>, span: Span) -> Control {
+        self.events.push(format!("pi {:?} {:?}", content, span));
+        Control::Continue
+    }
+
+    fn element_start(&mut self, name: Cow<str>, attributes: TagAttributes, autoclose: bool, raw_content: Option<Cow<str>>, span: Span) -> Control {
+        self.events.push(format!("start {:?} {:?} {:?} {:?} {:?}", name, attributes, autoclose, raw_content, span));
+        Control::Continue
+    }
+
+    fn element_end(&mut self, name: &str, span: Span) -> Control {
+        self.events.push(format!("end {:?} {:?}", name, span));
+        Control::Continue
+    }
+
+    fn error(&mut self, error: ParseError) {
+        self.events.push(format!("error {:?}", error));
+    }
+}
+
+const STREAMING_HTML: &str = "<!DOCTYPE html>\n<html><head><title>T&auml;st</title><script>if (a<b) {}</script></head>\n\
+    <body class=\"x\" hidden><!-- cömment --><![CDATA[ data ]]><?pi ?><p>Hällo &amp; <br/>W&ouml;rld&ZZZ;</p><!a></body></html>";
+
+fn parse_events(html: &str) -> Vec<String> {
+    let mut handler = RecordingHandler::default();
+    parse(&mut handler, html);
+    handler.events
+}
+
+/// Records which event strings were copied from the input
+#[derive(Default)]
+struct CopyHandler {
+    owned: Vec<String>,
+    borrowed: Vec<String>,
+}
+
+impl CopyHandler {
+    fn record(&mut self, value: Cow<str>) {
+        match value {
+            Cow::Borrowed(value) => self.borrowed.push(value.to_string()),
+            Cow::Owned(value) => self.owned.push(value),
+        }
+    }
+}
+
+impl ParseHandler for CopyHandler {
+    fn text(&mut self, text: Cow<str>, _span: Span) -> Control {
+        self.record(text);
+        Control::Continue
+    }
+
+    fn comment(&mut self, content: Cow<str>, _span: Span) -> Control {
+        self.record(content);
+        Control::Continue
+    }
+
+    fn cdata(&mut self, content: Cow<str>, _span: Span) -> Control {
+        self.record(content);
+        Control::Continue
+    }
+
+    fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+
+    fn processing_instruction(&mut self, content: Cow<str>, _span: Span) -> Control {
+        self.record(content);
+        Control::Continue
+    }
+
+    fn element_start(&mut self, name: Cow<str>, attributes: TagAttributes, _autoclose: bool, raw_content: Option<Cow<str>>, _span: Span) -> Control {
+        self.record(name);
+        for attr in &attributes {
+            self.record(attr.name.clone());
+            if let Some(value) = &attr.value {
+                self.record(value.clone());
+            }
+        }
+        if let Some(raw_content) = raw_content {
+            self.record(raw_content);
+        }
+
+        Control::Continue
+    }
+
+    fn element_end(&mut self, _name: &str, _span: Span) -> Control { Control::Continue }
+}
+
+#[test]
+fn events_borrow_from_input() {
+    let mut handler = CopyHandler::default();
+    parse(&mut handler, "<!DOCTYPE html><p class=\"a b\" hidden>Text<!--c--><script>1<2</script><title>T</title></p>");
+
+    assert!(handler.owned.is_empty(), "Copied: {:?}", handler.owned);
+    assert_eq!(handler.borrowed, vec!["p", "class", "a b", "hidden", "Text", "c", "script", "1<2", "title", "T"]);
+}
+
+#[test]
+fn events_copy_decoded_and_lowercased_content() {
+    let mut handler = CopyHandler::default();
+    parse(&mut handler, "<P title=\"&lt;\">a&amp;b</P><title>&auml;</title>");
+
+    assert_eq!(handler.owned, vec!["p", "<", "a&b", "\u{e4}"]);
+    assert_eq!(handler.borrowed, vec!["title", "title"]);
+}
+
+#[test]
+fn tag_attributes_into_attributes() {
+    let mut attributes = TagAttributes::new();
+    attributes.set(Cow::Borrowed("a"), Some(Cow::Borrowed("1")));
+    attributes.set(Cow::Borrowed("b"), None);
+
+    assert_eq!(attributes.get_value("a"), Some("1"));
+    assert!(attributes.contains("b"));
+
+    let attributes = Attributes::from(attributes);
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(attributes.get_value("a"), Some("1"));
+    assert_eq!(attributes.get_value("b"), None);
+}
+
+#[test]
+fn streaming_single_chunk() {
+    let mut parser = StreamingParser::new(RecordingHandler::default());
+    parser.feed(STREAMING_HTML);
+
+    assert_eq!(parser.finish().events, parse_events(STREAMING_HTML));
+}
+
+#[test]
+fn streaming_split_everywhere() {
+    let expected = parse_events(STREAMING_HTML);
+
+    for (i, _) in STREAMING_HTML.char_indices() {
+        let mut parser = StreamingParser::new(RecordingHandler::default());
+        parser.feed(&STREAMING_HTML[..i]);
+        parser.feed(&STREAMING_HTML[i..]);
+
+        assert_eq!(parser.finish().events, expected, "Split at {}", i);
+    }
+}
+
+#[test]
+fn streaming_char_by_char() {
+    let mut parser = StreamingParser::new(RecordingHandler::default());
+
+    for ch in STREAMING_HTML.chars() {
+        parser.feed(&ch.to_string());
+    }
+
+    assert_eq!(parser.finish().events, parse_events(STREAMING_HTML));
+}
+
+#[test]
+fn streaming_incomplete_input() {
+    for html in &["<", "<a href=\"", "<!-- x", "text", "&amp", "<script>x"] {
+        let mut parser = StreamingParser::new(RecordingHandler::default());
+        parser.feed(html);
+
+        assert!(parser.handler().events.is_empty(), "Events for {}", html);
+        assert_eq!(parser.finish().events, parse_events(html));
+    }
+}
+
+#[test]
+fn streaming_large_token_in_small_chunks() {
+    let html = format!("<p>a</p><script>{}</script><!--{}-->b", "var a = 1;\n".repeat(10_000), "c".repeat(10_000));
+    let mut parser = StreamingParser::new(RecordingHandler::default());
+
+    for chunk in html.as_bytes().chunks(7) {
+        parser.feed(std::str::from_utf8(chunk).unwrap());
+    }
+
+    assert_eq!(parser.finish().events, parse_events(&html));
+}
+
+const BOGUS_HTML: &str = "<p>a</1>b<!foo><?php echo 1 ?>c < d</p>";
+
+fn parse_bogus_events(html: &str) -> Vec<String> {
+    let mut handler = RecordingHandler::default();
+    parse_with_options(&mut handler, html, &ParseOptions { bogus_comments: true, ..ParseOptions::default() });
+    handler.events
+}
+
+#[test]
+fn streaming_with_options() {
+    let options = ParseOptions { bogus_comments: true, ..ParseOptions::default() };
+    let mut parser = StreamingParser::with_options(RecordingHandler::default(), &options);
+
+    for ch in BOGUS_HTML.chars() {
+        parser.feed(&ch.to_string());
+    }
+
+    let events = parser.finish().events;
+    assert_eq!(events, parse_bogus_events(BOGUS_HTML));
+    assert_ne!(events, parse_events(BOGUS_HTML));
+}
+
+/// A reader returning a single byte per call
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> std::io::Read for ByteReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.bytes.split_first() {
+            Some((byte, rest)) if !buf.is_empty() => {
+                buf[0] = *byte;
+                self.bytes = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn reader() {
+    let mut handler = RecordingHandler::default();
+    parse_reader(&mut handler, STREAMING_HTML.as_bytes()).unwrap();
+
+    assert_eq!(handler.events, parse_events(STREAMING_HTML));
+}
+
+#[test]
+fn reader_split_chars() {
+    let mut handler = RecordingHandler::default();
+    parse_reader(&mut handler, ByteReader { bytes: STREAMING_HTML.as_bytes() }).unwrap();
+
+    assert_eq!(handler.events, parse_events(STREAMING_HTML));
+}
+
+#[test]
+fn reader_with_options() {
+    let options = ParseOptions { bogus_comments: true, ..ParseOptions::default() };
+    let mut handler = RecordingHandler::default();
+    parse_reader_with_options(&mut handler, ByteReader { bytes: BOGUS_HTML.as_bytes() }, &options).unwrap();
+
+    assert_eq!(handler.events, parse_bogus_events(BOGUS_HTML));
+}
+
+#[test]
+fn reader_invalid_utf8() {
+    let mut handler = RecordingHandler::default();
+    let err = parse_reader(&mut handler, &b"<p>\xff</p>"[..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let err = parse_reader(&mut handler, &b"<p>\xc3"[..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn bytes_utf8() {
+    let doc = parse_bytes_to_dom("<p>Grüße</p>".as_bytes());
+    assert_eq!(doc.encoding, Some("UTF-8"));
+
+    if let Node::Element(e) = &doc.element.nodes[0] {
+        if let Node::Text(t) = &e.nodes[0] {
+            assert_eq!(t.content, "Grüße");
+        } else {
+            panic!("Node of wrong type");
+        }
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn bytes_bom() {
+    let doc = parse_bytes_to_dom(b"\xef\xbb\xbf<p>x</p>");
+    assert_eq!(doc.encoding, Some("UTF-8"));
+    assert_eq!(doc.element.nodes.len(), 1);
+
+    let doc = parse_bytes_to_dom(b"\xff\xfe<\0p\0>\0\xe4\0");
+    assert_eq!(doc.encoding, Some("UTF-16LE"));
+    assert_eq!(crate::writer::write(&doc), "<p>ä</p>");
+}
+
+#[test]
+fn bytes_meta_charset() {
+    let doc = parse_bytes_to_dom(b"<html><head><meta charset=\"ISO-8859-1\"></head><body>Gr\xfc\xdfe</body></html>");
+    assert_eq!(doc.encoding, Some("windows-1252"));
+    assert_eq!(crate::writer::write(&doc), "<html><head><meta charset=\"ISO-8859-1\"></head><body>Grüße</body></html>");
+}
+
+#[test]
+fn bytes_encoding_hint() {
+    let options = ParseOptions {
+        encoding_hint: Some("Shift_JIS".to_owned()),
+        ..ParseOptions::default()
+    };
+
+    let doc = parse_bytes_to_dom_with_options(b"<meta charset=\"utf-8\"><p>\x93\xfa\x96\x7b</p>", &options);
+    assert_eq!(doc.encoding, Some("Shift_JIS"));
+    assert_eq!(crate::writer::write(&doc), "<meta charset=\"utf-8\"><p>日本</p>");
+}
+
+#[test]
+fn bytes_fallback() {
+    let doc = parse_bytes_to_dom(b"<p>\x93quoted\x94</p>");
+    assert_eq!(doc.encoding, Some("windows-1252"));
+    assert_eq!(crate::writer::write(&doc), "<p>\u{201c}quoted\u{201d}</p>");
+}
+
+#[test]
+fn tokenize_all_token_types() {
+    let tokens: Vec<Token> = tokenize("<!DOCTYPE html><?pi?><P class='x'>a&amp;b<br/><!--c--><![CDATA[d]]></p>").collect();
+
+    let mut attributes = TagAttributes::new();
+    attributes.set(Cow::Borrowed("class"), Some(Cow::Borrowed("x")));
+
+    let kinds: Vec<Token> = tokens.into_iter().map(|token| match token {
+        Token::StartTag { name, attributes, autoclose, raw_content, .. } => Token::StartTag {
+            name,
+            attributes,
+            autoclose,
+            raw_content,
+            span: Span::default(),
+        },
+        Token::Text(text, _) => Token::Text(text, Span::default()),
+        Token::Comment(text, _) => Token::Comment(text, Span::default()),
+        Token::CData(text, _) => Token::CData(text, Span::default()),
+        Token::Doctype(doctype, _) => Token::Doctype(doctype, Span::default()),
+        Token::ProcessingInstruction(text, _) => Token::ProcessingInstruction(text, Span::default()),
+        Token::EndTag(name, _) => Token::EndTag(name, Span::default()),
+    }).collect();
+
+    let span = Span::default();
+    assert_eq!(kinds, vec![
+        Token::Doctype(Doctype::new("html"), span),
+        Token::ProcessingInstruction("pi".into(), span),
+        Token::StartTag { name: "p".into(), attributes, autoclose: false, raw_content: None, span },
+        Token::Text("a&b".into(), span),
+        Token::StartTag { name: "br".into(), attributes: TagAttributes::new(), autoclose: true, raw_content: None, span },
+        Token::Comment("c".into(), span),
+        Token::CData("d".into(), span),
+        Token::EndTag("p".into(), span),
+    ]);
+}
+
+#[test]
+fn tokenize_matches_handler_events() {
+    let mut tokens = tokenize(STREAMING_HTML);
+    let token_spans: Vec<String> = (&mut tokens).map(|token| format!("{:?}", token.span())).collect();
+
+    let events = parse_events(STREAMING_HTML);
+    let spans: Vec<String> = events.iter()
+        .filter(|event| !event.starts_with("error") && *event != "finish")
+        .map(|event| event[event.find("Span {").unwrap()..].to_owned())
+        .collect();
+
+    assert_eq!(token_spans, spans);
+    assert_eq!(tokens.errors().len(), events.iter().filter(|event| event.starts_with("error")).count());
+}
+
+#[test]
+fn tokenize_stops_when_dropped() {
+    let title = tokenize("<title>T&auml;st</title><body><p>Text")
+        .find_map(|token| match token {
+            Token::StartTag { name, raw_content, .. } if name == "title" => raw_content,
+            _ => None,
+        });
+
+    assert_eq!(title.as_deref(), Some("T\u{e4}st"));
+}
+
+/// Records start and end tags and returns the configured control values
+#[derive(Default)]
+struct ControlHandler {
+    events: Vec<String>,
+    skip: &'static str,
+    stop: &'static str,
+    finished: bool,
+}
+
+impl ParseHandler for ControlHandler {
+    fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    fn text(&mut self, text: Cow<str>, _span: Span) -> Control {
+        self.events.push(text.into_owned());
+        Control::Continue
+    }
+
+    fn comment(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn cdata(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+    fn doctype(&mut self, _doctype: Doctype, _span: Span) -> Control { Control::Continue }
+    fn processing_instruction(&mut self, _content: Cow<str>, _span: Span) -> Control { Control::Continue }
+
+    fn element_start(&mut self, name: Cow<str>, _attributes: TagAttributes, _autoclose: bool, _raw_content: Option<Cow<str>>, _span: Span) -> Control {
+        self.events.push(name.clone().into_owned());
+        if name == self.skip {
+            Control::SkipChildren
+        } else {
+            Control::Continue
+        }
+    }
+
+    fn element_end(&mut self, name: &str, _span: Span) -> Control {
+        self.events.push(format!("/{}", name));
+        if name == self.stop {
+            Control::Stop
+        } else {
+            Control::Continue
+        }
+    }
+}
+
+const CONTROL_HTML: &str = "<html><head><title>T</title></head><body><div>a<div>b</div>c</div><p>d</p></body></html>";
+
+#[test]
+fn control_stop() {
+    let mut handler = ControlHandler { stop: "head", ..ControlHandler::default() };
+    parse(&mut handler, CONTROL_HTML);
+
+    assert_eq!(handler.events, vec!["html", "head", "title", "/head"]);
+    assert!(handler.finished);
+}
+
+#[test]
+fn control_skip_children() {
+    let mut handler = ControlHandler { skip: "div", ..ControlHandler::default() };
+    parse(&mut handler, CONTROL_HTML);
+
+    assert_eq!(handler.events, vec!["html", "head", "title", "/head", "body", "div", "/div", "p", "d", "/p", "/body", "/html"]);
+}
+
+#[test]
+fn control_skip_children_with_implied_end_tag() {
+    let mut handler = ControlHandler { skip: "li", ..ControlHandler::default() };
+    parse(&mut handler, "<ul><li>a<li>b</ul><p>after</p>");
+    assert_eq!(handler.events, vec!["ul", "li", "li", "/ul", "p", "after", "/p"]);
+
+    let mut handler = ControlHandler { skip: "p", ..ControlHandler::default() };
+    parse(&mut handler, "<p>a<div>b</div><p>c</p><span>after</span>");
+    assert_eq!(handler.events, vec!["p", "div", "b", "/div", "p", "/p", "span", "after", "/span"]);
+
+    let mut handler = ControlHandler { skip: "head", ..ControlHandler::default() };
+    parse(&mut handler, "<head><title>x</title><body>after");
+    assert_eq!(handler.events, vec!["head", "body", "after"]);
+}
+
+#[test]
+fn control_skip_children_with_nested_implied_end_tags() {
+    let mut handler = ControlHandler { skip: "li", ..ControlHandler::default() };
+    parse(&mut handler, "<ul><li>a<ul><li>b<li>c</ul><b>d</b><li>e</ul>");
+    assert_eq!(handler.events, vec!["ul", "li", "li", "/ul"]);
+
+    let mut handler = ControlHandler { skip: "p", ..ControlHandler::default() };
+    parse(&mut handler, "<p>a<button><div>b</div></button>c</p>d");
+    assert_eq!(handler.events, vec!["p", "/p", "d"]);
+}
+
+#[test]
+fn control_skip_children_of_void_element() {
+    let mut handler = ControlHandler { skip: "br", ..ControlHandler::default() };
+    parse(&mut handler, "<br>a<p>b</p>");
+
+    assert_eq!(handler.events, vec!["br", "a", "p", "b", "/p"]);
+}
+
+#[test]
+fn control_skip_children_up_to_end_of_input() {
+    let mut handler = ControlHandler { skip: "div", ..ControlHandler::default() };
+    parse(&mut handler, "<div><p>a</p>");
+
+    assert_eq!(handler.events, vec!["div"]);
+    assert!(handler.finished);
+}
+
+#[test]
+fn control_stop_streaming() {
+    let mut parser = StreamingParser::new(ControlHandler { stop: "head", ..ControlHandler::default() });
+    parser.feed("<html><head></he");
+    assert!(!parser.is_stopped());
+    parser.feed("ad><body>");
+    assert!(parser.is_stopped());
+    parser.feed("<p>ignored</p>");
+
+    let handler = parser.finish();
+    assert_eq!(handler.events, vec!["html", "head", "/head"]);
+}
+
+#[test]
+fn control_stop_reader() {
+    // Invalid UTF-8 after the stop isn't read anymore
+    let mut html = CONTROL_HTML.as_bytes().to_vec();
+    html.extend_from_slice(b"\xff");
+
+    let mut handler = ControlHandler { stop: "head", ..ControlHandler::default() };
+    parse_reader(&mut handler, ByteReader { bytes: &html }).unwrap();
+
+    assert_eq!(handler.events, vec!["html", "head", "title", "/head"]);
+}
+
+#[test]
+fn limit_token_length_at_char_boundary() {
+    let limits = ParserLimits { max_token_length: 3, ..ParserLimits::default() };
+    let mut handler = CopyHandler::default();
+    parse_with_options(&mut handler, "ab\u{e4}c<p id=\"&auml;&auml;\">abc</p>", &ParseOptions { limits, ..ParseOptions::default() });
+
+    assert_eq!(handler.borrowed, vec!["ab", "p", "id", "abc"]);
+    assert_eq!(handler.owned, vec!["\u{e4}"]);
+}
+
+#[test]
+fn limit_attributes_reports_error_once() {
+    let limits = ParserLimits { max_attributes: 1, ..ParserLimits::default() };
+    let mut handler = ErrorHandler::default();
+    parse_with_options(&mut handler, "<p a b c d><p a>", &ParseOptions { limits, ..ParseOptions::default() });
+
+    let codes: Vec<&str> = handler.errors.iter().map(|e| e.code()).collect();
+    assert_eq!(codes, vec!["too-many-attributes"]);
+}
+
+fn parse_bogus(html: &str) -> (String, Vec<&'static str>) {
+    let options = ParseOptions { bogus_comments: true, ..ParseOptions::default() };
+    let (document, errors) = parse_to_dom_with_errors(html, &options);
+    (crate::writer::write(&document), errors.iter().map(|e| e.code()).collect())
+}
+
+#[test]
+fn bogus_comments() {
+    assert_eq!(
+        parse_bogus("<!ELEMENT br EMPTY><!><?php echo 1 ?><?xml version=\"1.0\"?>"),
+        ("<!--ELEMENT br EMPTY--><!----><!--?php echo 1 ?--><!--?xml version=\"1.0\"?-->".to_owned(), vec!["bogus-comment"; 4])
+    );
+}
+
+#[test]
+fn bogus_comments_for_end_tags() {
+    assert_eq!(
+        parse_bogus("<p>a</1>b</>c</ d>"),
+        ("<p>a<!--1-->bc<!-- d--></p>".to_owned(), vec!["bogus-comment", "missing-end-tag-name", "bogus-comment"])
+    );
+    assert_eq!(parse_bogus("<p>a</p class=\"x\">b"), ("<p>a</p>b".to_owned(), vec![]));
+    assert_eq!(parse_bogus("a</"), ("a&lt;/".to_owned(), vec!["eof-before-tag-name"]));
+}
+
+#[test]
+fn bogus_comments_less_than_sign_in_text() {
+    assert_eq!(parse_bogus("a < b"), ("a &lt; b".to_owned(), vec!["invalid-first-character-of-tag-name"]));
+    assert_eq!(parse_bogus("1<2"), ("1&lt;2".to_owned(), vec!["invalid-first-character-of-tag-name"]));
+    assert_eq!(parse_bogus("x<1y"), ("x&lt;1y".to_owned(), vec!["invalid-first-character-of-tag-name"]));
+    assert_eq!(parse_bogus("< p>"), ("&lt; p&gt;".to_owned(), vec!["invalid-first-character-of-tag-name"]));
+}
+
+#[test]
+fn bogus_comments_end_of_comment() {
+    assert_eq!(
+        parse_bogus("<!-->a<!--->b<!--c--!>d<!-- e -- f --->g"),
+        ("<!---->a<!---->b<!--c-->d<!-- e -- f --->g".to_owned(), vec![
+            "abrupt-closing-of-empty-comment", "abrupt-closing-of-empty-comment", "incorrectly-closed-comment"
+        ])
+    );
+    assert_eq!(parse_bogus("<!--a--!"), ("<!--a--!-->".to_owned(), vec!["eof-in-comment"]));
+}
+
+#[test]
+fn bogus_comments_keep_doctype_and_cdata() {
+    let options = ParseOptions { bogus_comments: true, ..ParseOptions::default() };
+    let document = parse_to_dom_with_options("<!doctype html><![CDATA[x]]>", &options);
+
+    assert_eq!(document.doctype, Some(Doctype::new("html")));
+    assert!(matches!(&document.element.nodes[..], [Node::CData(cdata)] if cdata.content == "x"));
+}
+
+#[test]
+fn raw_text_end_tag_ignores_case() {
+    assert_eq!(cleanup("<script>a</SCRIPT><p>b</p>"), "<script>a</script><p>b</p>");
+    assert_eq!(cleanup("<Script>a</script><p>b</p>"), "<script>a</script><p>b</p>");
+    assert_eq!(cleanup("<TITLE>a&amp;</Title><p>b</p>"), "<title>a&amp;</title><p>b</p>");
+}
+
+#[test]
+fn raw_text_end_tag_with_whitespace_and_attributes() {
+    assert_eq!(cleanup("<script>a</script >b"), "<script>a</script>b");
+    assert_eq!(cleanup("<style>a</style\nmedia=\"x\">b"), "<style>a</style>b");
+    assert_eq!(cleanup("<script>a</script/>b"), "<script>a</script>b");
+    assert_eq!(cleanup("<textarea>a</textarea\t>b"), "<textarea>a</textarea>b");
+}
+
+#[test]
+fn raw_text_end_tag_needs_delimiter() {
+    assert_eq!(cleanup("<script>a</scripts></script>b"), "<script>a</scripts></script>b");
+    assert_eq!(error_codes("<script>a</script"), vec!["unclosed-element"]);
+    assert_eq!(error_codes("<script>a</script x"), vec!["eof-in-tag"]);
+}
+
+#[test]
+fn script_escapes() {
+    // An end tag in a comment-like section ends the script
+    assert_eq!(cleanup("<script><!-- a </script>b"), "<script><!-- a </script>b");
+
+    // but not after a nested script start tag
+    assert_eq!(
+        cleanup("<script><!--<script>x</script>y--></script>b"),
+        "<script><!--<script>x</script>y--></script>b"
+    );
+
+    // An end tag after the nested script ends the script again
+    assert_eq!(
+        cleanup("<script><!--<SCRIPT >x</script>y</script>--></script>b"),
+        "<script><!--<SCRIPT >x</script>y</script>--&gt;b"
+    );
+
+    // The section ends with -->
+    assert_eq!(cleanup("<script><!--><script></script>b"), "<script><!--><script></script>b");
+    assert_eq!(cleanup("<script><!-- --><script></script>b"), "<script><!-- --><script></script>b");
+}
+
+#[test]
+fn attribute_names_are_lowercased() {
+    let document = parse_to_dom("<a HREF=x Data-Id='1'>a</a>");
+
+    if let Node::Element(e) = &document.element.nodes[0] {
+        assert_eq!(e.get_attribute_value("href"), Some("x"));
+        assert_eq!(e.get_attribute_value("data-id"), Some("1"));
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn unquoted_attribute_values() {
+    assert_eq!(cleanup("<a href=x>text</a>"), "<a href=\"x\">text</a>");
+    assert_eq!(cleanup("<a href=/a/b\tclass=c\nid=d\x0Ctitle=e>t</a>"), "<a href=\"/a/b\" class=\"c\" id=\"d\" title=\"e\">t</a>");
+    assert_eq!(cleanup("<p a=>x</p>"), "<p a=\"\">x</p>");
+}
+
+#[test]
+fn slash_in_start_tag() {
+    assert_eq!(cleanup("<a / href=x>t</a>"), "<a href=\"x\">t</a>");
+    assert_eq!(cleanup("<p a=1/>x"), "<p a=\"1/\">x</p>");
+    assert_eq!(cleanup("<br/><p a/>x"), "<br><p a></p>x");
+}
+
+#[test]
+fn duplicate_attributes_keep_first() {
+    assert_eq!(cleanup("<p a=1 b A=2 B=3>x</p>"), "<p a=\"1\" b>x</p>");
+    assert_eq!(error_codes("<p a=1 b A=2 B=3>x</p>"), vec!["duplicate-attribute"; 2]);
+}
+
+#[test]
+fn unexpected_characters_in_attributes() {
+    assert_eq!(error_codes("<p a\"b=1 'c <d/=e>x</p>"), vec!["unexpected-character-in-attribute-name"; 4]);
+    assert_eq!(error_codes("<p a=b`c d=e=f g=\"h=i\">x</p>"), vec!["unexpected-character-in-unquoted-attribute-value"; 2]);
+
+    let document = parse_to_dom("<p a\"b=1 =e>x</p>");
+    if let Node::Element(e) = &document.element.nodes[0] {
+        assert_eq!(e.get_attribute_value("a\"b"), Some("1"));
+        assert!(e.has_attribute("=e"));
+    } else {
+        panic!("Node of wrong type");
+    }
+}
+
+#[test]
+fn write_doctype_identifiers() {
+    let html = "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">";
+    assert_eq!(crate::writer::write(&parse_to_dom(html)), html);
+}
+
+#[test]
+fn write_doctype_keeps_force_quirks() {
+    for html in &["<!DOCTYPE html PUBLIC>", "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN>", "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd>", "<!DOCTYPE html SYSTEM \"about:legacy-compat>"] {
+        let document = parse_to_dom(html);
+        assert!(document.doctype.as_ref().unwrap().force_quirks);
+        let written = crate::writer::write(&document);
+        assert_eq!(written, *html);
+        assert_eq!(parse_to_dom(&written).doctype, document.doctype);
+    }
+
+    let document = parse_to_dom("<!DOCTYPE html whatever>");
+    assert_eq!(crate::writer::write(&document), "<!DOCTYPE html PUBLIC>");
+    assert_eq!(parse_to_dom("<!DOCTYPE html PUBLIC>").quirks_mode, QuirksMode::Quirks);
+}
+
+fn parse_xml(xml: &str) -> (String, Vec<&'static str>) {
+    let options = ParseOptions { mode: ParseMode::Xml, ..ParseOptions::default() };
+    let (document, errors) = parse_to_dom_with_errors(xml, &options);
+    (crate::writer::write(&document), errors.iter().map(|e| e.code()).collect())
+}
+
+#[test]
+fn xml_names_keep_case() {
+    assert_eq!(parse_xml("<Feed xml:Lang=\"en\"><Entry/></Feed>"), ("<Feed xml:Lang=\"en\"><Entry/></Feed>".to_owned(), vec![]));
+    assert_eq!(parse_xml("<a><A></A></a>"), ("<a><A/></a>".to_owned(), vec![]));
+}
+
+#[test]
+fn xml_self_closing_elements() {
+    assert_eq!(parse_xml("<div/><p/>text"), ("<div/><p/>text".to_owned(), vec![]));
+    assert_eq!(parse_xml("<br>text</br>"), ("<br>text</br>".to_owned(), vec![]));
+}
+
+#[test]
+fn xml_has_no_raw_elements() {
+    assert_eq!(parse_xml("<script>a &lt; b<b/></script><style/>"), ("<script>a &lt; b<b/></script><style/>".to_owned(), vec![]));
+    assert_eq!(parse_xml("<textarea>\n<b>x</b></textarea>"), ("<textarea>\n<b>x</b></textarea>".to_owned(), vec![]));
+}
+
+#[test]
+fn xml_entities() {
+    let options = ParseOptions { mode: ParseMode::Xml, ..ParseOptions::default() };
+    let (document, errors) = parse_to_dom_with_errors("<a t=\"&quot;&apos;\">&amp;&lt;&gt;&#160;&#x41;&nbsp;&copy</a>", &options);
+
+    assert!(matches!(&document.element.nodes[..], [Node::Element(a)]
+        if a.get_attribute_value("t") == Some("\"'")
+        && matches!(&a.nodes[..], [Node::Text(text)] if text.content == "&<>\u{a0}A&nbsp;&copy")));
+    assert_eq!(errors.iter().map(|e| e.code()).collect::<Vec<_>>(), vec!["invalid-character-reference"]);
+}
+
+#[test]
+fn xml_mismatched_end_tags() {
+    assert_eq!(parse_xml("<a><b></a>"), ("<a><b/></a>".to_owned(), vec!["unclosed-element"]));
+    assert_eq!(parse_xml("<a></b></a>"), ("<a/>".to_owned(), vec!["unexpected-end-tag"]));
+    assert_eq!(parse_xml("<ul><li>a<li>b</ul>"), ("<ul><li>a<li>b</li></li></ul>".to_owned(), vec!["unclosed-element", "unclosed-element"]));
+    assert_eq!(parse_xml("<p>"), ("<p/>".to_owned(), vec!["unclosed-element"]));
+}
+
+#[test]
+fn xml_has_no_implied_elements() {
+    let options = ParseOptions { mode: ParseMode::Xml, implied_elements: true, ..ParseOptions::default() };
+    let document = parse_to_dom_with_options("<?xml version=\"1.0\"?><table><tr>x</tr></table>", &options);
+
+    assert_eq!(crate::writer::write(&document), "<?xml version=\"1.0\"?><table><tr>x</tr></table>");
+    assert_eq!(document.quirks_mode, QuirksMode::NoQuirks);
+}
@@ -1,7 +1,10 @@
 
+use std::borrow::Cow;
+
 use crate::reader::Reader;
+use crate::dom::Position;
 
-fn new_from_str(s: &str) -> Reader {
+fn new_from_str(s: &str) -> Reader<'_> {
     Reader::new(s)
 }
 
@@ -66,25 +69,87 @@ fn pushback() {
 #[test]
 fn entity() {
     let mut r = new_from_str("a&amp;b<");
-    assert_eq!(r.read_text(&['<']), "a&b");
+    assert_eq!(r.read_text(&['<'], false), "a&b");
 }
 
 #[test]
 fn badentity() {
     let mut r = new_from_str("a&");
-    assert_eq!(r.read_text(&['<']), "a&");
+    assert_eq!(r.read_text(&['<'], false), "a&");
 }
 
 #[test]
 fn badentity2() {
     let mut r = new_from_str("a&;");
-    assert_eq!(r.read_text(&['<']), "a&;");
+    assert_eq!(r.read_text(&['<'], false), "a&;");
 }
 
 #[test]
 fn badhexentity() {
     let mut r = new_from_str("a&#;");
-    assert_eq!(r.read_text(&['<']), "a&#;");
+    assert_eq!(r.read_text(&['<'], false), "a&#;");
+}
+
+#[test]
+fn legacy_entity() {
+    let mut r = new_from_str("&copy2&copy=<");
+    assert_eq!(r.read_text(&['<'], false), "©2©=");
+}
+
+#[test]
+fn legacy_entity_in_attribute() {
+    let mut r = new_from_str("&copy2&copy=&copy \"");
+    assert_eq!(r.read_text(&['"'], true), "&copy2&copy=© ");
+}
+
+#[test]
+fn text_without_references_is_borrowed() {
+    let mut r = new_from_str("a & b &ZZZ;<");
+    assert!(matches!(r.read_text(&['<'], false), Cow::Borrowed("a & b &ZZZ;")));
+}
+
+#[test]
+fn text_with_references_is_owned() {
+    let mut r = new_from_str("a &amp; b&lt<");
+    assert!(matches!(r.read_text(&['<'], false), Cow::Owned(ref text) if text == "a & b<"));
+}
+
+#[test]
+fn read_raw_at_offset() {
+    let mut r = Reader::new_at("x-->y", Position::new(10, 2, 5), false);
+    assert_eq!(r.read_raw("-->"), ("x", true));
+    assert_eq!(r.read_to_end(), "y");
+}
+
+#[test]
+fn position_after_raw_content() {
+    let mut r = new_from_str("a\nb\u{e4}-->c\n\nd");
+    assert_eq!(r.read_raw("-->"), ("a\nb\u{e4}", true));
+    assert_eq!(r.position(), Position::new(8, 2, 6));
+
+    r.read_to_end();
+    assert_eq!(r.position(), Position::new(12, 4, 2));
+}
+
+#[test]
+fn position_after_text_with_entities() {
+    let mut r = new_from_str("x\n&auml;&amp;y<");
+    assert_eq!(r.read_text(&['<'], false), "x\n\u{e4}&y");
+    assert_eq!(r.position(), Position::new(14, 2, 13));
+}
+
+#[test]
+fn read_raw_without_end_marker() {
+    let mut r = Reader::new_at("abc", Position::default(), true);
+    assert_eq!(r.read_raw("-->"), ("abc", false));
+    assert!(r.is_incomplete());
+}
+
+#[test]
+fn entity_at_end_of_partial_input() {
+    let mut r = Reader::new_at("a&am", Position::default(), true);
+    r.read_text(&['<'], false);
+    assert!(r.is_incomplete());
 }
 
 #[test]
@@ -141,4 +206,76 @@ fn test_valid_chars() {
             assert_eq!(r.next_char(), None);
         }
     }
-}
\ No newline at end of file
+}
+#[test]
+fn position() {
+    let mut r = new_from_str("aä\nb");
+
+    assert_eq!(r.position(), Position::new(0, 1, 1));
+    r.next_char();
+    assert_eq!(r.position(), Position::new(1, 1, 2));
+    r.next_char();
+    assert_eq!(r.position(), Position::new(3, 1, 3));
+    r.next_char();
+    assert_eq!(r.position(), Position::new(4, 2, 1));
+    r.next_char();
+    assert_eq!(r.position(), Position::new(5, 2, 2));
+}
+
+#[test]
+fn position_after_pushback() {
+    let mut r = new_from_str("a\nb");
+
+    r.next_char();
+    let ch = r.next_char().unwrap();
+    r.push_back(ch);
+    assert_eq!(r.position(), Position::new(1, 1, 2));
+
+    assert_eq!(r.next_char(), Some('\n'));
+    assert_eq!(r.position(), Position::new(2, 2, 1));
+}
+
+#[test]
+fn read_prefix() {
+    let mut r = new_from_str("DocType html");
+
+    assert!(!r.read_prefix("DOCTYPE", false));
+    assert!(r.read_prefix("DOCTYPE", true));
+    assert_eq!(r.position(), Position::new(7, 1, 8));
+    assert!(!r.is_incomplete());
+}
+
+#[test]
+fn read_prefix_of_partial_input() {
+    let mut r = Reader::new_at("<!-", Position::default(), true);
+
+    assert!(r.read_prefix("<!", false));
+    assert!(!r.read_prefix("--", false));
+    assert!(r.is_incomplete());
+}
+
+#[test]
+fn read_comment() {
+    let mut r = new_from_str("a -- b --!>c-->");
+
+    assert_eq!(r.read_comment(), ("a -- b ", Some("--!>")));
+    assert_eq!(r.read_comment(), ("c", Some("-->")));
+    assert_eq!(r.read_comment(), ("", None));
+}
+
+#[test]
+fn read_raw_text() {
+    let mut r = new_from_str("a</b></TITLE >c");
+
+    assert_eq!(r.read_raw_text("title", false), ("a</b>", true));
+    assert_eq!(r.position(), Position::new(12, 1, 13));
+}
+
+#[test]
+fn read_raw_text_of_script() {
+    let mut r = new_from_str("<!--<script></script>--></script>");
+    assert_eq!(r.read_raw_text("script", true), ("<!--<script></script>-->", true));
+
+    let mut r = new_from_str("<!--<script></script>--></script>");
+    assert_eq!(r.read_raw_text("script", false), ("<!--<script>", true));
+}
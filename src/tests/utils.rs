@@ -20,6 +20,7 @@ fn html_whitespace() {
     assert!(is_html_whitespace('\n'));
     assert!(is_html_whitespace('\r'));
     assert!(is_html_whitespace('\t'));
+    assert!(is_html_whitespace('\x0C'));
     assert!(!is_html_whitespace('\u{a0}'));
 }
 
@@ -35,17 +36,54 @@ fn unknown() {
 
 #[test]
 fn named_entity() {
-    assert_eq!(decode_entity("euro"), Some('€'));
+    assert_eq!(decode_entity("euro").as_deref(), Some("€"));
+}
+
+#[test]
+fn named_entity_with_two_code_points() {
+    assert_eq!(decode_entity("NotEqualTilde").as_deref(), Some("\u{2242}\u{338}"));
+    assert_eq!(decode_entity("bne").as_deref(), Some("=\u{20e5}"));
+}
+
+#[test]
+fn match_entity_longest_prefix() {
+    assert_eq!(match_entity("notin;"), Some((6, "\u{2209}")));
+    assert_eq!(match_entity("notit;"), Some((3, "\u{ac}")));
+    assert_eq!(match_entity("amp"), Some((3, "&")));
+    assert_eq!(match_entity("ampx"), Some((3, "&")));
+}
+
+#[test]
+fn match_entity_requires_semicolon() {
+    assert_eq!(match_entity("euro"), None);
+    assert_eq!(match_entity("euro;"), Some((5, "€")));
+    assert_eq!(match_entity(";"), None);
+    assert_eq!(match_entity(""), None);
+}
+
+#[test]
+fn match_entity_at_table_bounds() {
+    assert_eq!(match_entity("AElig"), Some((5, "\u{c6}")));
+    assert_eq!(match_entity("zwnj;"), Some((5, "\u{200c}")));
+    assert_eq!(match_entity("zwnjx;"), None);
+    assert_eq!(match_entity("\u{e4}uml;"), None);
+}
+
+#[test]
+fn decode_entity_at_table_bounds() {
+    assert_eq!(decode_entity("AElig").as_deref(), Some("\u{c6}"));
+    assert_eq!(decode_entity("zwnj").as_deref(), Some("\u{200c}"));
+    assert_eq!(decode_entity("Zwnj"), None);
 }
 
 #[test]
 fn hex_entity() {
-    assert_eq!(decode_entity("#x1F4A9"), Some('💩'));
+    assert_eq!(decode_entity("#x1F4A9").as_deref(), Some("💩"));
 }
 
 #[test]
 fn hex_entity2() {
-    assert_eq!(decode_entity("#X1F4A9"), Some('💩'));
+    assert_eq!(decode_entity("#X1F4A9").as_deref(), Some("💩"));
 }
 
 #[test]
@@ -60,13 +98,13 @@ fn bad_hex2() {
 
 #[test]
 fn bad_hex3() {
-    assert_eq!(decode_entity("#X1F4A9FFFFF"), None);
+    assert_eq!(decode_entity("#X1F4A9FFFFF").as_deref(), Some("\u{FFFD}"));
 }
 
 
 #[test]
 fn dec_entity() {
-    assert_eq!(decode_entity("#128169"), Some('💩'));
+    assert_eq!(decode_entity("#128169").as_deref(), Some("💩"));
 }
 
 #[test]
@@ -81,7 +119,36 @@ fn bad_dec2() {
 
 #[test]
 fn bad_dec3() {
-    assert_eq!(decode_entity("#99999999999999999999"), None);
+    assert_eq!(decode_entity("#99999999999999999999").as_deref(), Some("\u{FFFD}"));
+}
+
+#[test]
+fn numeric_entity() {
+    assert_eq!(decode_numeric_entity(""), None);
+    assert_eq!(decode_numeric_entity("#x1F4A9"), Some('💩'));
+    assert_eq!(decode_entity("#150").as_deref(), Some("\u{2013}"));
+}
+
+#[test]
+fn c1_entity() {
+    assert_eq!(decode_numeric_entity("#146"), Some('\u{2019}'));
+    assert_eq!(decode_numeric_entity("#x80"), Some('€'));
+    assert_eq!(decode_numeric_entity("#x81"), Some('\u{81}'));
+}
+
+#[test]
+fn null_entity() {
+    assert_eq!(decode_numeric_entity("#0"), Some('\u{FFFD}'));
+}
+
+#[test]
+fn surrogate_entity() {
+    assert_eq!(decode_numeric_entity("#xD83D"), Some('\u{FFFD}'));
+}
+
+#[test]
+fn out_of_range_entity() {
+    assert_eq!(decode_numeric_entity("#x110000"), Some('\u{FFFD}'));
 }
 
 #[test]
@@ -96,4 +163,9 @@ fn cleanup_missing_close_tag() {
     let html2 = "<!DOCTYPE html><html><head><title>Test</title></head><body><h1>test</h1></body></html>";
 
     assert_eq!(cleanup(html), html2);
-}
\ No newline at end of file
+}
+#[test]
+fn entities_are_sorted() {
+    // Entities are found with a binary search
+    assert!(ENTITIES.windows(2).all(|w| w[0].0 < w[1].0));
+}
@@ -11,9 +11,12 @@ fn empty_doc() {
     assert_eq!(
         "",
         write(&Document {
+            xml_declaration: None,
             doctype: None,
+            quirks_mode: QuirksMode::Quirks,
             element: Element::new("#document"),
-            is_xml: false
+            is_xml: false,
+            encoding: None,
         })
     );
 }
@@ -23,13 +26,41 @@ fn doctype() {
     assert_eq!(
         "<!DOCTYPE html>",
         write(&Document {
-            doctype: Some("html".to_owned()),
+            xml_declaration: None,
+            doctype: Some(Doctype::new("html")),
+            quirks_mode: QuirksMode::NoQuirks,
             element: Element::new("#document"),
-            is_xml: false
+            is_xml: false,
+            encoding: None,
         })
     );
 }
 
+#[test]
+fn doctype_identifiers() {
+    let write_doctype = |doctype: Doctype| write(&Document {
+        xml_declaration: None,
+        doctype: Some(doctype),
+        quirks_mode: QuirksMode::NoQuirks,
+        element: Element::new("#document"),
+        is_xml: false,
+        encoding: None,
+    });
+
+    let mut doctype = Doctype::new("html");
+    doctype.public_id = Some("-//W3C//DTD HTML 4.01//EN".to_owned());
+    doctype.system_id = Some("http://www.w3.org/TR/html4/strict.dtd".to_owned());
+    assert_eq!(write_doctype(doctype.clone()), "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">");
+
+    doctype.public_id = None;
+    assert_eq!(write_doctype(doctype.clone()), "<!DOCTYPE html SYSTEM \"http://www.w3.org/TR/html4/strict.dtd\">");
+
+    doctype.system_id = Some("a\"b".to_owned());
+    assert_eq!(write_doctype(doctype), "<!DOCTYPE html SYSTEM 'a\"b'>");
+
+    assert_eq!(write_doctype(Doctype::new("")), "<!DOCTYPE>");
+}
+
 #[test]
 fn single_element() {
     let mut doc = Element::new("#document");
@@ -96,11 +127,14 @@ fn processing_instruction() {
 #[test]
 fn xml() {
     assert_eq!(
-        "<?xml version=\"1.0\"?>\n<!DOCTYPE html>",
+        "<?xml version=\"1.0\"?><!DOCTYPE html>",
         write(&Document {
-            doctype: Some("html".to_owned()),
+            xml_declaration: Some(XmlDeclaration::new("1.0")),
+            doctype: Some(Doctype::new("html")),
+            quirks_mode: QuirksMode::NoQuirks,
             element: Element::new("#document"),
-            is_xml: true
+            is_xml: true,
+            encoding: None,
         })
     );
 }
@@ -112,4 +146,103 @@ fn single_raw_element() {
     e.nodes.push(Node::Raw(Raw::new(" ><&\"'\u{a0}äöü ")));
     doc.nodes.push(Node::Element(e));
     assert_eq!("<script> ><&\"'\u{a0}äöü </script>", write_element(&doc));
-}
\ No newline at end of file
+}
+#[test]
+fn rcdata_elements() {
+    let mut doc = Element::new("#document");
+    let mut e = Element::new("title");
+    e.add_text("a < b & c");
+    doc.nodes.push(Node::Element(e));
+    let mut e = Element::new("textarea");
+    e.add_text("\n<b>");
+    doc.nodes.push(Node::Element(e));
+    assert_eq!("<title>a &lt; b &amp; c</title><textarea>\n\n&lt;b&gt;</textarea>", write_element(&doc));
+}
+
+#[test]
+fn plaintext_without_end_tag() {
+    let mut doc = Element::new("#document");
+    let mut e = Element::new("plaintext");
+    e.nodes.push(Node::Raw(Raw::new("<b>x</b>")));
+    doc.nodes.push(Node::Element(e));
+    assert_eq!("<plaintext><b>x</b>", write_element(&doc));
+}
+
+#[test]
+fn deeply_nested_elements() {
+    let mut e = Element::new("b");
+    for _ in 0..100_000 {
+        let mut parent = Element::new("b");
+        parent.add_element(e);
+        e = parent;
+    }
+
+    let mut doc = Element::new("#document");
+    doc.add_element(e);
+    let html = write_element(&doc);
+
+    assert_eq!(html.len(), 100_001 * 7);
+    assert!(html.starts_with("<b><b>") && html.ends_with("</b></b>"));
+}
+
+#[test]
+fn xml_declaration() {
+    let mut declaration = XmlDeclaration::new("1.0");
+    declaration.encoding = Some("ISO-8859-1".to_owned());
+    declaration.standalone = Some(false);
+
+    assert_eq!(
+        "<?xml version=\"1.0\" encoding=\"ISO-8859-1\" standalone=\"no\"?>",
+        write(&Document {
+            xml_declaration: Some(declaration),
+            doctype: None,
+            quirks_mode: QuirksMode::Quirks,
+            element: Element::new("#document"),
+            is_xml: true,
+            encoding: None,
+        })
+    );
+}
+
+#[test]
+fn xml_declaration_with_quote_and_whitespace() {
+    let mut declaration = XmlDeclaration::new("1.0");
+    declaration.encoding = Some("UTF-8".to_owned());
+    declaration.quote = '\'';
+    declaration.trailing_whitespace = "\n".to_owned();
+
+    assert_eq!(
+        "<?xml version='1.0' encoding='UTF-8'?>\n",
+        write(&Document {
+            xml_declaration: Some(declaration),
+            doctype: None,
+            quirks_mode: QuirksMode::Quirks,
+            element: Element::new("#document"),
+            is_xml: true,
+            encoding: None,
+        })
+    );
+}
+
+#[test]
+fn xml_elements() {
+    let mut root = Element::new("#document");
+    let mut br = Element::new("br");
+    br.set_attribute("title", "a<b");
+    root.add_element(br);
+    let mut script = Element::new("script");
+    script.add_text("\u{a0}");
+    root.add_element(script);
+
+    assert_eq!(
+        "<br title=\"a&lt;b\"/><script>&#160;</script>",
+        write(&Document {
+            xml_declaration: None,
+            doctype: None,
+            quirks_mode: QuirksMode::NoQuirks,
+            element: root,
+            is_xml: true,
+            encoding: None,
+        })
+    );
+}
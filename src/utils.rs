@@ -1,75 +1,218 @@
 
 //! Set of utility function
 
-use std::collections::HashSet;
-use std::collections::HashMap;
+use std::borrow::Cow;
 use std::char::from_u32;
 
+use crate::dom::Span;
+use crate::error::ParseError;
+
 include!("entities_map.rs");
 
-lazy_static! {
-  static ref ENTITIES: HashMap<&'static str, char> = create_entity_map();
+///Test a tag name referes to a tag that doesn't have child elements i.e. &lt;br&gt;
+pub fn is_autoclose_element(name: &str) -> bool {
+    matches!(name, "meta" | "link" | "base" | "br" | "wbr" | "hr" | "img" | "input" | "frame" | "embed"
+        | "col" | "command" | "source" | "device" | "keygen")
 }
 
-lazy_static! {
-  static ref AUTOCLOSE_ELEMENTS: HashSet<&'static str> = {
-    let mut set = HashSet::new();
-    set.insert("meta");
-    set.insert("link");
-    set.insert("base");
-    set.insert("br");
-    set.insert("wbr");
-    set.insert("hr");
-    set.insert("img");
-    set.insert("input");
-    set.insert("frame");
-    set.insert("embed");
-    set.insert("col");
-    set.insert("command");
-    set.insert("source");
-    set.insert("device");
-    set.insert("keygen");
-    set
-  };
+///Test if the end tag of an element can be omitted i.e. &lt;p&gt; or &lt;li&gt;
+pub fn has_optional_end_tag(name: &str) -> bool {
+    matches!(name, "html" | "head" | "body" | "p" | "li" | "dt" | "dd" | "option" | "optgroup"
+        | "rb" | "rt" | "rtc" | "rp" | "colgroup" | "caption" | "thead" | "tbody" | "tfoot"
+        | "tr" | "td" | "th")
 }
 
-///Test a tag name referes to a tag that doesn't have child elements i.e. &lt;br&gt;
-pub fn is_autoclose_element(name: &str) -> bool {
-    AUTOCLOSE_ELEMENTS.contains(name)
+///Test if an element belongs to the special category of the HTML specification
+///
+///Special elements are never closed implicitly by end tags of other elements.
+pub fn is_special_element(name: &str) -> bool {
+    matches!(name, "address" | "applet" | "area" | "article" | "aside" | "base" | "basefont" | "bgsound"
+        | "blockquote" | "body" | "br" | "button" | "caption" | "center" | "col" | "colgroup" | "dd"
+        | "details" | "dir" | "div" | "dl" | "dt" | "embed" | "fieldset" | "figcaption" | "figure"
+        | "footer" | "form" | "frame" | "frameset" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head"
+        | "header" | "hgroup" | "hr" | "html" | "iframe" | "img" | "input" | "keygen" | "li" | "link"
+        | "listing" | "main" | "marquee" | "menu" | "meta" | "nav" | "noembed" | "noframes" | "noscript"
+        | "object" | "ol" | "p" | "param" | "plaintext" | "pre" | "script" | "search" | "section"
+        | "select" | "source" | "style" | "summary" | "table" | "tbody" | "td" | "template" | "textarea"
+        | "tfoot" | "th" | "thead" | "title" | "tr" | "track" | "ul" | "wbr" | "xmp")
 }
 
+/// Tests if the content of the element is read as text without markup
+///
+/// `plaintext` reads everything up to the end of the input.
 pub fn is_raw_element(name: &str) -> bool {
-    name == "script" || name == "style"
+    matches!(name, "script" | "style" | "xmp" | "iframe" | "noembed" | "noframes" | "plaintext")
+}
+
+/// Tests if the content of the element is read as text without markup but with character references
+pub fn is_rcdata_element(name: &str) -> bool {
+    name == "title" || name == "textarea"
 }
 
 pub fn is_html_whitespace(ch: char) -> bool {
-    ch == ' ' || ch == '\n' || ch == '\r' || ch == '\t'
+    ch == ' ' || ch == '\n' || ch == '\r' || ch == '\t' || ch == '\x0C'
 }
 
-pub fn decode_entity(entity: &str) -> Option<char> {
+/// Length of the longest entity name
+const MAX_ENTITY_LENGTH: usize = 31;
 
+/// Returns the text of a named or numeric character reference
+///
+/// The reference is expected without the leading `&` and the trailing `;`. Some named entities expand
+/// to two code points. Numeric references like `#150` are decoded like [`decode_numeric_entity`] does.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::utils::decode_entity;
+///
+/// assert_eq!(decode_entity("euro").as_deref(), Some("€"));
+/// assert_eq!(decode_entity("NotEqualTilde").as_deref(), Some("\u{2242}\u{338}"));
+/// assert_eq!(decode_entity("#150").as_deref(), Some("\u{2013}"));
+/// ```
+pub fn decode_entity(entity: &str) -> Option<Cow<'static, str>> {
     if entity.starts_with('#') {
-        if entity.starts_with("#x") || entity.starts_with("#X") {
-            return match u32::from_str_radix(&entity[2..], 16) {
-                Ok(cp) => from_u32(cp),
-                Err(_) => None,
-            };
-        } else {
-            return match u32::from_str_radix(&entity[1..], 10) {
-                Ok(cp) => from_u32(cp),
-                Err(_) => None,
-            };
+        return decode_numeric_entity(entity).map(|ch| Cow::Owned(ch.to_string()));
+    }
+
+    ENTITIES
+        .binary_search_by(|&(name, _, _)| name.cmp(entity))
+        .ok()
+        .map(|index| Cow::Borrowed(ENTITIES[index].1))
+}
+
+/// Finds the longest named character reference at the start of the input
+///
+/// The input is expected to follow the `&`. Returns the length of the matched name including the `;`
+/// and the text of the reference. Legacy entities like `&amp` are matched without a semicolon.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::utils::match_entity;
+///
+/// assert_eq!(match_entity("amp;x"), Some((4, "&")));
+/// assert_eq!(match_entity("notit;"), Some((3, "¬")));
+/// assert_eq!(match_entity("foo;"), None);
+/// ```
+pub fn match_entity(input: &str) -> Option<(usize, &'static str)> {
+    let input = input.as_bytes();
+    let mut candidates: &[(&str, &str, bool)] = &ENTITIES;
+    let mut result = None;
+
+    // Narrow the sorted table down to the names starting with a growing prefix of the input.
+    // All remaining candidates share the prefix up to the last byte, so only that byte is compared.
+    for length in 1..=input.len().min(MAX_ENTITY_LENGTH) {
+        let byte = Some(&input[length - 1]);
+        let start = candidates.partition_point(|&(name, _, _)| name.as_bytes().get(length - 1) < byte);
+        let end = candidates.partition_point(|&(name, _, _)| name.as_bytes().get(length - 1) <= byte);
+        candidates = &candidates[start..end];
+
+        match candidates.first() {
+            Some(&(name, value, legacy)) if name.len() == length => {
+                if input[length..].starts_with(b";") {
+                    result = Some((length + 1, value));
+                } else if legacy {
+                    result = Some((length, value));
+                }
+            }
+            Some(_) => {}
+            None => break,
         }
     }
 
-    ENTITIES.get(entity).cloned()
+    result
+}
+
+/// Returns the length and the value of the XML entity at the start of the input
+///
+/// Only `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;` are defined in XML and the semicolon is required.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::utils::match_xml_entity;
+///
+/// assert_eq!(match_xml_entity("apos;x"), Some((5, "'")));
+/// assert_eq!(match_xml_entity("amp"), None);
+/// assert_eq!(match_xml_entity("nbsp;"), None);
+/// ```
+pub fn match_xml_entity(input: &str) -> Option<(usize, &'static str)> {
+    let entities = [("amp;", "&"), ("lt;", "<"), ("gt;", ">"), ("quot;", "\""), ("apos;", "'")];
+
+    entities.iter()
+        .find(|(name, _)| input.starts_with(name))
+        .map(|&(name, value)| (name.len(), value))
+}
+
+/// Replacements for the C1 control characters `0x80` to `0x9F` as shown by browsers
+static C1_REPLACEMENTS: [Option<char>; 32] = [
+    Some('\u{20AC}'), None, Some('\u{201A}'), Some('\u{0192}'),
+    Some('\u{201E}'), Some('\u{2026}'), Some('\u{2020}'), Some('\u{2021}'),
+    Some('\u{02C6}'), Some('\u{2030}'), Some('\u{0160}'), Some('\u{2039}'),
+    Some('\u{0152}'), None, Some('\u{017D}'), None,
+    None, Some('\u{2018}'), Some('\u{2019}'), Some('\u{201C}'),
+    Some('\u{201D}'), Some('\u{2022}'), Some('\u{2013}'), Some('\u{2014}'),
+    Some('\u{02DC}'), Some('\u{2122}'), Some('\u{0161}'), Some('\u{203A}'),
+    Some('\u{0153}'), None, Some('\u{017E}'), Some('\u{0178}'),
+];
+
+/// Returns the char of a numeric character reference like `#x1F4A9` or `#128169`
+///
+/// Code points are fixed the same way browsers do it: C1 control characters are mapped like windows-1252,
+/// null, surrogates and values above `0x10FFFF` are replaced with U+FFFD.
+///
+/// # Example
+///
+/// ```rust
+/// use qd_html::utils::decode_numeric_entity;
+///
+/// assert_eq!(decode_numeric_entity("#150"), Some('\u{2013}'));
+/// assert_eq!(decode_numeric_entity("#xD800"), Some('\u{FFFD}'));
+/// ```
+pub fn decode_numeric_entity(entity: &str) -> Option<char> {
+    decode_numeric_entity_checked(entity, "", Span::default()).map(|(ch, _)| ch)
+}
+
+/// Decodes a numeric character reference and returns the error for a fixed code point
+///
+/// The reference is the text of the reference in the input used for the error.
+pub(crate) fn decode_numeric_entity_checked(entity: &str, reference: &str, span: Span) -> Option<(char, Option<ParseError>)> {
+    let number = entity.strip_prefix('#')?;
+    let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+        Some(hex) => (hex, 16),
+        None => (number, 10),
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    // Values too large for an u32 are outside of the unicode range as well
+    let code = u32::from_str_radix(digits, radix).unwrap_or(u32::MAX);
+    let reference = || reference.to_owned();
+
+    Some(match code {
+        0 => ('\u{FFFD}', Some(ParseError::NullCharacterReference { reference: reference(), span })),
+        0xD800..=0xDFFF => ('\u{FFFD}', Some(ParseError::SurrogateCharacterReference { reference: reference(), span })),
+        0x11_0000.. => ('\u{FFFD}', Some(ParseError::CharacterReferenceOutsideUnicodeRange { reference: reference(), span })),
+        0x80..=0x9F => {
+            let ch = C1_REPLACEMENTS[code as usize - 0x80].or_else(|| from_u32(code)).expect("Valid code point");
+            (ch, Some(ParseError::ControlCharacterReference { reference: reference(), span }))
+        }
+        0x0D | 0x01..=0x08 | 0x0B | 0x0E..=0x1F | 0x7F => {
+            let ch = from_u32(code).expect("Valid code point");
+            (ch, Some(ParseError::ControlCharacterReference { reference: reference(), span }))
+        }
+        _ => (from_u32(code).expect("Valid code point"), None),
+    })
 }
 
 ///Returns a cleaned up version of the input HTML
 ///
 /// This parses the html and write the resulting document without modifications.
 /// The result is not guaranteed to be a valid HTML but simple errors like missing close tags should be removed.
-
+///
 /// # Examples
 ///
 /// ```rust
@@ -6,11 +6,13 @@ use crate::utils::*;
 
 struct HtmlWriter<'a> {
     out: &'a mut String,
+    /// Write XML, where empty elements use `/>` and no HTML entities are defined
+    xml: bool,
 }
 
 impl<'a> HtmlWriter<'a> {
-    pub fn new(out: &'a mut String) -> HtmlWriter {
-        HtmlWriter { out }
+    pub fn new(out: &'a mut String) -> HtmlWriter<'a> {
+        HtmlWriter { out, xml: false }
     }
 
     pub fn push(&mut self, ch: char) {
@@ -21,12 +23,80 @@ impl<'a> HtmlWriter<'a> {
         self.out.push_str(s);
     }
 
-    pub fn write_doctype(&mut self, dtd: &str) {
-        self.push_str("<!DOCTYPE ");
-        self.push_str(dtd);
+    pub fn write_xml_declaration(&mut self, declaration: &XmlDeclaration) {
+        if let Some(source) = declaration.source.as_deref().filter(|source| declares_same_values(source, declaration)) {
+            self.push_str("<?");
+            self.push_str(source);
+            self.push_str("?>");
+            self.push_str(&declaration.trailing_whitespace);
+            return;
+        }
+
+        self.push_str("<?xml");
+        self.write_declaration_value("version", &declaration.version, declaration.quote);
+
+        if let Some(ref encoding) = declaration.encoding {
+            self.write_declaration_value("encoding", encoding, declaration.quote);
+        }
+
+        if let Some(standalone) = declaration.standalone {
+            self.write_declaration_value("standalone", if standalone { "yes" } else { "no" }, declaration.quote);
+        }
+
+        self.push_str("?>");
+        self.push_str(&declaration.trailing_whitespace);
+    }
+
+    fn write_declaration_value(&mut self, name: &str, value: &str, quote: char) {
+        self.push(' ');
+        self.push_str(name);
+        self.push('=');
+        self.push(quote);
+        self.push_str(value);
+        self.push(quote);
+    }
+
+    /// Writes a doctype, keeping `force_quirks` by leaving its last identifier unclosed
+    /// or by writing a keyword without identifier
+    pub fn write_doctype(&mut self, doctype: &Doctype) {
+        self.push_str("<!DOCTYPE");
+        if !doctype.name.is_empty() {
+            self.push(' ');
+            self.push_str(&doctype.name);
+        }
+
+        let force_quirks = doctype.force_quirks && !doctype.name.is_empty();
+
+        match (&doctype.public_id, &doctype.system_id) {
+            (Some(public_id), system_id) => {
+                self.push_str(" PUBLIC ");
+                self.doctype_id(public_id, !force_quirks || system_id.is_some());
+                if let Some(system_id) = system_id {
+                    self.push(' ');
+                    self.doctype_id(system_id, !force_quirks);
+                }
+            }
+            (None, Some(system_id)) => {
+                self.push_str(" SYSTEM ");
+                self.doctype_id(system_id, !force_quirks);
+            }
+            (None, None) if force_quirks => self.push_str(" PUBLIC"),
+            (None, None) => {}
+        }
+
         self.push('>');
     }
 
+    /// Writes a doctype identifier in single quotes if it contains a double quote
+    fn doctype_id(&mut self, id: &str, closed: bool) {
+        let quote = if id.contains('"') { '\'' } else { '"' };
+        self.push(quote);
+        self.push_str(id);
+        if closed {
+            self.push(quote);
+        }
+    }
+
     pub fn element_start(&mut self, element: &Element) {
         self.element_start_name(&element.name);
 
@@ -37,6 +107,17 @@ impl<'a> HtmlWriter<'a> {
         self.push('>');
     }
 
+    /// Writes an XML element without content as a single tag
+    pub fn empty_element(&mut self, element: &Element) {
+        self.element_start_name(&element.name);
+
+        for attr in &element.attributes {
+            self.element_attribute(&attr.name, &attr.value);
+        }
+
+        self.push_str("/>");
+    }
+
     pub fn element_start_name(&mut self, name: &str) {
         self.push('<');
         self.push_str(name);
@@ -49,7 +130,7 @@ impl<'a> HtmlWriter<'a> {
         if let Some(ref value) = *value {
             self.push('=');
             self.push('"');
-            self.append_text(&value, true);
+            self.append_text(value, true);
             self.push('"');
         }
     }
@@ -77,7 +158,7 @@ impl<'a> HtmlWriter<'a> {
                     }
                 }
                 '<' => {
-                    if attribute {
+                    if attribute && !self.xml {
                         self.push(ch);
                     } else {
                         self.push_str("&lt;");
@@ -90,7 +171,8 @@ impl<'a> HtmlWriter<'a> {
                         self.push(ch);
                     }
                 }
-                '\u{a0}' => self.push_str("&nbsp;"),
+                // XML doesn't define `&nbsp;`
+                '\u{a0}' => self.push_str(if self.xml { "&#160;" } else { "&nbsp;" }),
                 '\n' | '\t' | '\r' | ' ' => self.push(ch),
                 '\u{0}'..='\u{1f}' => {
                     //Ignore ISO control chars
@@ -138,14 +220,14 @@ pub fn write(doc: &Document) -> String {
     let mut result = "".to_owned();
     {
         let mut writer = HtmlWriter::new(&mut result);
+        writer.xml = doc.is_xml;
 
-        if doc.is_xml {
-            writer.append_processing_instruction("xml version=\"1.0\"");
-            writer.append_nl();
+        if let Some(ref declaration) = doc.xml_declaration {
+            writer.write_xml_declaration(declaration);
         }
 
-        if let Some(ref dtd) = doc.doctype {
-            writer.write_doctype(dtd);
+        if let Some(ref doctype) = doc.doctype {
+            writer.write_doctype(doctype);
         }
         append_nodes(&mut writer, &doc.element.nodes);
     }
@@ -167,23 +249,82 @@ pub fn write_element(root: &Element) -> String {
     result
 }
 
-fn append_nodes(mut writer: &mut HtmlWriter, nodes: &[Node]) {
-    for node in nodes {
-        match *node {
-            Node::Element(ref e) => append_element(&mut writer, e),
-            Node::Text(ref text) => writer.append_text(&text.content, false),
-            Node::Comment(ref comment) => writer.append_comment(&comment.content),
-            Node::CData(ref cdata) => writer.append_cdata(&cdata.content),
-            Node::Raw(ref raw) => writer.append_raw(&raw.content),
-            Node::ProcessingInstruction(ref pi) => writer.append_processing_instruction(&pi.content)
+fn append_nodes(writer: &mut HtmlWriter, nodes: &[Node]) {
+    append_content(writer, None, nodes);
+}
+
+fn append_element(writer: &mut HtmlWriter, element: &Element) {
+    if append_element_start(writer, element) {
+        append_content(writer, Some(element), &element.nodes);
+    }
+}
+
+/// Writes the nodes and the end tag of the parent
+///
+/// Nested elements are written in a loop instead of recursively, so deeply nested trees don't overflow the stack.
+fn append_content<'a>(writer: &mut HtmlWriter, parent: Option<&'a Element>, nodes: &'a [Node]) {
+    let xml = writer.xml;
+    let mut open = vec![(parent, nodes.iter())];
+
+    while let Some((parent, nodes)) = open.last_mut() {
+        match nodes.next() {
+            Some(Node::Element(e)) => {
+                if append_element_start(writer, e) {
+                    open.push((Some(e), e.nodes.iter()));
+                }
+            }
+            Some(Node::Text(text)) => writer.append_text(&text.content, false),
+            Some(Node::Comment(comment)) => writer.append_comment(&comment.content),
+            Some(Node::CData(cdata)) => writer.append_cdata(&cdata.content),
+            Some(Node::Raw(raw)) => writer.append_raw(&raw.content),
+            Some(Node::ProcessingInstruction(pi)) => writer.append_processing_instruction(&pi.content),
+            None => {
+                // Everything after a plaintext start tag is text
+                if let Some(e) = parent.filter(|e| xml || e.name != "plaintext") {
+                    writer.element_end(e);
+                }
+                open.pop();
+            }
         }
     }
 }
 
-fn append_element(writer: &mut HtmlWriter, element: &Element) {
+/// Writes the start tag of an element and returns true if its content and end tag follow
+fn append_element_start(writer: &mut HtmlWriter, element: &Element) -> bool {
+    if writer.xml {
+        if element.nodes.is_empty() {
+            writer.empty_element(element);
+            return false;
+        }
+
+        writer.element_start(element);
+        return true;
+    }
+
     writer.element_start(element);
-    if !is_autoclose_element(&element.name) {
-        append_nodes(writer, &element.nodes);
-        writer.element_end(element);
+    if is_autoclose_element(&element.name) {
+        return false;
+    }
+
+    // A leading newline in a textarea would be dropped when parsed again
+    if element.name == "textarea" && starts_with_newline(&element.nodes) {
+        writer.append_nl();
+    }
+
+    true
+}
+
+fn starts_with_newline(nodes: &[Node]) -> bool {
+    match nodes.first() {
+        Some(Node::Text(text)) => text.content.starts_with('\n'),
+        _ => false,
+    }
+}
+
+/// Checks if the source of a declaration still declares the values of the declaration
+fn declares_same_values(source: &str, declaration: &XmlDeclaration) -> bool {
+    match crate::dom_builder::read_xml_declaration(source) {
+        Some(read) => read.version == declaration.version && read.encoding == declaration.encoding && read.standalone == declaration.standalone,
+        None => false,
     }
 }